@@ -0,0 +1,160 @@
+use crate::math;
+use uom::si::{
+    angle::minute,
+    f64::{Angle, Length},
+};
+
+/// The sharpness of the viewer's vision, expressed as the smallest angle between two points at which they can still be told apart.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VisualAcuity {
+    /// The smallest angle between two points at which they can still be told apart, also known as the minimum angle of resolution.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "resolvable_angle_rad", with = "crate::serde_units::radians")
+    )]
+    pub resolvable_angle: Angle,
+}
+impl VisualAcuity {
+    /// Creates the acuity from a Snellen fraction, such as 20/20 or 6/6: the distance at which the viewer can read a letter, divided by the distance at which a viewer with normal vision can read it.
+    ///
+    /// Normal vision, i.e. 20/20, corresponds to a resolvable angle of 1 arcminute. The angle grows with the denominator: 20/40 vision can only resolve 2 arcminutes.
+    pub fn from_snellen(numerator: f64, denominator: f64) -> Self {
+        Self {
+            resolvable_angle: Angle::new::<minute>(denominator / numerator),
+        }
+    }
+    /// Returns the acuity of normal vision, i.e. 20/20, which can resolve 1 arcminute.
+    pub fn normal() -> Self {
+        Self::from_snellen(20.0, 20.0)
+    }
+    /// Calculates the distance beyond which two points separated by the given length can no longer be told apart, with the eye directly in front of the middle between them.
+    pub fn resolvable_distance(self, separation: Length) -> Length {
+        // The two points and the eye form an isosceles triangle, which is split into two
+        // right-angled triangles with half the separation as the opposite catet, same as the
+        // screen in MonitorConfiguration::fov_along
+        separation / 2.0 / math::tan(self.resolvable_angle / 2.0)
+    }
+}
+impl Default for VisualAcuity {
+    fn default() -> Self {
+        Self::normal()
+    }
+}
+
+/// How the pixels of a monitor compare to the viewer's [visual acuity].
+///
+/// [visual acuity]: struct.VisualAcuity.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcuityAnalysis {
+    /// The angle which a pixel at the center of the screen is seen at.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "pixel_angle_rad", with = "crate::serde_units::radians")
+    )]
+    pub pixel_angle: Angle,
+    /// The angle which a pixel is seen at divided by the [resolvable angle], i.e. how many times too large the pixels are to blend together. Values above 1 mean that individual pixels can be told apart.
+    ///
+    /// [resolvable angle]: struct.VisualAcuity.html#structfield.resolvable_angle " "
+    pub pixel_to_resolvable_ratio: f64,
+    /// The viewing distance beyond which individual pixels can no longer be told apart, sometimes called the "retina distance".
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "retina_distance_m", with = "crate::serde_units::meters")
+    )]
+    pub retina_distance: Length,
+}
+impl AcuityAnalysis {
+    /// Returns whether individual pixels can be told apart from the viewing distance.
+    pub fn pixels_resolvable(self) -> bool {
+        self.pixel_to_resolvable_ratio > 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MonitorConfiguration, MonitorDimensions};
+    use uom::si::{
+        angle::radian,
+        length::{centimeter, meter},
+        ratio::ratio,
+    };
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            math::abs(actual - expected) < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn snellen_fractions() {
+        assert_close(
+            VisualAcuity::from_snellen(20.0, 20.0)
+                .resolvable_angle
+                .get::<minute>(),
+            1.0,
+        );
+        assert_close(
+            VisualAcuity::from_snellen(6.0, 6.0)
+                .resolvable_angle
+                .get::<minute>(),
+            1.0,
+        );
+        assert_close(
+            VisualAcuity::from_snellen(20.0, 40.0)
+                .resolvable_angle
+                .get::<minute>(),
+            2.0,
+        );
+        assert_close(
+            VisualAcuity::from_snellen(20.0, 10.0)
+                .resolvable_angle
+                .get::<minute>(),
+            0.5,
+        );
+        assert_eq!(VisualAcuity::default(), VisualAcuity::normal());
+    }
+    #[test]
+    fn retina_distance_matches_the_resolvable_angle() {
+        let acuity = VisualAcuity::normal();
+        // A 27" 1440p monitor, 60 cm away
+        let mut monitor = MonitorConfiguration {
+            dimensions: MonitorDimensions::WidthAndHeight {
+                width: Length::new::<centimeter>(59.6736),
+                height: Length::new::<centimeter>(33.5664),
+            },
+            distance: Length::new::<centimeter>(60.0),
+            eye_offset: None,
+            curvature: None,
+            resolution: Some([2560, 1440]),
+        };
+        let analysis = monitor.acuity_analysis(acuity).unwrap();
+        assert!(analysis.pixels_resolvable());
+        assert_close(
+            analysis.pixel_to_resolvable_ratio,
+            (analysis.pixel_angle / acuity.resolvable_angle).get::<ratio>(),
+        );
+        // Moving to the retina distance makes a pixel exactly as large as the resolvable angle
+        let retina_distance = analysis.retina_distance;
+        assert!(retina_distance > monitor.distance);
+        monitor.distance = retina_distance;
+        let analysis = monitor.acuity_analysis(acuity).unwrap();
+        assert_close(
+            analysis.pixel_angle.get::<radian>(),
+            acuity.resolvable_angle.get::<radian>(),
+        );
+        assert_close(analysis.pixel_to_resolvable_ratio, 1.0);
+        assert_close(
+            analysis.retina_distance.get::<meter>(),
+            retina_distance.get::<meter>(),
+        );
+        // Unknown resolution means unknown pixel size
+        monitor.resolution = None;
+        assert!(monitor.acuity_analysis(acuity).is_none());
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{AspectRatio, MonitorConfiguration, MonitorDimensions, COMMON_ASPECT_RATIOS};
+use core::cmp::Ordering;
+use uom::si::{
+    f64::{Angle, Length},
+    length::inch,
+};
+
+/// A list of commonly sold display resolutions, as `[width, height]` in pixels.
+///
+/// The [advisor] suggests those for the [common aspect ratios] which they match exactly.
+///
+/// [advisor]: fn.display_candidates.html " "
+/// [common aspect ratios]: static.COMMON_ASPECT_RATIOS.html " "
+pub static COMMON_RESOLUTIONS: &[[u32; 2]] = &[
+    // 16:9
+    [1920, 1080],
+    [2560, 1440],
+    [3840, 2160],
+    [5120, 2880],
+    [7680, 4320],
+    // 16:10
+    [1920, 1200],
+    [2560, 1600],
+    [3840, 2400],
+    // 4:3 and 5:4
+    [1600, 1200],
+    [1280, 1024],
+    // 3:2
+    [2256, 1504],
+    [3000, 2000],
+    // Ultrawide
+    [2560, 1080],
+    [3440, 1440],
+    [3840, 1600],
+    [5120, 2160],
+    [3840, 1080],
+    [5120, 1440],
+    [7680, 2160],
+];
+/// A list of commonly sold display diagonal sizes, in inches.
+pub static COMMON_DIAGONALS_INCHES: &[f64] = &[
+    21.5, 24.0, 27.0, 32.0, 34.0, 38.0, 42.0, 43.0, 45.0, 48.0, 49.0, 55.0, 57.0, 65.0, 77.0, 83.0,
+];
+
+/// The requirements for a display which the [advisor] looks for.
+///
+/// [advisor]: fn.display_candidates.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayRequirements {
+    /// The distance from which the display will be viewed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "distance_m", with = "crate::serde_units::meters")
+    )]
+    pub distance: Length,
+    /// The smallest acceptable horizontal FOV which the display occupies.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "min_fov_rad", with = "crate::serde_units::radians")
+    )]
+    pub min_fov: Angle,
+    /// The largest acceptable horizontal FOV which the display occupies.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "max_fov_rad", with = "crate::serde_units::radians")
+    )]
+    pub max_fov: Angle,
+    /// The smallest acceptable average number of pixels per degree of the horizontal FOV, or zero if any resolution is acceptable.
+    pub min_pixels_per_degree: f64,
+}
+impl DisplayRequirements {
+    /// Returns whether a display with the given horizontal FOV and average pixels per degree meets the requirements. A display of unknown resolution, for which the pixels per degree are `None`, only meets a minimum of zero pixels per degree.
+    pub fn are_met_by(self, fov: Angle, pixels_per_degree: Option<f64>) -> bool {
+        let enough_pixels = match pixels_per_degree {
+            Some(pixels_per_degree) => pixels_per_degree >= self.min_pixels_per_degree,
+            None => self.min_pixels_per_degree <= 0.0,
+        };
+        fov >= self.min_fov && fov <= self.max_fov && enough_pixels
+    }
+    /// Compares two candidates by how well they suit the requirements, with the better one being the lesser.
+    ///
+    /// Candidates with a horizontal FOV closer to the middle of the acceptable range are better, with ties broken by a higher number of pixels per degree, and candidates of unknown resolution coming last. Candidates which are still tied are ordered by the diagonal, the aspect ratio and the resolution, smallest first, so that the ranking doesn't depend on the order in which the candidates were listed.
+    pub fn compare_candidates(self, a: &DisplayCandidate, b: &DisplayCandidate) -> Ordering {
+        let middle = (self.min_fov + self.max_fov) / 2.0;
+        let off_middle = |c: &DisplayCandidate| (c.fov - middle).abs();
+        let partial = |ordering: Option<Ordering>| ordering.unwrap_or(Ordering::Equal);
+        partial(off_middle(a).partial_cmp(&off_middle(b)))
+            .then_with(|| partial(b.pixels_per_degree.partial_cmp(&a.pixels_per_degree)))
+            .then_with(|| partial(a.diagonal.partial_cmp(&b.diagonal)))
+            .then_with(|| partial(a.aspect.value().partial_cmp(&b.aspect.value())))
+            .then_with(|| a.resolution.cmp(&b.resolution))
+    }
+}
+
+/// A display suggested by the [advisor].
+///
+/// [advisor]: fn.display_candidates.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayCandidate {
+    /// The diagonal of the display.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "diagonal_m", with = "crate::serde_units::meters")
+    )]
+    pub diagonal: Length,
+    /// The aspect ratio of the display, which is one of the [common aspect ratios].
+    ///
+    /// [common aspect ratios]: static.COMMON_ASPECT_RATIOS.html " "
+    pub aspect: AspectRatio,
+    /// The resolution of the display as `[width, height]` in pixels, or `None` if none of the [common resolutions] have the aspect ratio of the display.
+    ///
+    /// [common resolutions]: static.COMMON_RESOLUTIONS.html " "
+    pub resolution: Option<[u32; 2]>,
+    /// The horizontal FOV which the display occupies from the viewing distance.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "fov_rad", with = "crate::serde_units::radians")
+    )]
+    pub fov: Angle,
+    /// The average number of pixels per degree of the horizontal FOV, or `None` if the resolution is unknown.
+    pub pixels_per_degree: Option<f64>,
+}
+
+/// Returns an iterator over all combinations of [common aspect ratios] and [common diagonals] which meet the given requirements, in no particular order.
+///
+/// Each aspect ratio comes with every one of the [common resolutions] which matches it exactly, or with an unknown resolution if there are none. To rank the candidates from best to worst, collect them and sort them with [`rank_display_candidates`].
+///
+/// [common aspect ratios]: static.COMMON_ASPECT_RATIOS.html " "
+/// [common resolutions]: static.COMMON_RESOLUTIONS.html " "
+/// [common diagonals]: static.COMMON_DIAGONALS_INCHES.html " "
+/// [`rank_display_candidates`]: fn.rank_display_candidates.html " "
+pub fn display_candidates(
+    requirements: DisplayRequirements,
+) -> impl Iterator<Item = DisplayCandidate> {
+    COMMON_ASPECT_RATIOS
+        .iter()
+        .flat_map(|&aspect| {
+            let matches =
+                move |&[width, height]: &[u32; 2]| AspectRatio::new(width, height) == Some(aspect);
+            let unknown = if COMMON_RESOLUTIONS.iter().any(matches) {
+                None
+            } else {
+                Some(None)
+            };
+            COMMON_RESOLUTIONS
+                .iter()
+                .filter(move |resolution| matches(resolution))
+                .map(|&resolution| Some(resolution))
+                .chain(unknown)
+                .map(move |resolution| (aspect, resolution))
+        })
+        .flat_map(|(aspect, resolution)| {
+            COMMON_DIAGONALS_INCHES
+                .iter()
+                .map(move |&diagonal| (aspect, resolution, Length::new::<inch>(diagonal)))
+        })
+        .map(move |(aspect, resolution, diagonal)| {
+            let dimensions = MonitorDimensions::DiagonalAndAspect { diagonal, aspect };
+            let configuration = MonitorConfiguration {
+                dimensions,
+                distance: requirements.distance,
+                eye_offset: None,
+                curvature: None,
+                resolution,
+            };
+            DisplayCandidate {
+                diagonal,
+                aspect,
+                resolution,
+                fov: configuration.fov(),
+                pixels_per_degree: configuration.average_pixels_per_degree(),
+            }
+        })
+        .filter(move |c| requirements.are_met_by(c.fov, c.pixels_per_degree))
+}
+/// Sorts display candidates from best to worst according to [`DisplayRequirements::compare_candidates`].
+///
+/// [`DisplayRequirements::compare_candidates`]: struct.DisplayRequirements.html#method.compare_candidates " "
+pub fn rank_display_candidates(
+    requirements: DisplayRequirements,
+    candidates: &mut [DisplayCandidate],
+) {
+    candidates.sort_unstable_by(|a, b| requirements.compare_candidates(a, b));
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::vec::Vec;
+    use uom::si::{angle::degree, length::centimeter};
+
+    fn requirements(min_fov: f64, max_fov: f64, min_pixels_per_degree: f64) -> DisplayRequirements {
+        DisplayRequirements {
+            distance: Length::new::<centimeter>(80.0),
+            min_fov: Angle::new::<degree>(min_fov),
+            max_fov: Angle::new::<degree>(max_fov),
+            min_pixels_per_degree,
+        }
+    }
+    fn key(candidate: &DisplayCandidate) -> (u64, AspectRatio, Option<[u32; 2]>) {
+        let diagonal = (candidate.diagonal.get::<inch>() * 10.0) as u64;
+        (diagonal, candidate.aspect, candidate.resolution)
+    }
+
+    #[test]
+    fn constraints_exclude_candidates() {
+        // Without constraints, every diagonal is suggested with every common resolution of each
+        // aspect ratio, or once with an unknown resolution if there's none
+        let all = display_candidates(requirements(0.0, 180.0, 0.0)).count();
+        let resolutions_per_diagonal = COMMON_ASPECT_RATIOS
+            .iter()
+            .map(|&aspect| {
+                COMMON_RESOLUTIONS
+                    .iter()
+                    .filter(|&&[width, height]| AspectRatio::new(width, height) == Some(aspect))
+                    .count()
+                    .max(1)
+            })
+            .sum::<usize>();
+        assert_eq!(
+            all,
+            COMMON_DIAGONALS_INCHES.len() * resolutions_per_diagonal
+        );
+        let requirements = requirements(50.0, 70.0, 40.0);
+        let candidates = display_candidates(requirements).collect::<Vec<_>>();
+        assert!(!candidates.is_empty());
+        assert!(candidates.len() < all);
+        for candidate in &candidates {
+            assert!(candidate.fov >= requirements.min_fov && candidate.fov <= requirements.max_fov);
+            // Unknown resolutions can't be shown to meet a minimum
+            assert!(candidate.pixels_per_degree.unwrap() >= 40.0);
+        }
+        // A 24" 1080p monitor is too narrow from 80 cm, and a 4K 83" TV is too wide and too
+        // coarse
+        let has = |diagonal: f64, resolution: [u32; 2]| {
+            candidates.iter().any(|c| {
+                c.diagonal == Length::new::<inch>(diagonal) && c.resolution == Some(resolution)
+            })
+        };
+        assert!(!has(24.0, [1920, 1080]));
+        assert!(!has(83.0, [3840, 2160]));
+        assert!(has(38.0, [3840, 1600]));
+        // Without a pixel density requirement, displays of unknown resolution are suggested too
+        assert!(display_candidates(DisplayRequirements {
+            min_pixels_per_degree: 0.0,
+            ..requirements
+        })
+        .any(|c| c.resolution.is_none()));
+    }
+    #[test]
+    fn ranking_is_deterministic() {
+        let requirements = requirements(40.0, 100.0, 0.0);
+        let mut forward = display_candidates(requirements).collect::<Vec<_>>();
+        let mut backward = forward.iter().rev().copied().collect::<Vec<_>>();
+        rank_display_candidates(requirements, &mut forward);
+        rank_display_candidates(requirements, &mut backward);
+        assert_eq!(
+            forward.iter().map(key).collect::<Vec<_>>(),
+            backward.iter().map(key).collect::<Vec<_>>()
+        );
+        // The best candidate is closest to 70°, and higher resolutions of the same size win
+        let middle = Angle::new::<degree>(70.0);
+        let best = (forward[0].fov - middle).abs();
+        assert!(forward.iter().all(|c| (c.fov - middle).abs() >= best));
+        for pair in forward.windows(2) {
+            if pair[0].diagonal == pair[1].diagonal && pair[0].aspect == pair[1].aspect {
+                assert!(pair[0].pixels_per_degree >= pair[1].pixels_per_degree);
+            }
+        }
+    }
+}
@@ -1,15 +1,202 @@
+use crate::math;
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// An aspect ratio as a fraction of two whole numbers, always reduced to the lowest terms: a 2560×1080 screen has an aspect ratio of 64:27.
+///
+/// Since 16:10 and 21:9 reduce to 8:5 and 7:3, and ultrawide screens are sold as 21:9 despite having different aspect ratios, use [`marketing_name`] to get the name under which the aspect ratio is usually known.
+///
+/// [`marketing_name`]: #method.marketing_name " "
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "[u32; 2]", into = "[u32; 2]")
+)]
+pub struct AspectRatio {
+    numerator: u32,
+    denominator: u32,
+}
+impl AspectRatio {
+    /// Creates an aspect ratio from its numerator and denominator, reducing them to the lowest terms. Returns `None` if either of them is zero.
+    ///
+    /// A resolution in pixels can be used directly: `AspectRatio::new(2560, 1080)` is 64:27.
+    pub const fn new(numerator: u32, denominator: u32) -> Option<Self> {
+        if numerator == 0 || denominator == 0 {
+            None
+        } else {
+            Some(Self::reduced(numerator, denominator))
+        }
+    }
+    /// Approximates the given single-number ratio with a fraction which has a denominator no larger than the given one, stopping early at exact matches so that `16.0 / 9.0` becomes 16:9. Returns `None` if the ratio is not a positive finite number or if it's too far from any fraction with such a denominator.
+    pub fn approximate(ratio: f64, max_denominator: u32) -> Option<Self> {
+        if !ratio.is_finite() || ratio <= 0.0 {
+            return None;
+        }
+        // The convergents of the continued fraction of the ratio are the best approximations for
+        // their denominators, so we take the last one that still fits. Each one is calculated
+        // from the previous two, starting with the imaginary 0/1 and 1/0.
+        let max_denominator = u64::from(max_denominator);
+        let [mut previous, mut current] = [[0_u64, 1_u64], [1, 0]];
+        let mut remainder = ratio;
+        loop {
+            // Saturates for huge remainders, which are only left by exact fractions
+            let whole = remainder as u64;
+            let next = match (
+                whole
+                    .checked_mul(current[0])
+                    .and_then(|n| n.checked_add(previous[0])),
+                whole
+                    .checked_mul(current[1])
+                    .and_then(|d| d.checked_add(previous[1])),
+            ) {
+                (Some(n), Some(d)) if n <= u64::from(u32::MAX) && d <= max_denominator => [n, d],
+                _ => break,
+            };
+            previous = current;
+            current = next;
+            let fraction = remainder - whole as f64;
+            if fraction == 0.0 || current[0] as f64 / current[1] as f64 == ratio {
+                break;
+            }
+            remainder = 1.0 / fraction;
+        }
+        Self::new(current[0] as u32, current[1] as u32)
+    }
+    /// Returns the numerator, i.e. the relative width.
+    pub fn numerator(self) -> u32 {
+        self.numerator
+    }
+    /// Returns the denominator, i.e. the relative height.
+    pub fn denominator(self) -> u32 {
+        self.denominator
+    }
+    /// Returns the aspect ratio as a single number, i.e. `width / height`.
+    pub fn value(self) -> f64 {
+        f64::from(self.numerator) / f64::from(self.denominator)
+    }
+    /// Returns the name under which displays with this aspect ratio are sold, if it's different from the reduced form: "16:10" for 8:5, or "21:9" for 7:3 as well as the 64:27, 43:18 and 12:5 of actual ultrawide monitors.
+    pub fn marketing_name(self) -> Option<&'static str> {
+        MARKETING_NAMES
+            .iter()
+            .find(|(ratio, _)| *ratio == self)
+            .map(|&(_, name)| name)
+    }
+    /// Returns the [common aspect ratios] ordered from the closest to the given single-number ratio to the farthest.
+    ///
+    /// [common aspect ratios]: static.COMMON_ASPECT_RATIOS.html " "
+    pub fn nearest_common(ratio: f64) -> impl Iterator<Item = Self> {
+        let mut ranked = COMMON_ASPECT_RATIOS;
+        let distance = |candidate: &Self| math::abs(candidate.value() - ratio);
+        ranked.sort_unstable_by(|a, b| {
+            distance(a)
+                .partial_cmp(&distance(b))
+                .unwrap_or(Ordering::Equal)
+        });
+        (0..ranked.len()).map(move |i| ranked[i])
+    }
+    const fn reduced(numerator: u32, denominator: u32) -> Self {
+        let divisor = gcd(numerator as u64, denominator as u64) as u32;
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+}
+impl Display for AspectRatio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.numerator, self.denominator)
+    }
+}
+impl FromStr for AspectRatio {
+    type Err = AspectRatioError;
+    /// Parses an aspect ratio in the form of `16:9`, also accepting `16/9`, resolutions like `2560x1080` and decimal terms like `2.39:1`. A single number such as `2.39` is taken as the numerator with a denominator of 1.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let separators: &[char] = &[':', '/', 'x', '×'];
+        let mut terms = s.splitn(2, separators);
+        let numerator = parse_decimal(terms.next().unwrap_or(""))?;
+        let denominator = match terms.next() {
+            Some(term) => parse_decimal(term)?,
+            None => (1, 0),
+        };
+        // Scale both terms by the number of decimal places of the other one to make them whole
+        let scale = |(mantissa, _): (u64, u32), (_, places): (u64, u32)| {
+            10_u64
+                .checked_pow(places)
+                .and_then(|power| mantissa.checked_mul(power))
+                .ok_or(AspectRatioError::TooLarge)
+        };
+        let (numerator, denominator) = (
+            scale(numerator, denominator)?,
+            scale(denominator, numerator)?,
+        );
+        if numerator == 0 || denominator == 0 {
+            return Err(AspectRatioError::Zero);
+        }
+        let divisor = gcd(numerator, denominator);
+        match (
+            u32::try_from(numerator / divisor),
+            u32::try_from(denominator / divisor),
+        ) {
+            (Ok(numerator), Ok(denominator)) => Ok(Self {
+                numerator,
+                denominator,
+            }),
+            _ => Err(AspectRatioError::TooLarge),
+        }
+    }
+}
+impl TryFrom<[u32; 2]> for AspectRatio {
+    type Error = AspectRatioError;
+    fn try_from([numerator, denominator]: [u32; 2]) -> Result<Self, Self::Error> {
+        Self::new(numerator, denominator).ok_or(AspectRatioError::Zero)
+    }
+}
+impl From<AspectRatio> for [u32; 2] {
+    fn from(ratio: AspectRatio) -> Self {
+        [ratio.numerator, ratio.denominator]
+    }
+}
+
+/// The reason why an [aspect ratio] couldn't be created or parsed.
+///
+/// [aspect ratio]: struct.AspectRatio.html " "
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AspectRatioError {
+    /// The numerator or the denominator is zero.
+    Zero,
+    /// The string isn't one or two non-negative decimal numbers separated by a colon.
+    Malformed,
+    /// The numerator or the denominator doesn't fit into 32 bits after being reduced.
+    TooLarge,
+}
+impl Display for AspectRatioError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Zero => "the terms of an aspect ratio can't be zero",
+            Self::Malformed => "an aspect ratio must be two numbers separated by a colon",
+            Self::TooLarge => "the terms of the aspect ratio are too large",
+        })
+    }
+}
+
 macro_rules! generate_common_aspect_ratios {
     ($($n:literal : $d:literal),+ $(,)?) => {
         [$(
-            ($n as f64 / $d as f64, [$n as f64, $d as f64]),
+            AspectRatio::reduced($n, $d),
         )+]
     };
 }
 
-/// A list of commonly used monitor aspect ratios.
+/// A list of commonly used monitor aspect ratios, reduced to the lowest terms like any [`AspectRatio`].
 ///
-/// Values are given in the form of a tuple `(ratio, [numerator, denominator])`, all three numbers being `f64`s.
-pub static COMMON_ASPECT_RATIOS: &[(f64, [f64; 2])] = &generate_common_aspect_ratios! [
+/// [`AspectRatio`]: struct.AspectRatio.html " "
+pub static COMMON_ASPECT_RATIOS: [AspectRatio; 13] = generate_common_aspect_ratios! [
     // Common
     16:9,
     // Not very common
@@ -18,20 +205,151 @@ pub static COMMON_ASPECT_RATIOS: &[(f64, [f64; 2])] = &generate_common_aspect_ra
     // Considerably less common
     5:4,
     3:2,
-    // Ultrawide gamer ratios
+    // Ultrawide gamer ratios, with the actual ratios of 2560×1080, 3440×1440 and 3840×1600
+    // ultrawides which are all sold as 21:9
     17:9,
     21:9,
+    64:27,
+    43:18,
+    12:5,
     32:9,
     // Honestly not common at all
     1:1,
     4:1,
 ];
-/// Finds a common aspect ratio for the given single-number ratio, considering the ratio close enough if the difference is less than the given rounding.
-pub fn find_common_aspect_ratio(ratio: f64, rounding: f64) -> Option<[f64; 2]> {
-    COMMON_ASPECT_RATIOS
-        .iter()
-        .copied()
-        .filter(|(r, _)| (ratio - r).abs() < rounding)
-        .map(|(_, [n, d])| [n, d])
+/// Aspect ratios which are sold under a different name than their reduced form, along with that name.
+static MARKETING_NAMES: &[(AspectRatio, &str)] = &[
+    (AspectRatio::reduced(16, 10), "16:10"),
+    (AspectRatio::reduced(21, 9), "21:9"),
+    (AspectRatio::reduced(64, 27), "21:9"),
+    (AspectRatio::reduced(43, 18), "21:9"),
+    (AspectRatio::reduced(12, 5), "21:9"),
+    // 1366×768 and 1360×768
+    (AspectRatio::reduced(683, 384), "16:9"),
+    (AspectRatio::reduced(85, 48), "16:9"),
+    // 4096×2160 DCI 4K
+    (AspectRatio::reduced(256, 135), "17:9"),
+];
+/// Finds the common aspect ratio nearest to the given single-number ratio, considering it close enough if the difference is less than the given rounding.
+pub fn find_common_aspect_ratio(ratio: f64, rounding: f64) -> Option<AspectRatio> {
+    AspectRatio::nearest_common(ratio)
         .next()
+        .filter(|common| math::abs(ratio - common.value()) < rounding)
+}
+
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+/// Parses a non-negative decimal number without an exponent into its digits as a whole number and the number of digits after the point.
+fn parse_decimal(s: &str) -> Result<(u64, u32), AspectRatioError> {
+    let s = s.trim();
+    let (whole, fraction) = match s.find('.') {
+        Some(point) => (&s[..point], &s[point + 1..]),
+        None => (s, ""),
+    };
+    let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if whole.len() + fraction.len() == 0 || !all_digits(whole) || !all_digits(fraction) {
+        return Err(AspectRatioError::Malformed);
+    }
+    let mut mantissa = 0_u64;
+    for digit in whole.bytes().chain(fraction.bytes()) {
+        mantissa = mantissa
+            .checked_mul(10)
+            .and_then(|m| m.checked_add(u64::from(digit - b'0')))
+            .ok_or(AspectRatioError::TooLarge)?;
+    }
+    Ok((mantissa, fraction.len() as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::string::ToString;
+
+    fn ratio(numerator: u32, denominator: u32) -> AspectRatio {
+        AspectRatio::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn new_reduces() {
+        assert_eq!(ratio(2560, 1080), ratio(64, 27));
+        assert_eq!(ratio(1920, 1200).to_string(), "8:5");
+        assert_eq!(AspectRatio::new(0, 9), None);
+        assert_eq!(AspectRatio::new(16, 0), None);
+    }
+    #[test]
+    fn approximate() {
+        assert_eq!(
+            AspectRatio::approximate(16.0 / 9.0, 100),
+            Some(ratio(16, 9))
+        );
+        assert_eq!(
+            AspectRatio::approximate(2560.0 / 1080.0, 10_000),
+            Some(ratio(64, 27))
+        );
+        // The convergents of pi are 3, 22/7, 333/106 and 355/113
+        assert_eq!(
+            AspectRatio::approximate(core::f64::consts::PI, 100),
+            Some(ratio(22, 7))
+        );
+        assert_eq!(
+            AspectRatio::approximate(core::f64::consts::PI, 113),
+            Some(ratio(355, 113))
+        );
+        assert_eq!(AspectRatio::approximate(0.0, 100), None);
+        assert_eq!(AspectRatio::approximate(-1.5, 100), None);
+        assert_eq!(AspectRatio::approximate(f64::NAN, 100), None);
+        assert_eq!(AspectRatio::approximate(f64::INFINITY, 100), None);
+    }
+    #[test]
+    fn parse() {
+        assert_eq!("16:9".parse(), Ok(ratio(16, 9)));
+        assert_eq!("16/10".parse(), Ok(ratio(8, 5)));
+        assert_eq!("2560x1080".parse(), Ok(ratio(64, 27)));
+        assert_eq!("3440×1440".parse(), Ok(ratio(43, 18)));
+        assert_eq!(" 21 : 9 ".parse(), Ok(ratio(7, 3)));
+        assert_eq!("2.39:1".parse(), Ok(ratio(239, 100)));
+        assert_eq!("2.39".parse(), Ok(ratio(239, 100)));
+        assert_eq!("1.85:1.5".parse(), Ok(ratio(37, 30)));
+        assert_eq!("0:9".parse::<AspectRatio>(), Err(AspectRatioError::Zero));
+        assert_eq!("".parse::<AspectRatio>(), Err(AspectRatioError::Malformed));
+        assert_eq!(
+            "wide".parse::<AspectRatio>(),
+            Err(AspectRatioError::Malformed)
+        );
+        assert_eq!(
+            "-16:9".parse::<AspectRatio>(),
+            Err(AspectRatioError::Malformed)
+        );
+        assert_eq!(
+            "99999999999:1".parse::<AspectRatio>(),
+            Err(AspectRatioError::TooLarge)
+        );
+    }
+    #[test]
+    fn approximated_ratio_survives_display_and_parse() {
+        // The exact ratio is what the GUI writes into the aspect ratio input, so reading it back
+        // must not snap it to a common one
+        for &[width, height] in &[[2560, 1080], [3440, 1440], [3840, 1600], [1366, 768]] {
+            let approximated =
+                AspectRatio::approximate(f64::from(width) / f64::from(height), 10_000).unwrap();
+            assert_eq!(approximated, ratio(width, height));
+            assert_eq!(approximated.to_string().parse(), Ok(approximated));
+        }
+    }
+    #[test]
+    fn common_and_marketing_names() {
+        assert_eq!(find_common_aspect_ratio(2.37, 0.01), Some(ratio(64, 27)));
+        assert_eq!(find_common_aspect_ratio(1.77, 0.01), Some(ratio(16, 9)));
+        assert_eq!(find_common_aspect_ratio(6.0, 0.1), None);
+        assert_eq!(ratio(64, 27).marketing_name(), Some("21:9"));
+        assert_eq!(ratio(8, 5).marketing_name(), Some("16:10"));
+        assert_eq!(ratio(16, 9).marketing_name(), None);
+    }
 }
@@ -0,0 +1,212 @@
+use crate::math;
+use uom::si::{
+    angle::{degree, radian},
+    f64::{Angle, Length},
+    ratio::ratio,
+};
+
+/// The curvature of a curved monitor.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Curvature {
+    /// The radius of the curvature, i.e. the number in the R rating of the monitor: a 1800R monitor has a radius of 1800 millimeters.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "radius_m", with = "crate::serde_units::meters")
+    )]
+    pub radius: Length,
+    /// How the width in the [dimensions of the monitor] was measured.
+    ///
+    /// [dimensions of the monitor]: enum.MonitorDimensions.html " "
+    pub width_measurement: CurvedWidthMeasurement,
+}
+/// The way the width of a curved monitor was measured.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CurvedWidthMeasurement {
+    /// The width was measured along the curved surface of the screen. This is the case for the sizes listed in spec sheets, which are the sizes of the panel before it was bent.
+    Arc,
+    /// The width was measured along a straight line between the left and right edges of the screen, as with a tape measure held in front of the screen.
+    Chord,
+}
+impl Curvature {
+    /// Returns the half of the angle between the left and right edges of the screen as seen from the center of the curvature, for a given measured width.
+    fn half_central_angle(self, width: Length) -> Angle {
+        let half_width_over_radius = (width / 2.0 / self.radius).get::<ratio>();
+        match self.width_measurement {
+            // The length of an arc is the radius multiplied by the central angle in radians
+            CurvedWidthMeasurement::Arc => Angle::new::<radian>(half_width_over_radius),
+            // Half of the chord is the opposite catet of a right-angled triangle with the radius as
+            // the hypotenuse
+            CurvedWidthMeasurement::Chord => math::asin(half_width_over_radius),
+        }
+    }
+    /// Returns the length of the arc between the left and right edges of the screen, i.e. the width measured along the curved surface, for a given measured width.
+    pub fn arc_width(self, width: Length) -> Length {
+        self.radius * self.half_central_angle(width).get::<radian>() * 2.0
+    }
+    /// Returns the length of the chord between the left and right edges of the screen, i.e. the width measured along a straight line, for a given measured width.
+    pub fn chord_width(self, width: Length) -> Length {
+        self.radius * math::sin(self.half_central_angle(width)) * 2.0
+    }
+    /// Returns the depth of the curve for a given measured width, i.e. how far the center of the screen is behind the chord between its left and right edges.
+    pub fn sagitta(self, width: Length) -> Length {
+        self.radius * (1.0 - math::cos(self.half_central_angle(width)))
+    }
+    /// Returns the angle between the left and right edges of the screen as seen from the center of the curvature, for a given measured width.
+    pub fn central_angle(self, width: Length) -> Angle {
+        self.half_central_angle(width) * 2.0
+    }
+    /// Returns the width, measured the way this curvature specifies, of a screen which is seen at the given horizontal FOV from the given distance, or `None` if bending a screen this way can't reach the FOV from there, in which case its edges would end up beside or behind the eye.
+    pub fn width_for_fov(self, fov: Angle, distance: Length) -> Option<Length> {
+        // An edge is seen at half the FOV when tan(fov / 2) = R sin φ / (d - R + R cos φ), where φ
+        // is the half of the central angle. Multiplying out and dividing by 1 / cos(fov / 2)
+        // turns the left side into sin(φ - fov / 2), which leaves a single arcsine to take.
+        let half_fov = fov / 2.0;
+        let sine = math::sin(half_fov) * ((distance - self.radius) / self.radius).get::<ratio>();
+        if math::abs(sine) > 1.0 {
+            return None;
+        }
+        let half_central_angle = half_fov + math::asin(sine);
+        let edge_distance = distance - self.radius * (1.0 - math::cos(half_central_angle));
+        if edge_distance <= Length::default() {
+            return None;
+        }
+        match self.width_measurement {
+            CurvedWidthMeasurement::Arc => {
+                Some(self.radius * half_central_angle.get::<radian>() * 2.0)
+            }
+            // Past a half circle, the chord gets shorter again and can't be told apart from the
+            // chord of a narrower screen
+            CurvedWidthMeasurement::Chord if half_central_angle > Angle::new::<degree>(90.0) => {
+                None
+            }
+            CurvedWidthMeasurement::Chord => {
+                Some(self.radius * math::sin(half_central_angle) * 2.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MonitorConfiguration, MonitorDimensions};
+    use uom::si::length::{centimeter, meter, millimeter};
+
+    fn curvature(radius_mm: f64, width_measurement: CurvedWidthMeasurement) -> Curvature {
+        Curvature {
+            radius: Length::new::<millimeter>(radius_mm),
+            width_measurement,
+        }
+    }
+    fn curved_monitor(
+        width_cm: f64,
+        distance_m: f64,
+        curvature: Curvature,
+    ) -> MonitorConfiguration {
+        MonitorConfiguration {
+            dimensions: MonitorDimensions::WidthAndHeight {
+                width: Length::new::<centimeter>(width_cm),
+                height: Length::new::<centimeter>(width_cm * 9.0 / 21.0),
+            },
+            distance: Length::new::<meter>(distance_m),
+            eye_offset: None,
+            curvature: Some(curvature),
+            resolution: None,
+        }
+    }
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            math::abs(actual - expected) < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn arc_and_chord_round_trip() {
+        let arc = curvature(1800.0, CurvedWidthMeasurement::Arc);
+        let chord = curvature(1800.0, CurvedWidthMeasurement::Chord);
+        let width = Length::new::<centimeter>(80.0);
+        // Measuring the chord of an arc and then taking the arc of that chord gives back the arc
+        let chord_width = arc.chord_width(width);
+        assert!(chord_width < width);
+        assert_close(chord.arc_width(chord_width).get::<meter>(), 0.8);
+        assert_close(
+            chord.chord_width(chord_width).get::<meter>(),
+            chord_width.get::<meter>(),
+        );
+        assert_close(arc.arc_width(width).get::<meter>(), 0.8);
+        // Both ways of measuring describe the same screen
+        assert_close(
+            arc.central_angle(width).get::<radian>(),
+            chord.central_angle(chord_width).get::<radian>(),
+        );
+        assert_close(
+            arc.sagitta(width).get::<meter>(),
+            chord.sagitta(chord_width).get::<meter>(),
+        );
+    }
+    #[test]
+    fn fov_from_the_center_is_the_central_angle() {
+        for &measurement in &[CurvedWidthMeasurement::Arc, CurvedWidthMeasurement::Chord] {
+            let curvature = curvature(1000.0, measurement);
+            let monitor = curved_monitor(80.0, 1.0, curvature);
+            let width = Length::new::<centimeter>(80.0);
+            assert_close(
+                monitor.fov().get::<radian>(),
+                curvature.central_angle(width).get::<radian>(),
+            );
+        }
+        // The arc of a 1000R screen is the central angle in radians times one meter
+        let monitor = curved_monitor(80.0, 1.0, curvature(1000.0, CurvedWidthMeasurement::Arc));
+        assert_close(monitor.fov().get::<radian>(), 0.8);
+    }
+    #[test]
+    fn width_for_fov_inverts_fov() {
+        for &measurement in &[CurvedWidthMeasurement::Arc, CurvedWidthMeasurement::Chord] {
+            let curvature = curvature(1500.0, measurement);
+            for &distance in &[0.6, 1.0, 1.5, 2.5] {
+                let fov = Angle::new::<degree>(60.0);
+                let width = curvature
+                    .width_for_fov(fov, Length::new::<meter>(distance))
+                    .unwrap();
+                let monitor = curved_monitor(width.get::<centimeter>(), distance, curvature);
+                assert_close(monitor.fov().get::<radian>(), fov.get::<radian>());
+                let dimensions = monitor.dimensions_for_fov(fov).unwrap();
+                assert_close(
+                    dimensions.width_and_height()[0].get::<meter>(),
+                    width.get::<meter>(),
+                );
+            }
+        }
+        // From three radii behind the center of the curvature, even a full circle spans under 40°
+        let curvature = curvature(1000.0, CurvedWidthMeasurement::Arc);
+        assert!(curvature
+            .width_for_fov(Angle::new::<degree>(120.0), Length::new::<meter>(4.0))
+            .is_none());
+    }
+    #[test]
+    fn off_center_warning_thresholds() {
+        let curvature = curvature(1000.0, CurvedWidthMeasurement::Arc);
+        let far_from_center =
+            |distance| curved_monitor(80.0, distance, curvature).is_far_from_curvature_center(0.25);
+        assert!(!far_from_center(1.0));
+        assert!(!far_from_center(1.2));
+        assert!(!far_from_center(0.8));
+        assert!(far_from_center(1.3));
+        assert!(far_from_center(0.7));
+        let offset = curved_monitor(80.0, 0.7, curvature)
+            .distance_from_curvature_center()
+            .unwrap();
+        assert_close(offset.get::<meter>(), -0.3);
+        let flat = MonitorConfiguration {
+            curvature: None,
+            ..curved_monitor(80.0, 5.0, curvature)
+        };
+        assert!(!flat.is_far_from_curvature_center(0.25));
+        assert!(flat.distance_from_curvature_center().is_none());
+    }
+}
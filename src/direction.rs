@@ -0,0 +1,415 @@
+use crate::{math, EyeOffset, Frustum, MonitorConfiguration};
+use uom::si::{
+    angle::{degree, radian},
+    f64::{Angle, Length},
+    length::meter,
+    ratio::ratio,
+};
+
+/// A direction relative to the view direction of a camera or an eye, as the angles by which the view direction has to be turned to point in it: first around the vertical axis by the yaw, then up by the pitch.
+///
+/// This is the order in which first-person games turn the camera, so the angles are the same as the change in the camera angles needed to look in the direction.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewDirection {
+    /// The angle to the right of the view direction. Negative values mean that the direction is to the left.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "yaw_rad", with = "crate::serde_units::radians")
+    )]
+    pub yaw: Angle,
+    /// The angle above the horizontal plane, after the yaw is applied. Negative values mean that the direction is below it.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "pitch_rad", with = "crate::serde_units::radians")
+    )]
+    pub pitch: Angle,
+}
+impl ViewDirection {
+    /// Returns the direction towards the given point, with the X axis pointing right, the Y axis pointing up and the Z axis pointing along the view direction.
+    fn towards([x, y, z]: [f64; 3]) -> Self {
+        Self {
+            yaw: math::atan2(x, z),
+            pitch: math::atan2(y, math::sqrt(x * x + z * z)),
+        }
+    }
+    /// Returns whether the direction points forward rather than to the side or behind.
+    fn is_forward(self) -> bool {
+        let right_angle = Angle::new::<degree>(90.0);
+        self.yaw.abs() < right_angle && self.pitch.abs() < right_angle
+    }
+}
+
+impl Frustum {
+    /// Returns the direction, relative to the view direction of the camera, which is shown at the given position in an image rendered with this frustum at the given resolution.
+    ///
+    /// The position is in pixels from the top left corner of the image, with the Y axis pointing down, meaning that the center of the top left pixel is at `[0.5, 0.5]`. Positions outside of the image are also accepted.
+    pub fn direction_at_pixel(self, resolution: [u32; 2], pixel: [f64; 2]) -> ViewDirection {
+        let [u, v] = fraction_of_image(resolution, pixel);
+        // The image is a rectangle on a plane one unit in front of the camera, spanning the near
+        // plane extents of the frustum at that distance
+        let [left, right, bottom, top] = self.near_plane_extents(Length::new::<meter>(1.0));
+        let lerp = |from: Length, to: Length, t: f64| (from + (to - from) * t).get::<meter>();
+        ViewDirection::towards([lerp(left, right, u + 0.5), lerp(bottom, top, v + 0.5), 1.0])
+    }
+    /// Returns the position in pixels at which the given direction is shown in an image rendered with this frustum at the given resolution, or `None` if the direction points to the side of the camera or behind it. This is the inverse of [`direction_at_pixel`].
+    ///
+    /// The position may lie outside of the image if the direction is outside of the frustum.
+    ///
+    /// [`direction_at_pixel`]: #method.direction_at_pixel " "
+    pub fn pixel_for_direction(
+        self,
+        resolution: [u32; 2],
+        direction: ViewDirection,
+    ) -> Option<[f64; 2]> {
+        if !direction.is_forward() {
+            return None;
+        }
+        // Where the direction crosses the plane one unit in front of the camera...
+        let x = math::tan(direction.yaw);
+        let y = math::tan(direction.pitch) / math::cos(direction.yaw);
+        // ...relative to the extents of the frustum on that plane
+        let [left, right, bottom, top] = self.near_plane_extents(Length::new::<meter>(1.0));
+        let unlerp = |from: Length, to: Length, value: f64| {
+            let [from, to] = [from.get::<meter>(), to.get::<meter>()];
+            (value - from) / (to - from)
+        };
+        Some(pixel_at_fraction(
+            resolution,
+            [unlerp(left, right, x) - 0.5, unlerp(bottom, top, y) - 0.5],
+        ))
+    }
+}
+
+impl MonitorConfiguration {
+    /// Returns the direction in which the given pixel of the screen is seen from the eye, relative to the direction straight at the screen, or `None` if the resolution is unknown. This is the real-world angle subtended at the eye, which for a game rendered with the portal-like [frustum] is also the direction in which the pixel shows the game world.
+    ///
+    /// The position is given the same way as to [`Frustum::direction_at_pixel`]. The [eye offset] and the [curvature] are taken into account, with the pixels of a curved monitor spread evenly along the curve.
+    ///
+    /// [frustum]: #method.frustum " "
+    /// [`Frustum::direction_at_pixel`]: struct.Frustum.html#method.direction_at_pixel " "
+    /// [eye offset]: #structfield.eye_offset " "
+    /// [curvature]: #structfield.curvature " "
+    pub fn direction_at_pixel(self, pixel: [f64; 2]) -> Option<ViewDirection> {
+        let [u, v] = fraction_of_image(self.resolution?, pixel);
+        let [width, height] = self.dimensions.width_and_height();
+        let (horizontal_offset, vertical_offset) = self.eye_offset_or_centered();
+        // The pixel relative to the eye, with the Z axis pointing at the screen
+        let (x, z) = match self.curvature {
+            None => (width * u, self.distance),
+            Some(curvature) => {
+                // The length of an arc is the radius multiplied by the central angle in radians,
+                // which places the pixel on the circle around the center of the curvature
+                let angle = Angle::new::<radian>(
+                    (curvature.arc_width(width) * u / curvature.radius).get::<ratio>(),
+                );
+                (
+                    curvature.radius * math::sin(angle),
+                    self.distance - curvature.radius * (1.0 - math::cos(angle)),
+                )
+            }
+        };
+        let y = height * v - vertical_offset;
+        let x = x - horizontal_offset;
+        Some(ViewDirection::towards([
+            x.get::<meter>(),
+            y.get::<meter>(),
+            z.get::<meter>(),
+        ]))
+    }
+    /// Returns the position of the pixel of the screen which is seen from the eye in the given direction, or `None` if the resolution is unknown or the direction doesn't point towards the screen. This is the inverse of [`direction_at_pixel`].
+    ///
+    /// The position may lie outside of the screen if the direction misses it.
+    ///
+    /// [`direction_at_pixel`]: #method.direction_at_pixel " "
+    pub fn pixel_for_direction(self, direction: ViewDirection) -> Option<[f64; 2]> {
+        let resolution = self.resolution?;
+        if !direction.is_forward() {
+            return None;
+        }
+        let [width, height] = self.dimensions.width_and_height();
+        let (horizontal_offset, vertical_offset) = self.eye_offset_or_centered();
+        let (sin_yaw, cos_yaw) = (math::sin(direction.yaw), math::cos(direction.yaw));
+        // How far the point where the direction meets the screen is from the eye in the horizontal
+        // plane, and how far to the right of the screen center it is along the screen
+        let (reach, along_screen) = match self.curvature {
+            None => {
+                let reach = self.distance / cos_yaw;
+                (reach, reach * sin_yaw + horizontal_offset)
+            }
+            Some(curvature) => {
+                // With the eye at the origin, the center of the curvature is at [-horizontal
+                // offset, distance - radius], and the direction meets the screen one radius away
+                // from it. Of the two solutions of that quadratic equation, the farther one is on
+                // the screen rather than behind the eye.
+                let [center_x, center_z] = [
+                    -horizontal_offset.get::<meter>(),
+                    (self.distance - curvature.radius).get::<meter>(),
+                ];
+                let radius = curvature.radius.get::<meter>();
+                let projected = center_x * sin_yaw + center_z * cos_yaw;
+                let discriminant = projected * projected
+                    - (center_x * center_x + center_z * center_z - radius * radius);
+                if discriminant < 0.0 {
+                    return None;
+                }
+                let reach = projected + math::sqrt(discriminant);
+                let angle = math::atan2(reach * sin_yaw - center_x, reach * cos_yaw - center_z);
+                (
+                    Length::new::<meter>(reach),
+                    curvature.radius * angle.get::<radian>(),
+                )
+            }
+        };
+        let width = match self.curvature {
+            Some(curvature) => curvature.arc_width(width),
+            None => width,
+        };
+        let up_screen = reach * math::tan(direction.pitch) + vertical_offset;
+        Some(pixel_at_fraction(
+            resolution,
+            [
+                (along_screen / width).get::<ratio>(),
+                (up_screen / height).get::<ratio>(),
+            ],
+        ))
+    }
+    fn eye_offset_or_centered(self) -> (Length, Length) {
+        match self.eye_offset {
+            Some(EyeOffset {
+                horizontal,
+                vertical,
+            }) => (horizontal, vertical),
+            None => (Length::default(), Length::default()),
+        }
+    }
+}
+
+/// Converts a position in pixels from the top left corner into a position relative to the center of the image as a fraction of its size, with the Y axis pointing up.
+fn fraction_of_image([width, height]: [u32; 2], [x, y]: [f64; 2]) -> [f64; 2] {
+    [x / width as f64 - 0.5, 0.5 - y / height as f64]
+}
+/// The inverse of `fraction_of_image`.
+fn pixel_at_fraction([width, height]: [u32; 2], [u, v]: [f64; 2]) -> [f64; 2] {
+    [(u + 0.5) * width as f64, (0.5 - v) * height as f64]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Curvature, CurvedWidthMeasurement, MonitorDimensions};
+    use uom::si::length::centimeter;
+
+    const RESOLUTION: [u32; 2] = [2560, 1080];
+
+    fn monitor(
+        eye_offset: Option<EyeOffset>,
+        curvature: Option<Curvature>,
+    ) -> MonitorConfiguration {
+        MonitorConfiguration {
+            dimensions: MonitorDimensions::WidthAndHeight {
+                width: Length::new::<centimeter>(80.0),
+                height: Length::new::<centimeter>(33.75),
+            },
+            distance: Length::new::<centimeter>(70.0),
+            eye_offset,
+            curvature,
+            resolution: Some(RESOLUTION),
+        }
+    }
+    fn offset() -> Option<EyeOffset> {
+        Some(EyeOffset {
+            horizontal: Length::new::<centimeter>(12.0),
+            vertical: Length::new::<centimeter>(-5.0),
+        })
+    }
+    fn curvature() -> Option<Curvature> {
+        Some(Curvature {
+            radius: Length::new::<centimeter>(100.0),
+            width_measurement: CurvedWidthMeasurement::Arc,
+        })
+    }
+    fn direction(yaw: f64, pitch: f64) -> ViewDirection {
+        ViewDirection {
+            yaw: Angle::new::<degree>(yaw),
+            pitch: Angle::new::<degree>(pitch),
+        }
+    }
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            math::abs(actual - expected) < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+    fn assert_direction(actual: ViewDirection, yaw: Angle, pitch: Angle) {
+        assert_close(actual.yaw.get::<radian>(), yaw.get::<radian>());
+        assert_close(actual.pitch.get::<radian>(), pitch.get::<radian>());
+    }
+    fn assert_pixel(actual: [f64; 2], expected: [f64; 2]) {
+        assert_close(actual[0], expected[0]);
+        assert_close(actual[1], expected[1]);
+    }
+
+    #[test]
+    fn center_pixel_is_straight_ahead() {
+        let center = [1280.0, 540.0];
+        let frustum = Frustum::symmetric(Angle::new::<degree>(90.0), Angle::new::<degree>(45.0));
+        let zero = Angle::default();
+        assert_direction(frustum.direction_at_pixel(RESOLUTION, center), zero, zero);
+        assert_direction(
+            monitor(None, None).direction_at_pixel(center).unwrap(),
+            zero,
+            zero,
+        );
+        assert_direction(
+            monitor(None, curvature())
+                .direction_at_pixel(center)
+                .unwrap(),
+            zero,
+            zero,
+        );
+    }
+    #[test]
+    fn edge_pixels_are_at_half_the_fov() {
+        let frustum = Frustum {
+            left: Angle::new::<degree>(50.0),
+            right: Angle::new::<degree>(30.0),
+            top: Angle::new::<degree>(20.0),
+            bottom: Angle::new::<degree>(20.0),
+        };
+        let zero = Angle::default();
+        assert_direction(
+            frustum.direction_at_pixel(RESOLUTION, [0.0, 540.0]),
+            -frustum.left,
+            zero,
+        );
+        assert_direction(
+            frustum.direction_at_pixel(RESOLUTION, [2560.0, 540.0]),
+            frustum.right,
+            zero,
+        );
+        // The column straight ahead is off-center in this frustum
+        let [left, right] = [math::tan(frustum.left), math::tan(frustum.right)];
+        let straight_ahead_column = 2560.0 * left / (left + right);
+        assert_direction(
+            frustum.direction_at_pixel(RESOLUTION, [straight_ahead_column, 0.0]),
+            zero,
+            frustum.top,
+        );
+        assert_direction(
+            frustum.direction_at_pixel(RESOLUTION, [straight_ahead_column, 1080.0]),
+            zero,
+            -frustum.bottom,
+        );
+        for &curvature in &[None, curvature()] {
+            let monitor = monitor(None, curvature);
+            let half_fov = monitor.fov() / 2.0;
+            let half_vertical_fov = monitor.vertical_fov() / 2.0;
+            assert_direction(
+                monitor.direction_at_pixel([0.0, 540.0]).unwrap(),
+                -half_fov,
+                zero,
+            );
+            assert_direction(
+                monitor.direction_at_pixel([2560.0, 540.0]).unwrap(),
+                half_fov,
+                zero,
+            );
+            assert_direction(
+                monitor.direction_at_pixel([1280.0, 1080.0]).unwrap(),
+                zero,
+                -half_vertical_fov,
+            );
+        }
+    }
+    #[test]
+    fn round_trip() {
+        let pixels = [
+            [0.5, 0.5],
+            [100.0, 900.0],
+            [1280.0, 540.0],
+            [2000.0, 300.0],
+            [2559.5, 1079.5],
+        ];
+        let frustum = monitor(offset(), None).frustum();
+        for &pixel in &pixels {
+            let direction = frustum.direction_at_pixel(RESOLUTION, pixel);
+            let back = frustum.pixel_for_direction(RESOLUTION, direction).unwrap();
+            assert_pixel(back, pixel);
+        }
+        for &monitor in &[
+            monitor(None, None),
+            monitor(offset(), None),
+            monitor(None, curvature()),
+            monitor(offset(), curvature()),
+        ] {
+            for &pixel in &pixels {
+                let direction = monitor.direction_at_pixel(pixel).unwrap();
+                let back = monitor.pixel_for_direction(direction).unwrap();
+                assert_pixel(back, pixel);
+            }
+        }
+    }
+    #[test]
+    fn portal_like_frustum_matches_the_screen() {
+        // Rendering with the portal-like frustum shows each direction where the eye sees it
+        let monitor = monitor(offset(), None);
+        let frustum = monitor.frustum();
+        for &pixel in &[[0.0, 0.0], [700.0, 200.0], [2560.0, 1080.0]] {
+            let rendered = frustum.direction_at_pixel(RESOLUTION, pixel);
+            let seen = monitor.direction_at_pixel(pixel).unwrap();
+            assert_direction(rendered, seen.yaw, seen.pitch);
+        }
+    }
+    #[test]
+    fn directions_off_the_screen() {
+        let frustum = Frustum::symmetric(Angle::new::<degree>(90.0), Angle::new::<degree>(45.0));
+        for &(yaw, pitch) in &[(180.0, 0.0), (-95.0, 0.0), (0.0, 90.0), (30.0, -120.0)] {
+            assert_eq!(
+                frustum.pixel_for_direction(RESOLUTION, direction(yaw, pitch)),
+                None
+            );
+            assert_eq!(
+                monitor(None, None).pixel_for_direction(direction(yaw, pitch)),
+                None
+            );
+            assert_eq!(
+                monitor(None, curvature()).pixel_for_direction(direction(yaw, pitch)),
+                None
+            );
+        }
+        // Forward directions outside of the frustum land outside of the image
+        let [x, _] = frustum
+            .pixel_for_direction(RESOLUTION, direction(60.0, 0.0))
+            .unwrap();
+        assert!(x > 2560.0);
+        // From three radii away, the circle of a curved monitor is only seen within about 19.5°
+        // of the view direction
+        let far_from_curve = MonitorConfiguration {
+            distance: Length::new::<centimeter>(200.0),
+            curvature: Some(Curvature {
+                radius: Length::new::<centimeter>(50.0),
+                width_measurement: CurvedWidthMeasurement::Arc,
+            }),
+            ..monitor(None, None)
+        };
+        assert!(far_from_curve
+            .pixel_for_direction(direction(15.0, 0.0))
+            .is_some());
+        assert_eq!(
+            far_from_curve.pixel_for_direction(direction(25.0, 0.0)),
+            None
+        );
+        let unknown_resolution = MonitorConfiguration {
+            resolution: None,
+            ..monitor(None, None)
+        };
+        assert_eq!(unknown_resolution.direction_at_pixel([0.0, 0.0]), None);
+        assert_eq!(
+            unknown_resolution.pixel_for_direction(direction(0.0, 0.0)),
+            None
+        );
+    }
+}
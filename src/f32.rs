@@ -0,0 +1,1193 @@
+use crate::{
+    AspectRatio, CurvedWidthMeasurement, FovAxis, FovConvention, ProjectionModel,
+    ProjectionOptions, ValidationError, Viewport,
+};
+use uom::si::{
+    angle::radian,
+    f32::{Angle, Length},
+    f64 as wide,
+    length::meter,
+};
+
+/// Single-precision version of [`MonitorConfiguration`].
+///
+/// All calculations are performed in double precision and narrowed afterwards, so the results are as accurate as `f32` allows.
+///
+/// [`MonitorConfiguration`]: ../struct.MonitorConfiguration.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonitorConfiguration {
+    /// The dimensions of the monitor.
+    pub dimensions: MonitorDimensions,
+    /// The distance at which the viewer is said to be located from the monitor's surface.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "distance_m", with = "crate::serde_units::meters_f32")
+    )]
+    pub distance: Length,
+    /// The position of the eye relative to the center of the monitor, or `None` if the eye is right in front of the center.
+    pub eye_offset: Option<EyeOffset>,
+    /// The curvature of the monitor, or `None` if the monitor is flat.
+    pub curvature: Option<Curvature>,
+    /// The native resolution of the monitor as `[width, height]` in pixels, or `None` if it's unknown.
+    pub resolution: Option<[u32; 2]>,
+}
+impl MonitorConfiguration {
+    /// Calculates the horizontal viewing angle from the viewpoint towards the monitor. See [`MonitorConfiguration::fov`].
+    ///
+    /// [`MonitorConfiguration::fov`]: ../struct.MonitorConfiguration.html#method.fov " "
+    pub fn fov(self) -> Angle {
+        narrow_angle(self.widen().fov())
+    }
+    /// Calculates the vertical viewing angle from the viewpoint towards the monitor. See [`MonitorConfiguration::vertical_fov`].
+    ///
+    /// [`MonitorConfiguration::vertical_fov`]: ../struct.MonitorConfiguration.html#method.vertical_fov " "
+    pub fn vertical_fov(self) -> Angle {
+        narrow_angle(self.widen().vertical_fov())
+    }
+    /// Calculates the diagonal viewing angle from the viewpoint towards the monitor. See [`MonitorConfiguration::diagonal_fov`].
+    ///
+    /// [`MonitorConfiguration::diagonal_fov`]: ../struct.MonitorConfiguration.html#method.diagonal_fov " "
+    pub fn diagonal_fov(self) -> Angle {
+        narrow_angle(self.widen().diagonal_fov())
+    }
+    /// Calculates the viewing angle from the viewpoint towards the monitor along the specified axis. See [`MonitorConfiguration::fov_along`].
+    ///
+    /// [`MonitorConfiguration::fov_along`]: ../struct.MonitorConfiguration.html#method.fov_along " "
+    pub fn fov_along(self, axis: FovAxis) -> Angle {
+        narrow_angle(self.widen().fov_along(axis))
+    }
+    /// Calculates the value which needs to be given to a game using the specified FOV convention to get the portal-like FOV. See [`MonitorConfiguration::fov_for_convention`].
+    ///
+    /// [`MonitorConfiguration::fov_for_convention`]: ../struct.MonitorConfiguration.html#method.fov_for_convention " "
+    pub fn fov_for_convention(self, convention: FovConvention) -> Angle {
+        narrow_angle(self.widen().fov_for_convention(convention))
+    }
+    /// Calculates the horizontal FOV which makes a scope with the given magnification show objects at that many times their real-world size. See [`MonitorConfiguration::scope_fov`].
+    ///
+    /// [`MonitorConfiguration::scope_fov`]: ../struct.MonitorConfiguration.html#method.scope_fov " "
+    pub fn scope_fov(self, magnification: f32) -> Angle {
+        narrow_angle(self.widen().scope_fov(magnification.into()))
+    }
+    /// Calculates how many times larger than in the real world objects appear at the given horizontal FOV. See [`MonitorConfiguration::magnification_for_fov`].
+    ///
+    /// [`MonitorConfiguration::magnification_for_fov`]: ../struct.MonitorConfiguration.html#method.magnification_for_fov " "
+    pub fn magnification_for_fov(self, fov: Angle) -> f32 {
+        self.widen().magnification_for_fov(widen_angle(fov)) as f32
+    }
+    /// Calculates the FOVs which a game using the given projection model needs to be set to. See [`MonitorConfiguration::projection_fov`].
+    ///
+    /// [`MonitorConfiguration::projection_fov`]: ../struct.MonitorConfiguration.html#method.projection_fov " "
+    pub fn projection_fov(self, model: ProjectionModel) -> ProjectionFov {
+        self.widen().projection_fov(model).into()
+    }
+    /// Calculates the Panini distance which makes the edges of the screen show the right directions at the given vertical FOV. See [`MonitorConfiguration::panini_distance_for_vertical_fov`].
+    ///
+    /// [`MonitorConfiguration::panini_distance_for_vertical_fov`]: ../struct.MonitorConfiguration.html#method.panini_distance_for_vertical_fov " "
+    pub fn panini_distance_for_vertical_fov(self, vertical_fov: Angle) -> Option<f32> {
+        self.widen()
+            .panini_distance_for_vertical_fov(widen_angle(vertical_fov))
+            .map(|distance| distance as f32)
+    }
+    /// Calculates an FOV for the monitor such that a given distance will be represented with accurate scale. See [`MonitorConfiguration::monitor_fov_for_distance`].
+    ///
+    /// [`MonitorConfiguration::monitor_fov_for_distance`]: ../struct.MonitorConfiguration.html#method.monitor_fov_for_distance " "
+    pub fn monitor_fov_for_distance(self, distance: Length, relative_to_monitor: bool) -> Angle {
+        narrow_angle(
+            self.widen()
+                .monitor_fov_for_distance(widen_length(distance), relative_to_monitor),
+        )
+    }
+    /// Calculates the viewing distance from which the monitor is seen at the given horizontal FOV, taking the curvature into account. See [`MonitorConfiguration::distance_for_fov`].
+    ///
+    /// [`MonitorConfiguration::distance_for_fov`]: ../struct.MonitorConfiguration.html#method.distance_for_fov " "
+    pub fn distance_for_fov(self, fov: Angle) -> Length {
+        narrow_length(self.widen().distance_for_fov(widen_angle(fov)))
+    }
+    /// Calculates the dimensions with the same aspect ratio and curvature which are seen at the given horizontal FOV from the viewing distance. See [`MonitorConfiguration::dimensions_for_fov`].
+    ///
+    /// [`MonitorConfiguration::dimensions_for_fov`]: ../struct.MonitorConfiguration.html#method.dimensions_for_fov " "
+    pub fn dimensions_for_fov(self, fov: Angle) -> Option<MonitorDimensions> {
+        self.widen()
+            .dimensions_for_fov(widen_angle(fov))
+            .map(Into::into)
+    }
+    /// Returns the distance from the eye to the center of the curvature, or `None` if the monitor is flat. See [`MonitorConfiguration::distance_from_curvature_center`].
+    ///
+    /// [`MonitorConfiguration::distance_from_curvature_center`]: ../struct.MonitorConfiguration.html#method.distance_from_curvature_center " "
+    pub fn distance_from_curvature_center(self) -> Option<Length> {
+        self.widen()
+            .distance_from_curvature_center()
+            .map(narrow_length)
+    }
+    /// Returns whether the eye is farther from the center of the curvature than the given fraction of the radius. See [`MonitorConfiguration::is_far_from_curvature_center`].
+    ///
+    /// [`MonitorConfiguration::is_far_from_curvature_center`]: ../struct.MonitorConfiguration.html#method.is_far_from_curvature_center " "
+    pub fn is_far_from_curvature_center(self, tolerance: f32) -> bool {
+        self.widen().is_far_from_curvature_center(tolerance.into())
+    }
+    /// Returns the distance between the centers of two neighboring pixels, or `None` if the resolution is unknown. See [`MonitorConfiguration::dot_pitch`].
+    ///
+    /// [`MonitorConfiguration::dot_pitch`]: ../struct.MonitorConfiguration.html#method.dot_pitch " "
+    pub fn dot_pitch(self) -> Option<Length> {
+        self.widen().dot_pitch().map(narrow_length)
+    }
+    /// Returns the pixel density in pixels per inch, or `None` if the resolution is unknown. See [`MonitorConfiguration::pixels_per_inch`].
+    ///
+    /// [`MonitorConfiguration::pixels_per_inch`]: ../struct.MonitorConfiguration.html#method.pixels_per_inch " "
+    pub fn pixels_per_inch(self) -> Option<f32> {
+        self.widen().pixels_per_inch().map(|ppi| ppi as f32)
+    }
+    /// Returns the average number of pixels per degree of the horizontal FOV, or `None` if the resolution is unknown. See [`MonitorConfiguration::average_pixels_per_degree`].
+    ///
+    /// [`MonitorConfiguration::average_pixels_per_degree`]: ../struct.MonitorConfiguration.html#method.average_pixels_per_degree " "
+    pub fn average_pixels_per_degree(self) -> Option<f32> {
+        self.widen()
+            .average_pixels_per_degree()
+            .map(|ppd| ppd as f32)
+    }
+    /// Returns the number of pixels per degree at the center of the screen, or `None` if the resolution is unknown. See [`MonitorConfiguration::center_pixels_per_degree`].
+    ///
+    /// [`MonitorConfiguration::center_pixels_per_degree`]: ../struct.MonitorConfiguration.html#method.center_pixels_per_degree " "
+    pub fn center_pixels_per_degree(self) -> Option<f32> {
+        self.widen()
+            .center_pixels_per_degree()
+            .map(|ppd| ppd as f32)
+    }
+    /// Returns the number of pixels per degree at the left and right edges of the screen, or `None` if the resolution is unknown. See [`MonitorConfiguration::edge_pixels_per_degree`].
+    ///
+    /// [`MonitorConfiguration::edge_pixels_per_degree`]: ../struct.MonitorConfiguration.html#method.edge_pixels_per_degree " "
+    pub fn edge_pixels_per_degree(self) -> Option<f32> {
+        self.widen().edge_pixels_per_degree().map(|ppd| ppd as f32)
+    }
+    /// Returns the angle which a pixel at the center of the screen is seen at, or `None` if the resolution is unknown. See [`MonitorConfiguration::pixel_angle`].
+    ///
+    /// [`MonitorConfiguration::pixel_angle`]: ../struct.MonitorConfiguration.html#method.pixel_angle " "
+    pub fn pixel_angle(self) -> Option<Angle> {
+        self.widen().pixel_angle().map(narrow_angle)
+    }
+    /// Compares the pixels of the monitor to the given visual acuity, or returns `None` if the resolution is unknown. See [`MonitorConfiguration::acuity_analysis`].
+    ///
+    /// [`MonitorConfiguration::acuity_analysis`]: ../struct.MonitorConfiguration.html#method.acuity_analysis " "
+    pub fn acuity_analysis(self, acuity: VisualAcuity) -> Option<AcuityAnalysis> {
+        self.widen().acuity_analysis(acuity.into()).map(Into::into)
+    }
+    /// Calculates the portal-like view frustum, taking the eye offset into account. See [`MonitorConfiguration::frustum`].
+    ///
+    /// [`MonitorConfiguration::frustum`]: ../struct.MonitorConfiguration.html#method.frustum " "
+    pub fn frustum(self) -> Frustum {
+        self.widen().frustum().into()
+    }
+    /// Generates a perspective projection matrix for the portal-like frustum. See [`MonitorConfiguration::projection_matrix`].
+    ///
+    /// [`MonitorConfiguration::projection_matrix`]: ../struct.MonitorConfiguration.html#method.projection_matrix " "
+    pub fn projection_matrix(self, options: ProjectionOptions) -> [f32; 16] {
+        narrow_matrix(self.widen().projection_matrix(options))
+    }
+    /// Returns a configuration for the part of the screen covered by the given viewport. See [`MonitorConfiguration::viewport`].
+    ///
+    /// [`MonitorConfiguration::viewport`]: ../struct.MonitorConfiguration.html#method.viewport " "
+    pub fn viewport(self, viewport: Viewport) -> Option<Self> {
+        self.widen().viewport(viewport).map(Self::from)
+    }
+    /// Calculates the portal-like view through the given viewport. See [`MonitorConfiguration::viewport_view`].
+    ///
+    /// [`MonitorConfiguration::viewport_view`]: ../struct.MonitorConfiguration.html#method.viewport_view " "
+    pub fn viewport_view(self, viewport: Viewport) -> Option<ViewportView> {
+        self.widen().viewport_view(viewport).map(Into::into)
+    }
+    /// Calculates the range of values which the portal-like FOV can take if each input may be off by the given tolerance. See [`MonitorConfiguration::fov_interval`].
+    ///
+    /// [`MonitorConfiguration::fov_interval`]: ../struct.MonitorConfiguration.html#method.fov_interval " "
+    pub fn fov_interval(self, tolerances: Tolerances) -> FovInterval {
+        self.widen().fov_interval(tolerances.into()).into()
+    }
+    /// Calculates the range of values which the FOV from `monitor_fov_for_distance` can take if each input may be off by the given tolerance. See [`MonitorConfiguration::monitor_fov_for_distance_interval`].
+    ///
+    /// [`MonitorConfiguration::monitor_fov_for_distance_interval`]: ../struct.MonitorConfiguration.html#method.monitor_fov_for_distance_interval " "
+    pub fn monitor_fov_for_distance_interval(
+        self,
+        distance: Length,
+        relative_to_monitor: bool,
+        tolerances: Tolerances,
+    ) -> FovInterval {
+        self.widen()
+            .monitor_fov_for_distance_interval(
+                widen_length(distance),
+                relative_to_monitor,
+                tolerances.into(),
+            )
+            .into()
+    }
+    /// Returns the direction in which the given pixel of the screen is seen from the eye, or `None` if the resolution is unknown. See [`MonitorConfiguration::direction_at_pixel`].
+    ///
+    /// [`MonitorConfiguration::direction_at_pixel`]: ../struct.MonitorConfiguration.html#method.direction_at_pixel " "
+    pub fn direction_at_pixel(self, pixel: [f32; 2]) -> Option<ViewDirection> {
+        self.widen()
+            .direction_at_pixel(widen_pixel(pixel))
+            .map(Into::into)
+    }
+    /// Returns the position of the pixel of the screen which is seen from the eye in the given direction. See [`MonitorConfiguration::pixel_for_direction`].
+    ///
+    /// [`MonitorConfiguration::pixel_for_direction`]: ../struct.MonitorConfiguration.html#method.pixel_for_direction " "
+    pub fn pixel_for_direction(self, direction: ViewDirection) -> Option<[f32; 2]> {
+        self.widen()
+            .pixel_for_direction(direction.into())
+            .map(narrow_pixel)
+    }
+    /// Checks that all measurements in the configuration make sense, returning it back if they do. See [`MonitorConfiguration::validate`].
+    ///
+    /// [`MonitorConfiguration::validate`]: ../struct.MonitorConfiguration.html#method.validate " "
+    pub fn validate(self) -> Result<Self, ValidationError> {
+        self.widen().validate().map(Self::from)
+    }
+    fn widen(self) -> crate::MonitorConfiguration {
+        self.into()
+    }
+}
+impl From<crate::MonitorConfiguration> for MonitorConfiguration {
+    fn from(c: crate::MonitorConfiguration) -> Self {
+        Self {
+            dimensions: c.dimensions.into(),
+            distance: narrow_length(c.distance),
+            eye_offset: c.eye_offset.map(Into::into),
+            curvature: c.curvature.map(Into::into),
+            resolution: c.resolution,
+        }
+    }
+}
+impl From<MonitorConfiguration> for crate::MonitorConfiguration {
+    fn from(c: MonitorConfiguration) -> Self {
+        Self {
+            dimensions: c.dimensions.into(),
+            distance: widen_length(c.distance),
+            eye_offset: c.eye_offset.map(Into::into),
+            curvature: c.curvature.map(Into::into),
+            resolution: c.resolution,
+        }
+    }
+}
+
+/// Single-precision version of [`MonitorDimensions`].
+///
+/// [`MonitorDimensions`]: ../enum.MonitorDimensions.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MonitorDimensions {
+    /// Dimensions expressed directly as the width and the height.
+    #[allow(missing_docs)] // Field names are self-explanatory
+    WidthAndHeight {
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "width_m", with = "crate::serde_units::meters_f32")
+        )]
+        width: Length,
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "height_m", with = "crate::serde_units::meters_f32")
+        )]
+        height: Length,
+    },
+    /// Dimensions expressed indirectly as the diagonal and the aspect ratio.
+    DiagonalAndAspect {
+        /// The length of the (imaginary) line drawn between two opposite corners of the monitor.
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "diagonal_m", with = "crate::serde_units::meters_f32")
+        )]
+        diagonal: Length,
+        /// The aspect ratio, i.e. `width / height`.
+        aspect: AspectRatio,
+    },
+}
+impl MonitorDimensions {
+    /// Returns the width and height of the monitor, calculating them indirectly if necessary.
+    pub fn width_and_height(self) -> [Length; 2] {
+        match self {
+            Self::WidthAndHeight { width, height } => [width, height],
+            Self::DiagonalAndAspect { .. } => {
+                let [width, height] = self.widen().width_and_height();
+                [narrow_length(width), narrow_length(height)]
+            }
+        }
+    }
+    /// Returns the size of the monitor along the specified axis, i.e. the width, height or diagonal.
+    pub fn extent_along(self, axis: FovAxis) -> Length {
+        narrow_length(self.widen().extent_along(axis))
+    }
+    /// Calculates the viewing distance from which a flat monitor with these dimensions is seen at the given FOV along the specified axis. See [`MonitorDimensions::distance_for_fov`].
+    ///
+    /// [`MonitorDimensions::distance_for_fov`]: ../enum.MonitorDimensions.html#method.distance_for_fov " "
+    pub fn distance_for_fov(self, fov: Angle, axis: FovAxis) -> Length {
+        narrow_length(self.widen().distance_for_fov(widen_angle(fov), axis))
+    }
+    /// Calculates the dimensions of a flat monitor with the given aspect ratio which is seen at the given FOV along the specified axis from the given viewing distance. See [`MonitorDimensions::for_fov`].
+    ///
+    /// [`MonitorDimensions::for_fov`]: ../enum.MonitorDimensions.html#method.for_fov " "
+    pub fn for_fov(fov: Angle, axis: FovAxis, distance: Length, aspect: AspectRatio) -> Self {
+        crate::MonitorDimensions::for_fov(widen_angle(fov), axis, widen_length(distance), aspect)
+            .into()
+    }
+    /// Returns the aspect ratio of the monitor, calculating it if necessary.
+    pub fn aspect(self) -> f32 {
+        self.widen().aspect() as f32
+    }
+    /// Returns the aspect ratio of the monitor as a fraction, approximating it if necessary. See [`MonitorDimensions::aspect_ratio`].
+    ///
+    /// [`MonitorDimensions::aspect_ratio`]: ../enum.MonitorDimensions.html#method.aspect_ratio " "
+    pub fn aspect_ratio(self) -> Option<AspectRatio> {
+        self.widen().aspect_ratio()
+    }
+    /// Returns the length of the diagonal of the monitor, calculating it if necessary.
+    pub fn diagonal(self) -> Length {
+        match self {
+            Self::WidthAndHeight { .. } => narrow_length(self.widen().diagonal()),
+            Self::DiagonalAndAspect { diagonal, .. } => diagonal,
+        }
+    }
+    /// Re-represents the dimensions as the `WidthAndHeight` variant.
+    pub fn as_width_and_height(self) -> Self {
+        let [width, height] = self.width_and_height();
+        Self::WidthAndHeight { width, height }
+    }
+    /// Re-represents the dimensions as the `DiagonalAndAspect` variant. See [`MonitorDimensions::as_diagonal_and_aspect`].
+    ///
+    /// [`MonitorDimensions::as_diagonal_and_aspect`]: ../enum.MonitorDimensions.html#method.as_diagonal_and_aspect " "
+    pub fn as_diagonal_and_aspect(self) -> Self {
+        match self {
+            Self::WidthAndHeight { .. } => self.widen().as_diagonal_and_aspect().into(),
+            Self::DiagonalAndAspect { .. } => self,
+        }
+    }
+    /// Re-represents the dimensions as the `DiagonalAndAspect` variant, returning `None` if the aspect ratio can't be represented. See [`MonitorDimensions::try_as_diagonal_and_aspect`].
+    ///
+    /// [`MonitorDimensions::try_as_diagonal_and_aspect`]: ../enum.MonitorDimensions.html#method.try_as_diagonal_and_aspect " "
+    pub fn try_as_diagonal_and_aspect(self) -> Option<Self> {
+        Some(Self::DiagonalAndAspect {
+            diagonal: self.diagonal(),
+            aspect: self.aspect_ratio()?,
+        })
+    }
+    /// Checks the dimensions the same way as the checked constructors do, returning them back if they're valid. See [`MonitorDimensions::validate`].
+    ///
+    /// [`MonitorDimensions::validate`]: ../enum.MonitorDimensions.html#method.validate " "
+    pub fn validate(self) -> Result<Self, ValidationError> {
+        self.widen().validate().map(Self::from)
+    }
+    fn widen(self) -> crate::MonitorDimensions {
+        self.into()
+    }
+}
+impl From<crate::MonitorDimensions> for MonitorDimensions {
+    fn from(d: crate::MonitorDimensions) -> Self {
+        match d {
+            crate::MonitorDimensions::WidthAndHeight { width, height } => Self::WidthAndHeight {
+                width: narrow_length(width),
+                height: narrow_length(height),
+            },
+            crate::MonitorDimensions::DiagonalAndAspect { diagonal, aspect } => {
+                Self::DiagonalAndAspect {
+                    diagonal: narrow_length(diagonal),
+                    aspect,
+                }
+            }
+        }
+    }
+}
+impl From<MonitorDimensions> for crate::MonitorDimensions {
+    fn from(d: MonitorDimensions) -> Self {
+        match d {
+            MonitorDimensions::WidthAndHeight { width, height } => Self::WidthAndHeight {
+                width: widen_length(width),
+                height: widen_length(height),
+            },
+            MonitorDimensions::DiagonalAndAspect { diagonal, aspect } => Self::DiagonalAndAspect {
+                diagonal: widen_length(diagonal),
+                aspect,
+            },
+        }
+    }
+}
+
+/// Single-precision version of [`EyeOffset`].
+///
+/// [`EyeOffset`]: ../struct.EyeOffset.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EyeOffset {
+    /// How far to the right of the screen center the eye is located. Negative values mean that the eye is to the left of the center.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "horizontal_m", with = "crate::serde_units::meters_f32")
+    )]
+    pub horizontal: Length,
+    /// How far above the screen center the eye is located. Negative values mean that the eye is below the center.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vertical_m", with = "crate::serde_units::meters_f32")
+    )]
+    pub vertical: Length,
+}
+impl From<crate::EyeOffset> for EyeOffset {
+    fn from(o: crate::EyeOffset) -> Self {
+        Self {
+            horizontal: narrow_length(o.horizontal),
+            vertical: narrow_length(o.vertical),
+        }
+    }
+}
+impl From<EyeOffset> for crate::EyeOffset {
+    fn from(o: EyeOffset) -> Self {
+        Self {
+            horizontal: widen_length(o.horizontal),
+            vertical: widen_length(o.vertical),
+        }
+    }
+}
+
+/// Single-precision version of [`Curvature`].
+///
+/// [`Curvature`]: ../struct.Curvature.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Curvature {
+    /// The radius of the curvature, i.e. the number in the R rating of the monitor.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "radius_m", with = "crate::serde_units::meters_f32")
+    )]
+    pub radius: Length,
+    /// How the width in the dimensions of the monitor was measured.
+    pub width_measurement: CurvedWidthMeasurement,
+}
+impl Curvature {
+    /// Returns the width of the monitor measured along the arc. See [`Curvature::arc_width`].
+    ///
+    /// [`Curvature::arc_width`]: ../struct.Curvature.html#method.arc_width " "
+    pub fn arc_width(self, width: Length) -> Length {
+        narrow_length(self.widen().arc_width(widen_length(width)))
+    }
+    /// Returns the width of the monitor measured along the chord. See [`Curvature::chord_width`].
+    ///
+    /// [`Curvature::chord_width`]: ../struct.Curvature.html#method.chord_width " "
+    pub fn chord_width(self, width: Length) -> Length {
+        narrow_length(self.widen().chord_width(widen_length(width)))
+    }
+    /// Returns how far the middle of the screen lies behind the chord between its edges. See [`Curvature::sagitta`].
+    ///
+    /// [`Curvature::sagitta`]: ../struct.Curvature.html#method.sagitta " "
+    pub fn sagitta(self, width: Length) -> Length {
+        narrow_length(self.widen().sagitta(widen_length(width)))
+    }
+    /// Returns the angle which the screen spans around the center of the curvature. See [`Curvature::central_angle`].
+    ///
+    /// [`Curvature::central_angle`]: ../struct.Curvature.html#method.central_angle " "
+    pub fn central_angle(self, width: Length) -> Angle {
+        narrow_angle(self.widen().central_angle(widen_length(width)))
+    }
+    /// Calculates the width of a monitor with this curvature which is seen at the given horizontal FOV from the given viewing distance. See [`Curvature::width_for_fov`].
+    ///
+    /// [`Curvature::width_for_fov`]: ../struct.Curvature.html#method.width_for_fov " "
+    pub fn width_for_fov(self, fov: Angle, distance: Length) -> Option<Length> {
+        self.widen()
+            .width_for_fov(widen_angle(fov), widen_length(distance))
+            .map(narrow_length)
+    }
+    fn widen(self) -> crate::Curvature {
+        self.into()
+    }
+}
+impl From<crate::Curvature> for Curvature {
+    fn from(c: crate::Curvature) -> Self {
+        Self {
+            radius: narrow_length(c.radius),
+            width_measurement: c.width_measurement,
+        }
+    }
+}
+impl From<Curvature> for crate::Curvature {
+    fn from(c: Curvature) -> Self {
+        Self {
+            radius: widen_length(c.radius),
+            width_measurement: c.width_measurement,
+        }
+    }
+}
+
+/// Single-precision version of [`Frustum`].
+///
+/// [`Frustum`]: ../struct.Frustum.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frustum {
+    /// The angle between the view direction and the left side of the frustum.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "left_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub left: Angle,
+    /// The angle between the view direction and the right side of the frustum.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "right_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub right: Angle,
+    /// The angle between the view direction and the top side of the frustum.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "top_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub top: Angle,
+    /// The angle between the view direction and the bottom side of the frustum.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "bottom_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub bottom: Angle,
+}
+impl Frustum {
+    /// Creates a frustum which is centered on the view direction. See [`Frustum::symmetric`].
+    ///
+    /// [`Frustum::symmetric`]: ../struct.Frustum.html#method.symmetric " "
+    pub fn symmetric(horizontal_fov: Angle, vertical_fov: Angle) -> Self {
+        crate::Frustum::symmetric(widen_angle(horizontal_fov), widen_angle(vertical_fov)).into()
+    }
+    /// Returns the total horizontal FOV of the frustum.
+    pub fn horizontal_fov(self) -> Angle {
+        self.left + self.right
+    }
+    /// Returns the total vertical FOV of the frustum.
+    pub fn vertical_fov(self) -> Angle {
+        self.top + self.bottom
+    }
+    /// Returns the coordinates of the sides of the frustum on a near clipping plane located at the given distance from the eye, in the order `[left, right, bottom, top]`. See [`Frustum::near_plane_extents`].
+    ///
+    /// [`Frustum::near_plane_extents`]: ../struct.Frustum.html#method.near_plane_extents " "
+    pub fn near_plane_extents(self, near: Length) -> [Length; 4] {
+        let [left, right, bottom, top] = self.widen().near_plane_extents(widen_length(near));
+        [
+            narrow_length(left),
+            narrow_length(right),
+            narrow_length(bottom),
+            narrow_length(top),
+        ]
+    }
+    /// Returns the lens shift of the frustum as `[x, y]`. See [`Frustum::lens_shift`].
+    ///
+    /// [`Frustum::lens_shift`]: ../struct.Frustum.html#method.lens_shift " "
+    pub fn lens_shift(self) -> [f32; 2] {
+        let [x, y] = self.widen().lens_shift();
+        [x as f32, y as f32]
+    }
+    /// Generates a perspective projection matrix for the frustum, which is off-axis if the frustum is asymmetric. See [`Frustum::projection_matrix`].
+    ///
+    /// [`Frustum::projection_matrix`]: ../struct.Frustum.html#method.projection_matrix " "
+    pub fn projection_matrix(self, options: ProjectionOptions) -> [f32; 16] {
+        narrow_matrix(self.widen().projection_matrix(options))
+    }
+    /// Returns the direction which is shown at the given position in an image rendered with this frustum at the given resolution. See [`Frustum::direction_at_pixel`].
+    ///
+    /// [`Frustum::direction_at_pixel`]: ../struct.Frustum.html#method.direction_at_pixel " "
+    pub fn direction_at_pixel(self, resolution: [u32; 2], pixel: [f32; 2]) -> ViewDirection {
+        self.widen()
+            .direction_at_pixel(resolution, widen_pixel(pixel))
+            .into()
+    }
+    /// Returns the position in pixels at which the given direction is shown in an image rendered with this frustum at the given resolution. See [`Frustum::pixel_for_direction`].
+    ///
+    /// [`Frustum::pixel_for_direction`]: ../struct.Frustum.html#method.pixel_for_direction " "
+    pub fn pixel_for_direction(
+        self,
+        resolution: [u32; 2],
+        direction: ViewDirection,
+    ) -> Option<[f32; 2]> {
+        self.widen()
+            .pixel_for_direction(resolution, direction.into())
+            .map(narrow_pixel)
+    }
+    fn widen(self) -> crate::Frustum {
+        self.into()
+    }
+}
+impl From<crate::Frustum> for Frustum {
+    fn from(f: crate::Frustum) -> Self {
+        Self {
+            left: narrow_angle(f.left),
+            right: narrow_angle(f.right),
+            top: narrow_angle(f.top),
+            bottom: narrow_angle(f.bottom),
+        }
+    }
+}
+impl From<Frustum> for crate::Frustum {
+    fn from(f: Frustum) -> Self {
+        Self {
+            left: widen_angle(f.left),
+            right: widen_angle(f.right),
+            top: widen_angle(f.top),
+            bottom: widen_angle(f.bottom),
+        }
+    }
+}
+
+/// Single-precision version of [`ViewDirection`].
+///
+/// [`ViewDirection`]: ../struct.ViewDirection.html " "
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewDirection {
+    /// The angle to the right of the view direction. Negative values mean that the direction is to the left.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "yaw_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub yaw: Angle,
+    /// The angle above the horizontal plane, after the yaw is applied. Negative values mean that the direction is below it.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "pitch_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub pitch: Angle,
+}
+impl From<crate::ViewDirection> for ViewDirection {
+    fn from(d: crate::ViewDirection) -> Self {
+        Self {
+            yaw: narrow_angle(d.yaw),
+            pitch: narrow_angle(d.pitch),
+        }
+    }
+}
+impl From<ViewDirection> for crate::ViewDirection {
+    fn from(d: ViewDirection) -> Self {
+        Self {
+            yaw: widen_angle(d.yaw),
+            pitch: widen_angle(d.pitch),
+        }
+    }
+}
+
+/// Single-precision version of [`VisualAcuity`].
+///
+/// [`VisualAcuity`]: ../struct.VisualAcuity.html " "
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VisualAcuity {
+    /// The smallest angle between two points at which they can still be told apart, also known as the minimum angle of resolution.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "resolvable_angle_rad",
+            with = "crate::serde_units::radians_f32"
+        )
+    )]
+    pub resolvable_angle: Angle,
+}
+impl VisualAcuity {
+    /// Creates the acuity from a Snellen fraction, such as 20/20 or 6/6. See [`VisualAcuity::from_snellen`].
+    ///
+    /// [`VisualAcuity::from_snellen`]: ../struct.VisualAcuity.html#method.from_snellen " "
+    pub fn from_snellen(numerator: f32, denominator: f32) -> Self {
+        crate::VisualAcuity::from_snellen(numerator.into(), denominator.into()).into()
+    }
+    /// Returns the acuity of normal vision, i.e. 20/20, which can resolve 1 arcminute.
+    pub fn normal() -> Self {
+        crate::VisualAcuity::normal().into()
+    }
+    /// Calculates the distance beyond which two points separated by the given length can no longer be told apart. See [`VisualAcuity::resolvable_distance`].
+    ///
+    /// [`VisualAcuity::resolvable_distance`]: ../struct.VisualAcuity.html#method.resolvable_distance " "
+    pub fn resolvable_distance(self, separation: Length) -> Length {
+        narrow_length(crate::VisualAcuity::from(self).resolvable_distance(widen_length(separation)))
+    }
+}
+impl Default for VisualAcuity {
+    fn default() -> Self {
+        Self::normal()
+    }
+}
+impl From<crate::VisualAcuity> for VisualAcuity {
+    fn from(a: crate::VisualAcuity) -> Self {
+        Self {
+            resolvable_angle: narrow_angle(a.resolvable_angle),
+        }
+    }
+}
+impl From<VisualAcuity> for crate::VisualAcuity {
+    fn from(a: VisualAcuity) -> Self {
+        Self {
+            resolvable_angle: widen_angle(a.resolvable_angle),
+        }
+    }
+}
+
+/// Single-precision version of [`AcuityAnalysis`].
+///
+/// [`AcuityAnalysis`]: ../struct.AcuityAnalysis.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcuityAnalysis {
+    /// The angle which a pixel at the center of the screen is seen at.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "pixel_angle_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub pixel_angle: Angle,
+    /// The angle which a pixel is seen at divided by the resolvable angle. Values above 1 mean that individual pixels can be told apart.
+    pub pixel_to_resolvable_ratio: f32,
+    /// The viewing distance beyond which individual pixels can no longer be told apart.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "retina_distance_m", with = "crate::serde_units::meters_f32")
+    )]
+    pub retina_distance: Length,
+}
+impl AcuityAnalysis {
+    /// Returns whether individual pixels can be told apart from the viewing distance.
+    pub fn pixels_resolvable(self) -> bool {
+        self.pixel_to_resolvable_ratio > 1.0
+    }
+}
+impl From<crate::AcuityAnalysis> for AcuityAnalysis {
+    fn from(a: crate::AcuityAnalysis) -> Self {
+        Self {
+            pixel_angle: narrow_angle(a.pixel_angle),
+            pixel_to_resolvable_ratio: a.pixel_to_resolvable_ratio as f32,
+            retina_distance: narrow_length(a.retina_distance),
+        }
+    }
+}
+
+/// Single-precision version of [`ProjectionFov`].
+///
+/// [`ProjectionFov`]: ../struct.ProjectionFov.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectionFov {
+    /// The angle between the directions shown at the left and right edges of the screen, along the center row.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "horizontal_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub horizontal: Angle,
+    /// The angle between the directions shown at the top and bottom edges of the screen, along the center column.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vertical_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub vertical: Angle,
+}
+impl From<crate::ProjectionFov> for ProjectionFov {
+    fn from(f: crate::ProjectionFov) -> Self {
+        Self {
+            horizontal: narrow_angle(f.horizontal),
+            vertical: narrow_angle(f.vertical),
+        }
+    }
+}
+
+/// Single-precision version of [`ViewportView`].
+///
+/// [`ViewportView`]: ../struct.ViewportView.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewportView {
+    /// The horizontal FOV of the view.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "fov_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub fov: Angle,
+    /// The vertical FOV of the view.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vertical_fov_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub vertical_fov: Angle,
+    /// The frustum of the view, which is asymmetric unless the viewport is right in front of the eye.
+    pub frustum: Frustum,
+    /// The lens shift of the frustum as `[x, y]`.
+    pub lens_shift: [f32; 2],
+}
+impl From<crate::ViewportView> for ViewportView {
+    fn from(v: crate::ViewportView) -> Self {
+        let [x, y] = v.lens_shift;
+        Self {
+            fov: narrow_angle(v.fov),
+            vertical_fov: narrow_angle(v.vertical_fov),
+            frustum: v.frustum.into(),
+            lens_shift: [x as f32, y as f32],
+        }
+    }
+}
+
+/// Single-precision version of [`Tolerances`].
+///
+/// [`Tolerances`]: ../struct.Tolerances.html " "
+#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tolerances {
+    /// The tolerance of each of the lengths in the dimensions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dimensions_m", with = "crate::serde_units::meters_f32")
+    )]
+    pub dimensions: Length,
+    /// The tolerance of the viewing distance.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "distance_m", with = "crate::serde_units::meters_f32")
+    )]
+    pub distance: Length,
+    /// The tolerance of the radius of the curvature, ignored for flat monitors.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "curvature_radius_m", with = "crate::serde_units::meters_f32")
+    )]
+    pub curvature_radius: Length,
+}
+impl From<crate::Tolerances> for Tolerances {
+    fn from(t: crate::Tolerances) -> Self {
+        Self {
+            dimensions: narrow_length(t.dimensions),
+            distance: narrow_length(t.distance),
+            curvature_radius: narrow_length(t.curvature_radius),
+        }
+    }
+}
+impl From<Tolerances> for crate::Tolerances {
+    fn from(t: Tolerances) -> Self {
+        Self {
+            dimensions: widen_length(t.dimensions),
+            distance: widen_length(t.distance),
+            curvature_radius: widen_length(t.curvature_radius),
+        }
+    }
+}
+
+/// Single-precision version of [`FovInterval`].
+///
+/// [`FovInterval`]: ../struct.FovInterval.html " "
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FovInterval {
+    /// The smallest possible value.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "min_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub min: Angle,
+    /// The largest possible value.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "max_rad", with = "crate::serde_units::radians_f32")
+    )]
+    pub max: Angle,
+}
+impl FovInterval {
+    /// Returns the middle of the interval.
+    pub fn center(self) -> Angle {
+        (self.min + self.max) / 2.0
+    }
+    /// Returns how far the ends of the interval are from its center.
+    pub fn tolerance(self) -> Angle {
+        (self.max - self.min) / 2.0
+    }
+    /// Returns whether the given FOV lies within the interval.
+    pub fn contains(self, fov: Angle) -> bool {
+        self.min <= fov && fov <= self.max
+    }
+}
+impl From<crate::FovInterval> for FovInterval {
+    fn from(i: crate::FovInterval) -> Self {
+        Self {
+            min: narrow_angle(i.min),
+            max: narrow_angle(i.max),
+        }
+    }
+}
+impl From<FovInterval> for crate::FovInterval {
+    fn from(i: FovInterval) -> Self {
+        Self {
+            min: widen_angle(i.min),
+            max: widen_angle(i.max),
+        }
+    }
+}
+impl core::fmt::Display for FovInterval {
+    /// Displays the interval in degrees as its center plus or minus the tolerance, the same way as the double-precision version does.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::FovInterval::from(*self).fmt(f)
+    }
+}
+
+/// Single-precision version of [`convert_fov`].
+///
+/// [`convert_fov`]: ../fn.convert_fov.html " "
+pub fn convert_fov(fov: Angle, from: FovAxis, to: FovAxis, aspect: f32) -> Angle {
+    narrow_angle(crate::convert_fov(
+        widen_angle(fov),
+        from,
+        to,
+        aspect.into(),
+    ))
+}
+
+fn narrow_length(length: wide::Length) -> Length {
+    Length::new::<meter>(length.get::<meter>() as f32)
+}
+fn widen_length(length: Length) -> wide::Length {
+    wide::Length::new::<meter>(length.get::<meter>().into())
+}
+fn narrow_angle(angle: wide::Angle) -> Angle {
+    Angle::new::<radian>(angle.get::<radian>() as f32)
+}
+fn widen_angle(angle: Angle) -> wide::Angle {
+    wide::Angle::new::<radian>(angle.get::<radian>().into())
+}
+fn narrow_pixel([x, y]: [f64; 2]) -> [f32; 2] {
+    [x as f32, y as f32]
+}
+fn widen_pixel([x, y]: [f32; 2]) -> [f64; 2] {
+    [x.into(), y.into()]
+}
+fn narrow_matrix(matrix: [f64; 16]) -> [f32; 16] {
+    let mut narrowed = [0.0; 16];
+    for (n, m) in narrowed.iter_mut().zip(matrix.iter()) {
+        *n = *m as f32;
+    }
+    narrowed
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use crate::math;
+    use std::string::ToString;
+    use uom::si::length::{centimeter, inch};
+
+    /// Checks that a single-precision result is the double-precision one, narrowed.
+    fn assert_agrees(narrow: f32, wide: f64) {
+        assert!(
+            math::abs(f64::from(narrow) - wide) <= math::abs(wide) * f64::from(f32::EPSILON),
+            "{} is not {}",
+            narrow,
+            wide
+        );
+    }
+    fn assert_angle_agrees(narrow: Angle, wide: wide::Angle) {
+        assert_agrees(narrow.get::<radian>(), wide.get::<radian>());
+    }
+    fn assert_length_agrees(narrow: Length, wide: wide::Length) {
+        assert_agrees(narrow.get::<meter>(), wide.get::<meter>());
+    }
+    /// Returns a curved 1440p monitor seen from off-center, along with its double-precision version built from the same single-precision values.
+    fn monitor() -> (MonitorConfiguration, crate::MonitorConfiguration) {
+        let narrow = MonitorConfiguration {
+            dimensions: MonitorDimensions::DiagonalAndAspect {
+                diagonal: Length::new::<inch>(34.0),
+                aspect: AspectRatio::new(21, 9).unwrap(),
+            },
+            distance: Length::new::<centimeter>(65.0),
+            eye_offset: Some(EyeOffset {
+                horizontal: Length::new::<centimeter>(3.0),
+                vertical: Length::new::<centimeter>(-2.0),
+            }),
+            curvature: Some(Curvature {
+                radius: Length::new::<meter>(1.5),
+                width_measurement: CurvedWidthMeasurement::Arc,
+            }),
+            resolution: Some([3440, 1440]),
+        };
+        (narrow, narrow.into())
+    }
+
+    #[test]
+    fn monitor_configuration_agrees() {
+        let (narrow, wide) = monitor();
+        assert_angle_agrees(narrow.fov(), wide.fov());
+        assert_angle_agrees(narrow.vertical_fov(), wide.vertical_fov());
+        assert_angle_agrees(narrow.diagonal_fov(), wide.diagonal_fov());
+        let fov = Angle::new::<radian>(1.5);
+        assert_length_agrees(
+            narrow.distance_for_fov(fov),
+            wide.distance_for_fov(widen_angle(fov)),
+        );
+        assert_agrees(
+            narrow.pixels_per_inch().unwrap(),
+            wide.pixels_per_inch().unwrap(),
+        );
+        assert_agrees(
+            narrow.edge_pixels_per_degree().unwrap(),
+            wide.edge_pixels_per_degree().unwrap(),
+        );
+        let direction = narrow.direction_at_pixel([100.0, 200.0]).unwrap();
+        let wide_direction = wide.direction_at_pixel([100.0, 200.0]).unwrap();
+        assert_angle_agrees(direction.yaw, wide_direction.yaw);
+        assert_angle_agrees(direction.pitch, wide_direction.pitch);
+        assert!(narrow.validate().is_ok());
+        // Widening and narrowing again gives back the same values
+        let round_trip = MonitorConfiguration::from(wide);
+        assert_eq!(round_trip.distance, narrow.distance);
+        assert_eq!(
+            round_trip.dimensions.diagonal(),
+            narrow.dimensions.diagonal()
+        );
+    }
+    #[test]
+    fn monitor_dimensions_agree() {
+        let (narrow, wide) = monitor();
+        let (narrow, wide) = (narrow.dimensions, wide.dimensions);
+        assert_length_agrees(narrow.diagonal(), wide.diagonal());
+        assert_agrees(narrow.aspect(), wide.aspect());
+        let [width, height] = narrow.width_and_height();
+        let [wide_width, wide_height] = wide.width_and_height();
+        assert_length_agrees(width, wide_width);
+        assert_length_agrees(height, wide_height);
+        let fov = Angle::new::<radian>(1.2);
+        let distance = narrow.distance_for_fov(fov, FovAxis::Vertical);
+        assert_length_agrees(
+            distance,
+            wide.distance_for_fov(widen_angle(fov), FovAxis::Vertical),
+        );
+    }
+    #[test]
+    fn eye_offset_round_trips() {
+        let (narrow, _) = monitor();
+        let offset = narrow.eye_offset.unwrap();
+        let round_trip = EyeOffset::from(crate::EyeOffset::from(offset));
+        assert_eq!(round_trip.horizontal, offset.horizontal);
+        assert_eq!(round_trip.vertical, offset.vertical);
+    }
+    #[test]
+    fn curvature_agrees() {
+        let (narrow, wide) = monitor();
+        let (curvature, wide_curvature) = (narrow.curvature.unwrap(), wide.curvature.unwrap());
+        let width = Length::new::<centimeter>(80.0);
+        let wide_width = widen_length(width);
+        assert_length_agrees(
+            curvature.chord_width(width),
+            wide_curvature.chord_width(wide_width),
+        );
+        assert_length_agrees(curvature.sagitta(width), wide_curvature.sagitta(wide_width));
+        assert_angle_agrees(
+            curvature.central_angle(width),
+            wide_curvature.central_angle(wide_width),
+        );
+    }
+    #[test]
+    fn frustum_agrees() {
+        let (narrow, wide) = monitor();
+        let (frustum, wide_frustum) = (narrow.frustum(), wide.frustum());
+        assert_angle_agrees(frustum.left, wide_frustum.left);
+        assert_angle_agrees(frustum.top, wide_frustum.top);
+        // The methods of the frustum itself start from its single-precision angles
+        let wide_frustum = crate::Frustum::from(frustum);
+        let near = Length::new::<centimeter>(10.0);
+        let extents = frustum.near_plane_extents(near);
+        let wide_extents = wide_frustum.near_plane_extents(widen_length(near));
+        for (&extent, &wide_extent) in extents.iter().zip(wide_extents.iter()) {
+            assert_length_agrees(extent, wide_extent);
+        }
+        let [x, y] = frustum.lens_shift();
+        let [wide_x, wide_y] = wide_frustum.lens_shift();
+        assert_agrees(x, wide_x);
+        assert_agrees(y, wide_y);
+    }
+    #[test]
+    fn view_direction_round_trips() {
+        let (narrow, _) = monitor();
+        let direction = narrow.direction_at_pixel([0.0, 0.0]).unwrap();
+        assert_eq!(
+            ViewDirection::from(crate::ViewDirection::from(direction)),
+            direction
+        );
+        assert!(narrow.pixel_for_direction(direction).is_some());
+    }
+    #[test]
+    fn visual_acuity_agrees() {
+        let acuity = VisualAcuity::from_snellen(20.0, 40.0);
+        let wide_acuity = crate::VisualAcuity::from_snellen(20.0, 40.0);
+        assert_angle_agrees(acuity.resolvable_angle, wide_acuity.resolvable_angle);
+        let separation = Length::new::<centimeter>(0.025);
+        assert_length_agrees(
+            acuity.resolvable_distance(separation),
+            wide_acuity.resolvable_distance(widen_length(separation)),
+        );
+        assert_eq!(VisualAcuity::default(), VisualAcuity::normal());
+    }
+    #[test]
+    fn acuity_analysis_agrees() {
+        let (narrow, wide) = monitor();
+        let analysis = narrow.acuity_analysis(VisualAcuity::normal()).unwrap();
+        let wide_analysis = wide.acuity_analysis(crate::VisualAcuity::normal()).unwrap();
+        assert_angle_agrees(analysis.pixel_angle, wide_analysis.pixel_angle);
+        assert_agrees(
+            analysis.pixel_to_resolvable_ratio,
+            wide_analysis.pixel_to_resolvable_ratio,
+        );
+        assert_length_agrees(analysis.retina_distance, wide_analysis.retina_distance);
+        assert_eq!(
+            analysis.pixels_resolvable(),
+            wide_analysis.pixels_resolvable()
+        );
+    }
+    #[test]
+    fn projection_fov_agrees() {
+        let (narrow, wide) = monitor();
+        let model = ProjectionModel::Cylindrical;
+        let (fov, wide_fov) = (narrow.projection_fov(model), wide.projection_fov(model));
+        assert_angle_agrees(fov.horizontal, wide_fov.horizontal);
+        assert_angle_agrees(fov.vertical, wide_fov.vertical);
+    }
+    #[test]
+    fn viewport_view_agrees() {
+        let (narrow, wide) = monitor();
+        let viewport = Viewport::fit(AspectRatio::new(16, 9).unwrap(), 21.0 / 9.0);
+        let view = narrow.viewport_view(viewport).unwrap();
+        let wide_view = wide.viewport_view(viewport).unwrap();
+        assert_angle_agrees(view.fov, wide_view.fov);
+        assert_angle_agrees(view.vertical_fov, wide_view.vertical_fov);
+        assert_angle_agrees(view.frustum.right, wide_view.frustum.right);
+        assert_agrees(view.lens_shift[1], wide_view.lens_shift[1]);
+    }
+    #[test]
+    fn tolerances_round_trip() {
+        let tolerances = Tolerances {
+            dimensions: Length::new::<centimeter>(0.5),
+            distance: Length::new::<centimeter>(2.0),
+            curvature_radius: Length::new::<centimeter>(10.0),
+        };
+        let round_trip = Tolerances::from(crate::Tolerances::from(tolerances));
+        assert_eq!(round_trip.dimensions, tolerances.dimensions);
+        assert_eq!(round_trip.distance, tolerances.distance);
+        assert_eq!(round_trip.curvature_radius, tolerances.curvature_radius);
+    }
+    #[test]
+    fn fov_interval_agrees() {
+        let (narrow, wide) = monitor();
+        let tolerances = Tolerances {
+            distance: Length::new::<centimeter>(2.0),
+            ..Tolerances::default()
+        };
+        let interval = narrow.fov_interval(tolerances);
+        let wide_interval = wide.fov_interval(tolerances.into());
+        assert_angle_agrees(interval.min, wide_interval.min);
+        assert_angle_agrees(interval.max, wide_interval.max);
+        assert!(interval.contains(narrow.fov()));
+        assert_eq!(interval.to_string(), wide_interval.to_string());
+    }
+    #[test]
+    fn convert_fov_agrees() {
+        let fov = Angle::new::<radian>(1.6);
+        assert_angle_agrees(
+            convert_fov(fov, FovAxis::Horizontal, FovAxis::Vertical, 16.0 / 9.0),
+            crate::convert_fov(
+                widen_angle(fov),
+                FovAxis::Horizontal,
+                FovAxis::Vertical,
+                f64::from(16.0f32 / 9.0),
+            ),
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::math;
+use uom::si::f64::Angle;
+
+/// An axis of the screen along which a field of view can be measured.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FovAxis {
+    /// The field of view from the left edge of the screen to the right edge.
+    Horizontal,
+    /// The field of view from the top edge of the screen to the bottom edge.
+    Vertical,
+    /// The field of view from one corner of the screen to the opposite one.
+    Diagonal,
+}
+impl FovAxis {
+    /// All axes, in the order in which they're usually displayed.
+    pub const ALL: [Self; 3] = [Self::Horizontal, Self::Vertical, Self::Diagonal];
+    /// Returns the length of the screen along the axis, relative to the height of the screen, for a given aspect ratio.
+    pub fn extent_for_aspect(self, aspect: f64) -> f64 {
+        match self {
+            Self::Horizontal => aspect,
+            Self::Vertical => 1.0,
+            // Pythagorean theorem with the height taken as 1
+            Self::Diagonal => math::sqrt(aspect * aspect + 1.0),
+        }
+    }
+}
+
+/// Converts a field of view measured along one axis of a screen with the given aspect ratio (`width / height`) into the field of view along another axis of the same screen.
+///
+/// This assumes a rectilinear projection with the eye in front of the center of the screen, which is what virtually all 3D rendering uses.
+pub fn convert_fov(fov: Angle, from: FovAxis, to: FovAxis, aspect: f64) -> Angle {
+    if from == to {
+        return fov;
+    }
+    // The tangent of the half-angle is the half-extent of the screen along the axis divided by the
+    // distance to the screen. The distance is the same for all axes, so the tangents are in the
+    // same proportion as the extents.
+    let half_tangent = math::tan(fov / 2.0);
+    let scale = to.extent_for_aspect(aspect) / from.extent_for_aspect(aspect);
+    math::atan(half_tangent * scale) * 2.0
+}
+
+/// Calculates the field of view which makes everything in the middle of the image appear the given number of times larger than with the given field of view, which is what looking through a scope with that magnification does.
+///
+/// Magnification scales the tangent of the half-angle rather than the angle itself, so halving the FOV gives slightly more than 2× magnification. Since the tangents along all axes are scaled by the same factor, the FOVs can be measured along any axis as long as it's the same one for both.
+pub fn zoomed_fov(fov: Angle, magnification: f64) -> Angle {
+    math::atan(math::tan(fov / 2.0) / magnification) * 2.0
+}
+/// Calculates how many times larger everything in the middle of the image appears with the zoomed field of view than with the base one. This is the reverse of [`zoomed_fov`].
+///
+/// [`zoomed_fov`]: fn.zoomed_fov.html " "
+pub fn magnification(base_fov: Angle, zoomed_fov: Angle) -> f64 {
+    math::tan(base_fov / 2.0) / math::tan(zoomed_fov / 2.0)
+}
+
+/// A convention used by a game or engine for the FOV value which it accepts in its settings.
+///
+/// Few games take the actual horizontal FOV of the rendered image. This describes how the value the game expects relates to it, so that the physically correct FOV can be converted into what needs to be entered into the game's settings.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FovConvention {
+    /// The value is the actual horizontal FOV. This is what games with Vert- scaling use, since they keep the horizontal FOV fixed and crop the image vertically on wider screens.
+    Horizontal,
+    /// The value is the actual vertical FOV. This is what games with unnormalized Hor+ scaling use, since they keep the vertical FOV fixed and extend the image horizontally on wider screens. Unity cameras also use this.
+    Vertical,
+    /// The value is the actual diagonal FOV.
+    Diagonal,
+    /// The value is the horizontal FOV which the image would have if the screen had the specified aspect ratio with the same vertical FOV. This is Hor+ scaling with the horizontal FOV normalized to a reference aspect ratio, most commonly 4:3 as used by Source engine games.
+    HorPlus {
+        /// The aspect ratio to which the horizontal FOV is normalized, i.e. `width / height`.
+        reference_aspect: f64,
+    },
+}
+impl FovConvention {
+    /// Hor+ scaling with the horizontal FOV normalized to 4:3, as used by Source engine games.
+    pub const HOR_PLUS_4_3: Self = Self::HorPlus {
+        reference_aspect: 4.0 / 3.0,
+    };
+    /// Converts the actual horizontal FOV of a screen with the given aspect ratio into the value expected by the convention.
+    pub fn value_for(self, horizontal_fov: Angle, aspect: f64) -> Angle {
+        let convert = |to| convert_fov(horizontal_fov, FovAxis::Horizontal, to, aspect);
+        match self {
+            Self::Horizontal => horizontal_fov,
+            Self::Vertical => convert(FovAxis::Vertical),
+            Self::Diagonal => convert(FovAxis::Diagonal),
+            Self::HorPlus { reference_aspect } => convert_fov(
+                convert(FovAxis::Vertical),
+                FovAxis::Vertical,
+                FovAxis::Horizontal,
+                reference_aspect,
+            ),
+        }
+    }
+    /// Converts a value expected by the convention into the actual horizontal FOV of a screen with the given aspect ratio. This is the reverse of [`value_for`].
+    ///
+    /// [`value_for`]: #method.value_for " "
+    pub fn horizontal_fov_for(self, value: Angle, aspect: f64) -> Angle {
+        let convert = |from| convert_fov(value, from, FovAxis::Horizontal, aspect);
+        match self {
+            Self::Horizontal => value,
+            Self::Vertical => convert(FovAxis::Vertical),
+            Self::Diagonal => convert(FovAxis::Diagonal),
+            Self::HorPlus { reference_aspect } => {
+                let vertical_fov = convert_fov(
+                    value,
+                    FovAxis::Horizontal,
+                    FovAxis::Vertical,
+                    reference_aspect,
+                );
+                convert_fov(vertical_fov, FovAxis::Vertical, FovAxis::Horizontal, aspect)
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::math;
+use uom::si::f64::{Angle, Length};
+
+/// The position of the eye relative to the center of the screen, measured in the plane of the screen.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EyeOffset {
+    /// How far to the right of the screen center the eye is located. Negative values mean that the eye is to the left of the center.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "horizontal_m", with = "crate::serde_units::meters")
+    )]
+    pub horizontal: Length,
+    /// How far above the screen center the eye is located. Negative values mean that the eye is below the center.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vertical_m", with = "crate::serde_units::meters")
+    )]
+    pub vertical: Length,
+}
+
+/// A view frustum, possibly asymmetric, expressed as the angles between the view direction and each of its four sides.
+///
+/// Each angle is positive if the corresponding side lies on its own side of the view direction, i.e. all four angles are positive and pairwise equal for a symmetric frustum. An angle is negative if the eye is so far off-center that the view direction doesn't pass through the screen at all.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frustum {
+    /// The angle between the view direction and the left side of the frustum.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "left_rad", with = "crate::serde_units::radians")
+    )]
+    pub left: Angle,
+    /// The angle between the view direction and the right side of the frustum.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "right_rad", with = "crate::serde_units::radians")
+    )]
+    pub right: Angle,
+    /// The angle between the view direction and the top side of the frustum.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "top_rad", with = "crate::serde_units::radians")
+    )]
+    pub top: Angle,
+    /// The angle between the view direction and the bottom side of the frustum.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "bottom_rad", with = "crate::serde_units::radians")
+    )]
+    pub bottom: Angle,
+}
+impl Frustum {
+    /// Creates a symmetric frustum from its horizontal and vertical FOV.
+    pub fn symmetric(horizontal_fov: Angle, vertical_fov: Angle) -> Self {
+        Self {
+            left: horizontal_fov / 2.0,
+            right: horizontal_fov / 2.0,
+            top: vertical_fov / 2.0,
+            bottom: vertical_fov / 2.0,
+        }
+    }
+    /// Returns the total horizontal FOV of the frustum.
+    pub fn horizontal_fov(self) -> Angle {
+        self.left + self.right
+    }
+    /// Returns the total vertical FOV of the frustum.
+    pub fn vertical_fov(self) -> Angle {
+        self.top + self.bottom
+    }
+    /// Returns the coordinates of the sides of the frustum on a near clipping plane located at the given distance from the eye, in the order `[left, right, bottom, top]`.
+    ///
+    /// The coordinates are relative to the view direction, with the X axis pointing right and the Y axis pointing up, meaning that `left` and `bottom` are negative for a symmetric frustum. This is the form accepted by `glFrustum` and most off-axis projection functions in engines.
+    pub fn near_plane_extents(self, near: Length) -> [Length; 4] {
+        [
+            -math::tan(self.left) * near,
+            math::tan(self.right) * near,
+            -math::tan(self.bottom) * near,
+            math::tan(self.top) * near,
+        ]
+    }
+    /// Returns the lens shift of the frustum as `[x, y]`, i.e. the offset of the center of the frustum from the view direction as a fraction of the frustum's width and height respectively.
+    ///
+    /// This is the form of off-axis projection used by physical cameras in engines, most notably Unity. A symmetric frustum has a lens shift of zero.
+    pub fn lens_shift(self) -> [f64; 2] {
+        let shift = |negative: Angle, positive: Angle| {
+            let (negative, positive) = (-math::tan(negative), math::tan(positive));
+            (negative + positive) / (positive - negative) / 2.0
+        };
+        [shift(self.left, self.right), shift(self.bottom, self.top)]
+    }
+}
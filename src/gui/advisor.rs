@@ -0,0 +1,220 @@
+use crate::{
+    layout::{LayoutGen, Position, Rect, Size},
+    util::{friendly_aspect, friendly_degrees, friendly_ftoa, PosExt, Repack},
+    RcUi, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
+};
+use fltk::{frame::Frame, group::Group, input::FloatInput, output::MultilineOutput, prelude::*};
+use fpvsetup::{display_candidates, rank_display_candidates, DisplayRequirements};
+use std::{fmt::Write, rc::Rc};
+use uom::si::{angle::degree, f64::Angle, length::inch};
+
+/// How many lines of the candidate list are visible without scrolling.
+const CANDIDATES_OUTPUT_LINES: i32 = 6;
+
+#[derive(Clone)]
+pub struct Advisor {
+    pub containing_group: Group,
+    pub fov_label_1: Frame,
+    pub min_fov_input: FloatInput,
+    pub fov_label_2: Frame,
+    pub max_fov_input: FloatInput,
+    pub fov_label_3: Frame,
+    pub min_ppd_label: Frame,
+    pub min_ppd_input: FloatInput,
+    pub candidates_output: MultilineOutput,
+}
+impl Advisor {
+    pub fn new(ui: &RcUi) -> Self {
+        let containing_group = Group::default().with_label("Advisor");
+
+        let fov_label_1 = Frame::default().with_label("Desired field of view from");
+        let mut min_fov_input = FloatInput::default();
+        let r = Rc::clone(ui);
+        min_fov_input.set_callback(move || Self::update(&r));
+        min_fov_input.set_trigger(CallbackTrigger::Changed);
+        let fov_label_2 = Frame::default().with_label("° to");
+        let mut max_fov_input = FloatInput::default();
+        let r = Rc::clone(ui);
+        max_fov_input.set_callback(move || Self::update(&r));
+        max_fov_input.set_trigger(CallbackTrigger::Changed);
+        let fov_label_3 = Frame::default().with_label("°, horizontal");
+
+        let min_ppd_label = Frame::default().with_label("Minimum pixels per degree:");
+        let mut min_ppd_input = FloatInput::default();
+        min_ppd_input.set_value("0");
+        let r = Rc::clone(ui);
+        min_ppd_input.set_callback(move || Self::update(&r));
+        min_ppd_input.set_trigger(CallbackTrigger::Changed);
+
+        let candidates_output = MultilineOutput::default();
+
+        containing_group.end();
+
+        Self {
+            containing_group,
+            fov_label_1,
+            min_fov_input,
+            fov_label_2,
+            max_fov_input,
+            fov_label_3,
+            min_ppd_label,
+            min_ppd_input,
+            candidates_output,
+        }
+    }
+    pub fn apply_layout(&mut self, layout: &AdvisorLayout, pos: Position) {
+        self.containing_group
+            .set_rect(layout.containing_group.with_added_pos(pos));
+        self.fov_label_1
+            .set_rect(layout.fov_label_1.with_added_pos(pos));
+        self.min_fov_input
+            .set_rect(layout.min_fov_input.with_added_pos(pos));
+        self.fov_label_2
+            .set_rect(layout.fov_label_2.with_added_pos(pos));
+        self.max_fov_input
+            .set_rect(layout.max_fov_input.with_added_pos(pos));
+        self.fov_label_3
+            .set_rect(layout.fov_label_3.with_added_pos(pos));
+        self.min_ppd_label
+            .set_rect(layout.min_ppd_label.with_added_pos(pos));
+        self.min_ppd_input
+            .set_rect(layout.min_ppd_input.with_added_pos(pos));
+        self.candidates_output
+            .set_rect(layout.candidates_output.with_added_pos(pos));
+    }
+    pub fn update(ui: &RcUi) {
+        let mut _u = ui.borrow_mut();
+        let u = _u.as_mut().unwrap();
+        let mp = &mut u.monitor_properties;
+        let ad = &mut u.output_tabs.advisor;
+        let min_fov = ad.min_fov_input.value().parse::<f64>();
+        let max_fov = ad.max_fov_input.value().parse::<f64>();
+        let min_ppd = ad.min_ppd_input.value().parse::<f64>();
+        if let (Some(distance), Ok(min_fov), Ok(max_fov), Ok(min_ppd)) =
+            (mp.distance(), min_fov, max_fov, min_ppd)
+        {
+            let requirements = DisplayRequirements {
+                distance,
+                min_fov: Angle::new::<degree>(min_fov),
+                max_fov: Angle::new::<degree>(max_fov),
+                min_pixels_per_degree: min_ppd,
+            };
+            let mut candidates = display_candidates(requirements).collect::<Vec<_>>();
+            rank_display_candidates(requirements, &mut candidates);
+
+            let mut candidates_text = String::new();
+            for candidate in &candidates {
+                let resolution = match candidate.resolution {
+                    Some([w, h]) => format!(" {}×{}", w, h),
+                    None => String::new(),
+                };
+                let _ = write!(
+                    candidates_text,
+                    "{}\" {}{}: field of view {}",
+                    friendly_ftoa(candidate.diagonal.get::<inch>()),
+                    friendly_aspect(candidate.aspect),
+                    resolution,
+                    friendly_degrees(candidate.fov),
+                );
+                let _ = match candidate.pixels_per_degree {
+                    Some(ppd) => writeln!(
+                        candidates_text,
+                        ", {} pixels per degree",
+                        friendly_ftoa(ppd)
+                    ),
+                    None => writeln!(candidates_text, ", resolution varies"),
+                };
+            }
+            if candidates.is_empty() {
+                candidates_text.push_str("No common display meets the requirements");
+            }
+            ad.candidates_output.set_value(candidates_text.trim_end());
+        } else {
+            ad.candidates_output.set_value("");
+        }
+    }
+}
+impl LayoutGen<'_> for Advisor {
+    type Layout = AdvisorLayout;
+    type Arguments = ();
+
+    fn generate_layout(&self, _: Self::Arguments) -> Self::Layout {
+        const NUM_LINES: i32 = 3;
+
+        let height_l1;
+        let mut width_l1 = GROUP_H_PADDING * 2;
+
+        let fov_label_1 = Rect(
+            Position(GROUP_H_PADDING, GROUP_V_PADDING),
+            self.fov_label_1.measure_label().repack(),
+        );
+        height_l1 = fov_label_1.h() + ADDED_HEIGHT;
+        width_l1 += fov_label_1.w();
+
+        let min_fov_input = Rect(fov_label_1.to_right(5), Size(70, height_l1));
+        width_l1 += min_fov_input.w() + 5;
+
+        let fov_label_2 = Rect(
+            min_fov_input.to_right(0),
+            self.fov_label_2.measure_label().repack(),
+        );
+        width_l1 += fov_label_2.w();
+
+        let max_fov_input = Rect(fov_label_2.to_right(5), Size(70, height_l1));
+        width_l1 += max_fov_input.w() + 5;
+
+        let fov_label_3 = Rect(
+            max_fov_input.to_right(0),
+            self.fov_label_3.measure_label().repack(),
+        );
+        width_l1 += fov_label_3.w();
+
+        let height_l2;
+        let mut width_l2 = GROUP_H_PADDING * 2;
+
+        let min_ppd_label = Rect(
+            fov_label_1.to_bottom(LINE_V_PADDING),
+            self.min_ppd_label.measure_label().repack(),
+        );
+        height_l2 = min_ppd_label.h() + ADDED_HEIGHT;
+        width_l2 += min_ppd_label.w();
+
+        let min_ppd_input = Rect(min_ppd_label.to_right(5), Size(70, height_l2));
+        width_l2 += min_ppd_input.w() + 5;
+
+        let width_l12 = std::cmp::max(width_l1, width_l2);
+        let height_l3 = height_l2 * CANDIDATES_OUTPUT_LINES;
+        let candidates_output = Rect(
+            min_ppd_label.to_bottom(LINE_V_PADDING),
+            Size(width_l12 - GROUP_H_PADDING * 2, height_l3),
+        );
+
+        let total_width = width_l12;
+        let total_height = height_l1
+            + height_l2
+            + height_l3
+            + LINE_V_PADDING * (NUM_LINES - 1)
+            + GROUP_V_PADDING * 2;
+        let total_size = Size(total_width, total_height);
+        let containing_group = Rect(Position(0, 0), total_size);
+        AdvisorLayout {
+            total_size,
+            containing_group,
+            fov_label_1,
+            min_fov_input,
+            fov_label_2,
+            max_fov_input,
+            fov_label_3,
+            min_ppd_label,
+            min_ppd_input,
+            candidates_output,
+        }
+    }
+}
+
+make_layout!(pub AdvisorLayout, has
+    containing_group,
+    fov_label_1, min_fov_input, fov_label_2, max_fov_input, fov_label_3,
+    min_ppd_label, min_ppd_input,
+    candidates_output,
+);
@@ -1,42 +1,94 @@
 use crate::{
-    build_unit_selector,
+    build_fov_convention_selector, build_sensor_selector, build_unit_selector,
     layout::{LayoutGen, Position, Rect, Size},
-    util::{friendly_ftoa, length_from_unit, PosExt, Repack, Unit, DEGREE_SIGN},
+    util::{
+        friendly_degrees, friendly_fov_interval, friendly_ftoa, length_from_input, selected_unit,
+        PosExt, Repack, FOV_CONVENTIONS, SENSOR_SIZES,
+    },
     Number::*,
     RcUi, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
 };
-use fltk::{frame::Frame, group::Group, input::FloatInput, menu::Choice, prelude::*};
-use fpvsetup::MonitorConfiguration;
-use std::{cmp::max, convert::TryInto, rc::Rc};
-use uom::si::angle::degree;
+use fltk::{
+    frame::Frame,
+    group::Group,
+    input::{FloatInput, Input},
+    menu::Choice,
+    prelude::*,
+};
+use fpvsetup::{convert_fov, equivalent_focal_length, FovAxis, LengthUnit};
+use std::rc::Rc;
+use uom::si::length::millimeter;
 
 #[derive(Clone)]
 pub struct Focused {
     pub containing_group: Group,
     pub accurate_distance_label_1: Frame,
-    pub accurate_distance_input: FloatInput,
+    pub accurate_distance_input: Input,
     pub accurate_distance_label_2: Frame,
     pub accurate_distance_unit_selector: Choice,
     pub fov_output_label: Frame,
     pub fov_output: FloatInput,
+    pub vertical_fov_label: Frame,
+    pub vertical_fov_output: FloatInput,
+    pub diagonal_fov_label: Frame,
+    pub diagonal_fov_output: FloatInput,
+    pub convention_label: Frame,
+    pub convention_selector: Choice,
+    pub convention_output_label: Frame,
+    pub convention_output: FloatInput,
+    pub focal_length_label: Frame,
+    pub sensor_selector: Choice,
+    pub focal_length_output_label: Frame,
+    pub focal_length_output: FloatInput,
+    pub equivalent_focal_length_label: Frame,
+    pub equivalent_focal_length_output: FloatInput,
+    pub millimeters_label: Frame,
 }
 impl Focused {
     pub fn new(ui: &RcUi) -> Self {
         let containing_group = Group::default().with_label("Focused");
 
         let accurate_distance_label_1 = Frame::default().with_label("Accurate scale");
-        let mut accurate_distance_input = FloatInput::default();
+        let mut accurate_distance_input = Input::default();
         let r = Rc::clone(&ui);
         accurate_distance_input.set_callback(move || Self::update(&r));
         accurate_distance_input.set_trigger(CallbackTrigger::Changed);
-        let accurate_distance_unit_selector =
-            build_unit_selector(&accurate_distance_input, Some(Unit::Meters), Plural, false);
+        let accurate_distance_unit_selector = build_unit_selector(
+            &accurate_distance_input,
+            Some(LengthUnit::Meters),
+            Plural,
+            false,
+        );
         let accurate_distance_label_2 = Frame::default().with_label("away from the camera");
 
-        let fov_output_label = Frame::default().with_label("Camera field of view:");
+        let fov_output_label = Frame::default().with_label("Camera field of view, horizontal:");
         let mut fov_output = FloatInput::default();
         fov_output.set_readonly(true);
 
+        let vertical_fov_label = Frame::default().with_label(", vertical:");
+        let mut vertical_fov_output = FloatInput::default();
+        vertical_fov_output.set_readonly(true);
+
+        let diagonal_fov_label = Frame::default().with_label(", diagonal:");
+        let mut diagonal_fov_output = FloatInput::default();
+        diagonal_fov_output.set_readonly(true);
+
+        let convention_label = Frame::default().with_label("In-game field of view,");
+        let convention_selector = build_fov_convention_selector(ui);
+        let convention_output_label = Frame::default().with_label(":");
+        let mut convention_output = FloatInput::default();
+        convention_output.set_readonly(true);
+
+        let focal_length_label = Frame::default().with_label("Focal length with a");
+        let sensor_selector = build_sensor_selector(ui);
+        let focal_length_output_label = Frame::default().with_label("sensor:");
+        let mut focal_length_output = FloatInput::default();
+        focal_length_output.set_readonly(true);
+        let equivalent_focal_length_label = Frame::default().with_label("mm, 35 mm equivalent:");
+        let mut equivalent_focal_length_output = FloatInput::default();
+        equivalent_focal_length_output.set_readonly(true);
+        let millimeters_label = Frame::default().with_label("mm");
+
         containing_group.end();
 
         Self {
@@ -47,6 +99,21 @@ impl Focused {
             accurate_distance_unit_selector,
             fov_output_label,
             fov_output,
+            vertical_fov_label,
+            vertical_fov_output,
+            diagonal_fov_label,
+            diagonal_fov_output,
+            convention_label,
+            convention_selector,
+            convention_output_label,
+            convention_output,
+            focal_length_label,
+            sensor_selector,
+            focal_length_output_label,
+            focal_length_output,
+            equivalent_focal_length_label,
+            equivalent_focal_length_output,
+            millimeters_label,
         }
     }
     pub fn apply_layout(&mut self, layout: &FocusedLayout, pos: Position) {
@@ -64,42 +131,91 @@ impl Focused {
             .set_rect(layout.fov_output_label.with_added_pos(pos));
         self.fov_output
             .set_rect(layout.fov_output.with_added_pos(pos));
+        self.vertical_fov_label
+            .set_rect(layout.vertical_fov_label.with_added_pos(pos));
+        self.vertical_fov_output
+            .set_rect(layout.vertical_fov_output.with_added_pos(pos));
+        self.diagonal_fov_label
+            .set_rect(layout.diagonal_fov_label.with_added_pos(pos));
+        self.diagonal_fov_output
+            .set_rect(layout.diagonal_fov_output.with_added_pos(pos));
+        self.convention_label
+            .set_rect(layout.convention_label.with_added_pos(pos));
+        self.convention_selector
+            .set_rect(layout.convention_selector.with_added_pos(pos));
+        self.convention_output_label
+            .set_rect(layout.convention_output_label.with_added_pos(pos));
+        self.convention_output
+            .set_rect(layout.convention_output.with_added_pos(pos));
+        self.focal_length_label
+            .set_rect(layout.focal_length_label.with_added_pos(pos));
+        self.sensor_selector
+            .set_rect(layout.sensor_selector.with_added_pos(pos));
+        self.focal_length_output_label
+            .set_rect(layout.focal_length_output_label.with_added_pos(pos));
+        self.focal_length_output
+            .set_rect(layout.focal_length_output.with_added_pos(pos));
+        self.equivalent_focal_length_label
+            .set_rect(layout.equivalent_focal_length_label.with_added_pos(pos));
+        self.equivalent_focal_length_output
+            .set_rect(layout.equivalent_focal_length_output.with_added_pos(pos));
+        self.millimeters_label
+            .set_rect(layout.millimeters_label.with_added_pos(pos));
+    }
+    pub fn clear(&mut self) {
+        for output in [
+            &mut self.fov_output,
+            &mut self.vertical_fov_output,
+            &mut self.diagonal_fov_output,
+            &mut self.convention_output,
+            &mut self.focal_length_output,
+            &mut self.equivalent_focal_length_output,
+        ]
+        .iter_mut()
+        {
+            output.set_value("");
+        }
     }
     pub fn update(ui: &RcUi) {
         let mut _u = ui.borrow_mut();
         let u = _u.as_mut().unwrap();
         let mp = &mut u.monitor_properties;
         let fo = &mut u.output_tabs.focused;
-        let width = mp.width_input.value().parse::<f64>();
-        let height = mp.height_input.value().parse::<f64>();
-        let distance = mp.distance_input.value().parse::<f64>();
-        let accurate_distance = fo.accurate_distance_input.value().parse::<f64>();
-        if let (Ok(width), Ok(height), Ok(distance), Ok(accurate_distance)) =
-            (width, height, distance, accurate_distance)
+        let accurate_distance_unit = selected_unit(&fo.accurate_distance_unit_selector);
+        let accurate_distance =
+            length_from_input(&fo.accurate_distance_input.value(), accurate_distance_unit);
+        if let (Ok(monitor_conf), Some(accurate_distance)) =
+            (mp.game_configuration(), accurate_distance)
         {
-            let width_unit = mp.width_unit_selector.value().try_into().unwrap();
-            let height_unit = mp.height_unit_selector.value().try_into().unwrap();
-            let distance_unit = mp.distance_unit_selector.value().try_into().unwrap();
-            let accurate_distance_unit = fo
-                .accurate_distance_unit_selector
-                .value()
-                .try_into()
-                .unwrap();
-            let width = length_from_unit(width, width_unit);
-            let height = length_from_unit(height, height_unit);
-            let distance = length_from_unit(distance, distance_unit);
-            let accurate_distance = length_from_unit(accurate_distance, accurate_distance_unit);
-
-            let monitor_conf = MonitorConfiguration {
-                dimensions: fpvsetup::MonitorDimensions::WidthAndHeight { width, height },
-                distance,
-            };
             let fov = monitor_conf.monitor_fov_for_distance(accurate_distance, true);
-            fo.fov_output.set_value(&format!(
-                "{}{}",
-                &friendly_ftoa(fov.get::<degree>()),
-                DEGREE_SIGN,
+            let aspect = monitor_conf.dimensions.aspect();
+            let vertical_fov = convert_fov(fov, FovAxis::Horizontal, FovAxis::Vertical, aspect);
+            let diagonal_fov = convert_fov(fov, FovAxis::Horizontal, FovAxis::Diagonal, aspect);
+            let fov_interval = monitor_conf.monitor_fov_for_distance_interval(
+                accurate_distance,
+                true,
+                mp.tolerances(),
+            );
+            fo.fov_output
+                .set_value(&friendly_fov_interval(fov, fov_interval));
+            fo.vertical_fov_output
+                .set_value(&friendly_degrees(vertical_fov));
+            fo.diagonal_fov_output
+                .set_value(&friendly_degrees(diagonal_fov));
+            let (_, convention) = FOV_CONVENTIONS[fo.convention_selector.value() as usize];
+            fo.convention_output
+                .set_value(&friendly_degrees(convention.value_for(fov, aspect)));
+            let (_, sensor) = SENSOR_SIZES[fo.sensor_selector.value() as usize];
+            fo.focal_length_output.set_value(&friendly_ftoa(
+                sensor
+                    .focal_length_for_fov(fov, FovAxis::Horizontal)
+                    .get::<millimeter>(),
             ));
+            fo.equivalent_focal_length_output.set_value(&friendly_ftoa(
+                equivalent_focal_length(diagonal_fov).get::<millimeter>(),
+            ));
+        } else {
+            fo.clear();
         }
     }
 }
@@ -108,7 +224,7 @@ impl LayoutGen<'_> for Focused {
     type Arguments = ();
 
     fn generate_layout(&self, _: Self::Arguments) -> Self::Layout {
-        const NUM_LINES: i32 = 2;
+        const NUM_LINES: i32 = 4;
 
         let mut width_l1 = GROUP_H_PADDING * 2;
         let height_l1;
@@ -144,12 +260,100 @@ impl LayoutGen<'_> for Focused {
         height_l2 = fov_output_label.h() + ADDED_HEIGHT;
         width_l2 += fov_output_label.w();
 
-        let fov_output = Rect(fov_output_label.to_right(5), Size(70, height_l2));
-        width_l2 += fov_output.w();
+        let fov_output = Rect(fov_output_label.to_right(5), Size(110, height_l2));
+        width_l2 += fov_output.w() + 5;
+
+        let vertical_fov_label = Rect(
+            fov_output.to_right(0),
+            self.vertical_fov_label.measure_label().repack(),
+        );
+        width_l2 += vertical_fov_label.w();
+
+        let vertical_fov_output = Rect(vertical_fov_label.to_right(5), Size(70, height_l2));
+        width_l2 += vertical_fov_output.w() + 5;
+
+        let diagonal_fov_label = Rect(
+            vertical_fov_output.to_right(0),
+            self.diagonal_fov_label.measure_label().repack(),
+        );
+        width_l2 += diagonal_fov_label.w();
+
+        let diagonal_fov_output = Rect(diagonal_fov_label.to_right(5), Size(70, height_l2));
+        width_l2 += diagonal_fov_output.w() + 5;
+
+        let height_l3;
+        let mut width_l3 = GROUP_H_PADDING * 2;
+
+        let convention_label = Rect(
+            fov_output_label.to_bottom(LINE_V_PADDING),
+            self.convention_label.measure_label().repack(),
+        );
+        height_l3 = convention_label.h() + ADDED_HEIGHT;
+        width_l3 += convention_label.w();
+
+        let convention_selector = Rect(convention_label.to_right(5), Size(160, height_l3));
+        width_l3 += convention_selector.w() + 5;
+
+        let convention_output_label = Rect(
+            convention_selector.to_right(0),
+            self.convention_output_label.measure_label().repack(),
+        );
+        width_l3 += convention_output_label.w();
+
+        let convention_output = Rect(convention_output_label.to_right(5), Size(70, height_l3));
+        width_l3 += convention_output.w() + 5;
+
+        let height_l4;
+        let mut width_l4 = GROUP_H_PADDING * 2;
+
+        let focal_length_label = Rect(
+            convention_label.to_bottom(LINE_V_PADDING),
+            self.focal_length_label.measure_label().repack(),
+        );
+        height_l4 = focal_length_label.h() + ADDED_HEIGHT;
+        width_l4 += focal_length_label.w();
+
+        let sensor_selector = Rect(focal_length_label.to_right(5), Size(120, height_l4));
+        width_l4 += sensor_selector.w() + 5;
+
+        let focal_length_output_label = Rect(
+            sensor_selector.to_right(5),
+            self.focal_length_output_label.measure_label().repack(),
+        );
+        width_l4 += focal_length_output_label.w() + 5;
+
+        let focal_length_output = Rect(focal_length_output_label.to_right(5), Size(70, height_l4));
+        width_l4 += focal_length_output.w() + 5;
+
+        let equivalent_focal_length_label = Rect(
+            focal_length_output.to_right(5),
+            self.equivalent_focal_length_label.measure_label().repack(),
+        );
+        width_l4 += equivalent_focal_length_label.w() + 5;
+
+        let equivalent_focal_length_output = Rect(
+            equivalent_focal_length_label.to_right(5),
+            Size(70, height_l4),
+        );
+        width_l4 += equivalent_focal_length_output.w() + 5;
+
+        let millimeters_label = Rect(
+            equivalent_focal_length_output.to_right(5),
+            self.millimeters_label.measure_label().repack(),
+        );
+        width_l4 += millimeters_label.w() + 5;
 
-        let total_width = max(width_l1, width_l2);
-        let total_height =
-            height_l1 + height_l2 + LINE_V_PADDING * (NUM_LINES - 1) + GROUP_V_PADDING * 2;
+        let total_width = [width_l1, width_l2, width_l3, width_l4]
+            .iter()
+            .copied()
+            .max()
+            .unwrap();
+        let total_height = height_l1
+            + height_l2
+            + height_l3
+            + height_l4
+            + LINE_V_PADDING * (NUM_LINES - 1)
+            + GROUP_V_PADDING * 2;
         let total_size = Size(total_width, total_height);
         let containing_group = Rect(Position(0, 0), total_size);
         FocusedLayout {
@@ -161,6 +365,21 @@ impl LayoutGen<'_> for Focused {
             accurate_distance_label_2,
             fov_output_label,
             fov_output,
+            vertical_fov_label,
+            vertical_fov_output,
+            diagonal_fov_label,
+            diagonal_fov_output,
+            convention_label,
+            convention_selector,
+            convention_output_label,
+            convention_output,
+            focal_length_label,
+            sensor_selector,
+            focal_length_output_label,
+            focal_length_output,
+            equivalent_focal_length_label,
+            equivalent_focal_length_output,
+            millimeters_label,
         }
     }
 }
@@ -173,4 +392,14 @@ make_layout!(pub FocusedLayout, has
     accurate_distance_label_2,
     fov_output_label,
     fov_output,
+    vertical_fov_label,
+    vertical_fov_output,
+    diagonal_fov_label,
+    diagonal_fov_output,
+    convention_label,
+    convention_selector,
+    convention_output_label,
+    convention_output,
+    focal_length_label, sensor_selector, focal_length_output_label, focal_length_output,
+    equivalent_focal_length_label, equivalent_focal_length_output, millimeters_label,
 );
@@ -5,17 +5,15 @@
 use fltk::{
     app::{App, Scheme},
     image::PngImage,
-    input::FloatInput,
     menu::{Choice, MenuExt, MenuFlag},
     window::{WidgetExt, Window},
     GroupExt, InputExt, Shortcut, WindowExt,
 };
-use fpvsetup::MonitorDimensions;
+use fpvsetup::{LengthUnit, MonitorDimensions};
 use native_dialog::{MessageDialog, MessageType};
 use std::{
     cell::{Cell, RefCell},
     cmp::max,
-    convert::TryFrom,
     panic::{self, PanicInfo},
     process,
     rc::Rc,
@@ -24,15 +22,23 @@ use std::{
 
 #[macro_use]
 mod layout;
+mod advisor;
 mod focused;
 mod monitor_properties;
 mod monitors;
 mod output_tabs;
+mod pixels;
 mod portal_like;
+mod projections;
+mod scopes;
+mod solver;
+mod stereo;
+mod surround;
 mod unit_setup;
 mod util;
 use {
-    focused::*, layout::*, monitor_properties::*, monitors::*, output_tabs::*, portal_like::*,
+    advisor::*, focused::*, layout::*, monitor_properties::*, monitors::*, output_tabs::*,
+    pixels::*, portal_like::*, projections::*, scopes::*, solver::*, stereo::*, surround::*,
     unit_setup::*, util::*,
 };
 
@@ -101,6 +107,7 @@ impl Ui {
         }
         built
     }
+    #[allow(clippy::too_many_arguments)] // One layout per pane, there's no way around it
     pub fn apply_layout(
         &mut self,
         layout: &UiLayout,
@@ -109,6 +116,13 @@ impl Ui {
         output_tabs_layout: &OutputTabsLayout,
         portal_like_layout: &PortalLikeLayout,
         focused_layout: &FocusedLayout,
+        pixels_layout: &PixelsLayout,
+        projections_layout: &ProjectionsLayout,
+        scopes_layout: &ScopesLayout,
+        stereo_layout: &StereoLayout,
+        surround_layout: &SurroundLayout,
+        solver_layout: &SolverLayout,
+        advisor_layout: &AdvisorLayout,
     ) {
         self.monitor_properties
             .apply_layout(monitor_properties_layout, layout.monitor_properties.pos());
@@ -118,6 +132,13 @@ impl Ui {
             output_tabs_layout,
             portal_like_layout,
             focused_layout,
+            pixels_layout,
+            projections_layout,
+            scopes_layout,
+            stereo_layout,
+            surround_layout,
+            solver_layout,
+            advisor_layout,
             layout.output_tabs.pos(),
         );
     }
@@ -166,13 +187,29 @@ fn build_ui(monitor_dimensions: Option<MonitorDimensions>) -> Size {
     let unit_setup_layout = ui.unit_setup.generate_layout(());
     let portal_like_layout = ui.output_tabs.portal_like.generate_layout(());
     let focused_layout = ui.output_tabs.focused.generate_layout(());
+    let pixels_layout = ui.output_tabs.pixels.generate_layout(());
+    let projections_layout = ui.output_tabs.projections.generate_layout(());
+    let scopes_layout = ui.output_tabs.scopes.generate_layout(());
+    let stereo_layout = ui.output_tabs.stereo.generate_layout(());
+    let surround_layout = ui.output_tabs.surround.generate_layout(());
+    let solver_layout = ui.output_tabs.solver.generate_layout(());
+    let advisor_layout = ui.output_tabs.advisor.generate_layout(());
     let fill_width = max(
         monitor_properties_layout.total_size.w(),
         unit_setup_layout.total_size.w(),
     );
-    let output_tabs_layout =
-        ui.output_tabs
-            .generate_layout((&portal_like_layout, &focused_layout, fill_width));
+    let output_tabs_layout = ui.output_tabs.generate_layout((
+        &portal_like_layout,
+        &focused_layout,
+        &pixels_layout,
+        &projections_layout,
+        &scopes_layout,
+        &stereo_layout,
+        &surround_layout,
+        &solver_layout,
+        &advisor_layout,
+        fill_width,
+    ));
     let ui_layout = ui.generate_layout((
         &monitor_properties_layout,
         &unit_setup_layout,
@@ -185,50 +222,79 @@ fn build_ui(monitor_dimensions: Option<MonitorDimensions>) -> Size {
         &output_tabs_layout,
         &portal_like_layout,
         &focused_layout,
+        &pixels_layout,
+        &projections_layout,
+        &scopes_layout,
+        &stereo_layout,
+        &surround_layout,
+        &solver_layout,
+        &advisor_layout,
     );
     ui_layout.total_size
 }
 
 fn build_unit_selector(
-    input_field: &FloatInput,
-    default: Option<Unit>,
+    input_field: &(impl InputExt + Clone + 'static),
+    default: Option<LengthUnit>,
+    number: Number,
+    invert: bool,
+) -> Choice {
+    build_shared_unit_selector(vec![input_field.clone()], default, number, invert)
+}
+/// Builds a unit selector like `build_unit_selector`, except that it converts the values of all of the given input fields when a different unit is selected.
+fn build_shared_unit_selector<I: InputExt + Clone + 'static>(
+    input_fields: Vec<I>,
+    default: Option<LengthUnit>,
     number: Number,
     invert: bool,
 ) -> Choice {
     let mut selector = Choice::default();
-    let prev_rc = Rc::new(Cell::new(0));
-    let mut counter = 0;
-    let mut add_entry = |singular, plural| {
+    let prev_rc = Rc::new(Cell::new(default.map_or(0, |u| unit_index(u) as usize)));
+    for (index, &(singular, plural, new)) in LENGTH_UNITS.iter().enumerate() {
         let prev_c = Rc::clone(&prev_rc);
-        let input_c = input_field.clone();
-        let index = counter;
+        let inputs_c = input_fields.clone();
         let label = match number {
             Singular => singular,
             Plural => plural,
         };
         selector.add(label, Shortcut::empty(), MenuFlag::Normal, move || {
-            if let Some(old_val) = float_from_restricted_string(&input_c.value()) {
-                let prev = Unit::try_from(prev_c.get()).unwrap();
-                let new = Unit::try_from(index).unwrap();
-                let mul = if !invert {
-                    conversion_rate(prev, new)
-                } else {
-                    conversion_rate(new, prev)
-                };
-                let new_val = old_val * mul;
-                input_c.set_value(&friendly_ftoa(new_val));
+            let (_, _, prev) = LENGTH_UNITS[prev_c.get()];
+            for input_c in &inputs_c {
+                if invert {
+                    if let Some(old_val) = float_from_restricted_string(&input_c.value()) {
+                        // A value per one previous unit, scaled to a value per one new unit
+                        let new_val = old_val * prev.value_of(new.length(1.0));
+                        input_c.set_value(&friendly_ftoa(new_val));
+                    }
+                } else if let Some(old_val) = length_from_input(&input_c.value(), prev) {
+                    // Also converts values typed with a unit suffix into the newly selected unit
+                    input_c.set_value(&friendly_ftoa(new.value_of(old_val)));
+                }
             }
             prev_c.set(index); // Remember current state for later
         });
-        counter += 1;
-    };
-    add_entry("meter", "meters");
-    add_entry("centimeter", "centimeters");
-    add_entry("foot", "feet");
-    add_entry("inch", "inches");
+    }
     if let Some(default) = default {
-        selector.set_value(default.into());
+        selector.set_value(unit_index(default));
+    }
+    selector
+}
+fn build_fov_convention_selector(ui: &RcUi) -> Choice {
+    build_updating_selector(ui, FOV_CONVENTIONS.iter().map(|(label, _)| *label))
+}
+fn build_sensor_selector(ui: &RcUi) -> Choice {
+    build_updating_selector(ui, SENSOR_SIZES.iter().map(|(label, _)| *label))
+}
+/// Builds a selector with the given entries which updates the output tabs when changed, with the first entry selected by default.
+fn build_updating_selector<'a>(ui: &RcUi, labels: impl IntoIterator<Item = &'a str>) -> Choice {
+    let mut selector = Choice::default();
+    for label in labels {
+        let r = Rc::clone(ui);
+        selector.add(label, Shortcut::empty(), MenuFlag::Normal, move || {
+            OutputTabs::update(&r)
+        });
     }
+    selector.set_value(0);
     selector
 }
 #[derive(Copy, Clone)]
@@ -1,40 +1,113 @@
 use crate::{
-    build_unit_selector,
+    build_unit_selector, build_updating_selector,
     layout::{LayoutGen, Position, Rect, Size},
     output_tabs::OutputTabs,
-    util::{convert_units, friendly_ftoa, length_from_unit, PosExt, Repack, Unit},
+    util::{
+        friendly_aspect, friendly_ftoa, length_from_input, selected_unit, PosExt, Repack,
+        CURVED_WIDTH_MEASUREMENTS,
+    },
     Number::*,
     RcUi, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
 };
-use fltk::{frame::Frame, input::FloatInput, menu::Choice, prelude::*};
-use fpvsetup::{find_common_aspect_ratio, MonitorDimensions};
-use std::{convert::TryInto, rc::Rc};
-use uom::si::length::centimeter;
+use fltk::{
+    frame::Frame,
+    input::{Input, IntInput},
+    menu::Choice,
+    prelude::*,
+};
+use fpvsetup::{
+    find_common_aspect_ratio, AspectRatio, Curvature, EyeOffset, LengthUnit, MonitorConfiguration,
+    MonitorDimensions, Tolerances, ValidationError, Viewport,
+};
+use std::{
+    fmt::{self, Display, Formatter},
+    rc::Rc,
+};
+use uom::si::{f64::Length, length::centimeter};
+
+/// The tooltip of the aspect ratio input, which is prefixed with the nearest common aspect ratio when the ratio is calculated from the width and height.
+const ASPECT_HINT: &str = "Such as 16:9, 21:9, 2.39:1 or a resolution like 2560x1080";
+
+/// Why the monitor configuration couldn't be read from the monitor properties.
+#[derive(Copy, Clone, Debug)]
+pub enum InputError {
+    /// The width, height or distance hasn't been entered yet, which isn't worth complaining about.
+    Missing,
+    /// The inputs were rejected by `MonitorConfiguration::validate`.
+    Invalid(ValidationError),
+    /// A game viewport is given, but the resolution isn't, which is needed to tell which part of the screen the viewport covers.
+    ViewportNeedsResolution,
+    /// The game viewport is empty or doesn't fit onto the screen.
+    UnusableViewport,
+}
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => f.write_str("the width, height and distance are required"),
+            Self::Invalid(error) => Display::fmt(error, f),
+            Self::ViewportNeedsResolution => {
+                f.write_str("the game viewport needs the resolution to be known")
+            }
+            Self::UnusableViewport => f.write_str("the game viewport must fit onto the screen"),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct MonitorProperties {
     pub width_label: Frame,
-    pub width_input: FloatInput,
+    pub width_input: Input,
     pub width_unit_selector: Choice,
     pub height_label: Frame,
-    pub height_input: FloatInput,
+    pub height_input: Input,
     pub height_unit_selector: Choice,
     pub diagonal_label: Frame,
-    pub diagonal_input: FloatInput,
+    pub diagonal_input: Input,
     pub diagonal_unit_selector: Choice,
     pub aspect_label: Frame,
-    pub aspect_n_input: FloatInput,
-    pub aspect_sep: Frame,
-    pub aspect_d_input: FloatInput,
+    pub aspect_input: Input,
     pub distance_label: Frame,
-    pub distance_input: FloatInput,
+    pub distance_input: Input,
     pub distance_unit_selector: Choice,
+    pub resolution_label: Frame,
+    pub resolution_w_input: IntInput,
+    pub resolution_sep: Frame,
+    pub resolution_h_input: IntInput,
+    pub eye_offset_h_label: Frame,
+    pub eye_offset_h_input: Input,
+    pub eye_offset_h_unit_selector: Choice,
+    pub eye_offset_v_label: Frame,
+    pub eye_offset_v_input: Input,
+    pub eye_offset_v_unit_selector: Choice,
+    pub curvature_label: Frame,
+    pub curvature_input: Input,
+    pub curvature_unit_selector: Choice,
+    pub curvature_measurement_label: Frame,
+    pub curvature_measurement_selector: Choice,
+    pub tolerance_dimensions_label: Frame,
+    pub tolerance_dimensions_input: Input,
+    pub tolerance_dimensions_unit_selector: Choice,
+    pub tolerance_distance_label: Frame,
+    pub tolerance_distance_input: Input,
+    pub tolerance_distance_unit_selector: Choice,
+    pub tolerance_curvature_label: Frame,
+    pub tolerance_curvature_input: Input,
+    pub tolerance_curvature_unit_selector: Choice,
+    pub viewport_label: Frame,
+    pub viewport_w_input: IntInput,
+    pub viewport_sep: Frame,
+    pub viewport_h_input: IntInput,
+    pub viewport_position_label: Frame,
+    pub viewport_x_input: IntInput,
+    pub viewport_position_sep: Frame,
+    pub viewport_y_input: IntInput,
+    pub viewport_centered_label: Frame,
 }
 impl MonitorProperties {
     /// Generates a not yet laid out monitor properties panel.
     pub fn new(ui: &RcUi, monitor_dimensions: Option<MonitorDimensions>) -> Self {
         let width_label = Frame::default().with_label("Monitor width:");
-        let mut width_input = FloatInput::default();
+        let mut width_input = Input::default();
         let r = Rc::clone(ui);
         width_input.set_callback(move || Self::width_or_height_change_handler(&r));
         width_input.set_trigger(CallbackTrigger::Changed);
@@ -44,10 +117,10 @@ impl MonitorProperties {
             width_input.set_value(&friendly_ftoa(width.get::<centimeter>()))
         }
         let width_unit_selector =
-            build_unit_selector(&width_input, Some(Unit::Centimeters), Plural, false);
+            build_unit_selector(&width_input, Some(LengthUnit::Centimeters), Plural, false);
 
         let height_label = Frame::default().with_label(", height:");
-        let mut height_input = FloatInput::default();
+        let mut height_input = Input::default();
         let r = Rc::clone(ui);
         height_input.set_callback(move || Self::width_or_height_change_handler(&r));
         height_input.set_trigger(CallbackTrigger::Changed);
@@ -57,36 +130,154 @@ impl MonitorProperties {
             height_input.set_value(&friendly_ftoa(height.get::<centimeter>()))
         }
         let height_unit_selector =
-            build_unit_selector(&height_input, Some(Unit::Centimeters), Plural, false);
+            build_unit_selector(&height_input, Some(LengthUnit::Centimeters), Plural, false);
 
         let diagonal_label = Frame::default().with_label("Monitor diagonal:");
-        let mut diagonal_input = FloatInput::default();
+        let mut diagonal_input = Input::default();
         let r = Rc::clone(ui);
         diagonal_input.set_callback(move || Self::diagonal_or_aspect_change_handler(&r));
         diagonal_input.set_trigger(CallbackTrigger::Changed);
         let diagonal_unit_selector =
-            build_unit_selector(&diagonal_input, Some(Unit::Inches), Plural, false);
+            build_unit_selector(&diagonal_input, Some(LengthUnit::Inches), Plural, false);
 
         let aspect_label = Frame::default().with_label(", aspect ratio:");
-        let mut aspect_n_input = FloatInput::default();
+        let mut aspect_input = Input::default();
+        aspect_input.set_tooltip(ASPECT_HINT);
         let r = Rc::clone(ui);
-        aspect_n_input.set_callback(move || Self::diagonal_or_aspect_change_handler(&r));
-        aspect_n_input.set_trigger(CallbackTrigger::Changed);
-        let mut aspect_sep = Frame::default().with_label(":");
-        aspect_sep.set_label_font(Font::HelveticaBold);
-        let mut aspect_d_input = FloatInput::default();
-        let r = Rc::clone(ui);
-        aspect_d_input.set_callback(move || Self::diagonal_or_aspect_change_handler(&r));
-        aspect_d_input.set_trigger(CallbackTrigger::Changed);
+        aspect_input.set_callback(move || Self::diagonal_or_aspect_change_handler(&r));
+        aspect_input.set_trigger(CallbackTrigger::Changed);
 
         let distance_label = Frame::default().with_label("Viewing distance:");
-        let mut distance_input = FloatInput::default();
+        let mut distance_input = Input::default();
         let r = Rc::clone(ui);
         distance_input.set_callback(move || OutputTabs::update(&r));
         distance_input.set_trigger(CallbackTrigger::Changed);
 
-        let distance_unit_selector =
-            build_unit_selector(&distance_input, Some(Unit::Centimeters), Plural, false);
+        let distance_unit_selector = build_unit_selector(
+            &distance_input,
+            Some(LengthUnit::Centimeters),
+            Plural,
+            false,
+        );
+
+        let resolution_label = Frame::default().with_label(", resolution (if known):");
+        let mut resolution_w_input = IntInput::default();
+        let r = Rc::clone(ui);
+        resolution_w_input.set_callback(move || OutputTabs::update(&r));
+        resolution_w_input.set_trigger(CallbackTrigger::Changed);
+        let resolution_sep = Frame::default().with_label("×");
+        let mut resolution_h_input = IntInput::default();
+        let r = Rc::clone(ui);
+        resolution_h_input.set_callback(move || OutputTabs::update(&r));
+        resolution_h_input.set_trigger(CallbackTrigger::Changed);
+
+        let eye_offset_h_label = Frame::default().with_label("Eye offset from center, right:");
+        let mut eye_offset_h_input = Input::default();
+        let r = Rc::clone(ui);
+        eye_offset_h_input.set_callback(move || OutputTabs::update(&r));
+        eye_offset_h_input.set_trigger(CallbackTrigger::Changed);
+        let eye_offset_h_unit_selector = build_unit_selector(
+            &eye_offset_h_input,
+            Some(LengthUnit::Centimeters),
+            Plural,
+            false,
+        );
+
+        let eye_offset_v_label = Frame::default().with_label(", up:");
+        let mut eye_offset_v_input = Input::default();
+        let r = Rc::clone(ui);
+        eye_offset_v_input.set_callback(move || OutputTabs::update(&r));
+        eye_offset_v_input.set_trigger(CallbackTrigger::Changed);
+        let eye_offset_v_unit_selector = build_unit_selector(
+            &eye_offset_v_input,
+            Some(LengthUnit::Centimeters),
+            Plural,
+            false,
+        );
+
+        let curvature_label = Frame::default().with_label("Curvature radius (if curved):");
+        let mut curvature_input = Input::default();
+        let r = Rc::clone(ui);
+        curvature_input.set_callback(move || OutputTabs::update(&r));
+        curvature_input.set_trigger(CallbackTrigger::Changed);
+        let curvature_unit_selector = build_unit_selector(
+            &curvature_input,
+            Some(LengthUnit::Centimeters),
+            Plural,
+            false,
+        );
+        let curvature_measurement_label = Frame::default().with_label(", width measured");
+        let curvature_measurement_selector = build_updating_selector(
+            ui,
+            CURVED_WIDTH_MEASUREMENTS.iter().map(|(label, _)| *label),
+        );
+
+        let tolerance_dimensions_label = Frame::default().with_label("Tolerance, size: ±");
+        let mut tolerance_dimensions_input = Input::default();
+        if monitor_dimensions.is_some() {
+            // EDID stores the size in whole centimeters
+            tolerance_dimensions_input.set_value("0.5");
+        }
+        let r = Rc::clone(ui);
+        tolerance_dimensions_input.set_callback(move || OutputTabs::update(&r));
+        tolerance_dimensions_input.set_trigger(CallbackTrigger::Changed);
+        let tolerance_dimensions_unit_selector = build_unit_selector(
+            &tolerance_dimensions_input,
+            Some(LengthUnit::Centimeters),
+            Plural,
+            false,
+        );
+
+        let tolerance_distance_label = Frame::default().with_label(", distance: ±");
+        let mut tolerance_distance_input = Input::default();
+        let r = Rc::clone(ui);
+        tolerance_distance_input.set_callback(move || OutputTabs::update(&r));
+        tolerance_distance_input.set_trigger(CallbackTrigger::Changed);
+        let tolerance_distance_unit_selector = build_unit_selector(
+            &tolerance_distance_input,
+            Some(LengthUnit::Centimeters),
+            Plural,
+            false,
+        );
+
+        let tolerance_curvature_label = Frame::default().with_label(", curvature radius: ±");
+        let mut tolerance_curvature_input = Input::default();
+        let r = Rc::clone(ui);
+        tolerance_curvature_input.set_callback(move || OutputTabs::update(&r));
+        tolerance_curvature_input.set_trigger(CallbackTrigger::Changed);
+        let tolerance_curvature_unit_selector = build_unit_selector(
+            &tolerance_curvature_input,
+            Some(LengthUnit::Centimeters),
+            Plural,
+            false,
+        );
+
+        let viewport_label = Frame::default().with_label("Game viewport (if not fullscreen):");
+        let viewport_tooltip =
+            "The part of the screen which the game draws into, used by all tabs \
+            except Surround and Solver, which are about the whole monitor";
+        let mut viewport_w_input = IntInput::default();
+        viewport_w_input.set_tooltip(viewport_tooltip);
+        let r = Rc::clone(ui);
+        viewport_w_input.set_callback(move || OutputTabs::update(&r));
+        viewport_w_input.set_trigger(CallbackTrigger::Changed);
+        let viewport_sep = Frame::default().with_label("×");
+        let mut viewport_h_input = IntInput::default();
+        viewport_h_input.set_tooltip(viewport_tooltip);
+        let r = Rc::clone(ui);
+        viewport_h_input.set_callback(move || OutputTabs::update(&r));
+        viewport_h_input.set_trigger(CallbackTrigger::Changed);
+        let viewport_position_label = Frame::default().with_label("pixels, at");
+        let mut viewport_x_input = IntInput::default();
+        let r = Rc::clone(ui);
+        viewport_x_input.set_callback(move || OutputTabs::update(&r));
+        viewport_x_input.set_trigger(CallbackTrigger::Changed);
+        let viewport_position_sep = Frame::default().with_label(",");
+        let mut viewport_y_input = IntInput::default();
+        let r = Rc::clone(ui);
+        viewport_y_input.set_callback(move || OutputTabs::update(&r));
+        viewport_y_input.set_trigger(CallbackTrigger::Changed);
+        let viewport_centered_label = Frame::default().with_label("(centered if blank)");
 
         Self {
             width_label,
@@ -99,12 +290,43 @@ impl MonitorProperties {
             diagonal_input,
             diagonal_unit_selector,
             aspect_label,
-            aspect_n_input,
-            aspect_sep,
-            aspect_d_input,
+            aspect_input,
             distance_label,
             distance_input,
             distance_unit_selector,
+            resolution_label,
+            resolution_w_input,
+            resolution_sep,
+            resolution_h_input,
+            eye_offset_h_label,
+            eye_offset_h_input,
+            eye_offset_h_unit_selector,
+            eye_offset_v_label,
+            eye_offset_v_input,
+            eye_offset_v_unit_selector,
+            curvature_label,
+            curvature_input,
+            curvature_unit_selector,
+            curvature_measurement_label,
+            curvature_measurement_selector,
+            tolerance_dimensions_label,
+            tolerance_dimensions_input,
+            tolerance_dimensions_unit_selector,
+            tolerance_distance_label,
+            tolerance_distance_input,
+            tolerance_distance_unit_selector,
+            tolerance_curvature_label,
+            tolerance_curvature_input,
+            tolerance_curvature_unit_selector,
+            viewport_label,
+            viewport_w_input,
+            viewport_sep,
+            viewport_h_input,
+            viewport_position_label,
+            viewport_x_input,
+            viewport_position_sep,
+            viewport_y_input,
+            viewport_centered_label,
         }
     }
     pub fn apply_layout(&mut self, layout: &MonitorPropertiesLayout, pos: Position) {
@@ -128,41 +350,234 @@ impl MonitorProperties {
             .set_rect(layout.diagonal_unit_selector.with_added_pos(pos));
         self.aspect_label
             .set_rect(layout.aspect_label.with_added_pos(pos));
-        self.aspect_n_input
-            .set_rect(layout.aspect_n_input.with_added_pos(pos));
-        self.aspect_sep
-            .set_rect(layout.aspect_sep.with_added_pos(pos));
-        self.aspect_d_input
-            .set_rect(layout.aspect_d_input.with_added_pos(pos));
+        self.aspect_input
+            .set_rect(layout.aspect_input.with_added_pos(pos));
         self.distance_label
             .set_rect(layout.distance_label.with_added_pos(pos));
         self.distance_input
             .set_rect(layout.distance_input.with_added_pos(pos));
         self.distance_unit_selector
             .set_rect(layout.distance_unit_selector.with_added_pos(pos));
+        self.resolution_label
+            .set_rect(layout.resolution_label.with_added_pos(pos));
+        self.resolution_w_input
+            .set_rect(layout.resolution_w_input.with_added_pos(pos));
+        self.resolution_sep
+            .set_rect(layout.resolution_sep.with_added_pos(pos));
+        self.resolution_h_input
+            .set_rect(layout.resolution_h_input.with_added_pos(pos));
+        self.eye_offset_h_label
+            .set_rect(layout.eye_offset_h_label.with_added_pos(pos));
+        self.eye_offset_h_input
+            .set_rect(layout.eye_offset_h_input.with_added_pos(pos));
+        self.eye_offset_h_unit_selector
+            .set_rect(layout.eye_offset_h_unit_selector.with_added_pos(pos));
+        self.eye_offset_v_label
+            .set_rect(layout.eye_offset_v_label.with_added_pos(pos));
+        self.eye_offset_v_input
+            .set_rect(layout.eye_offset_v_input.with_added_pos(pos));
+        self.eye_offset_v_unit_selector
+            .set_rect(layout.eye_offset_v_unit_selector.with_added_pos(pos));
+        self.curvature_label
+            .set_rect(layout.curvature_label.with_added_pos(pos));
+        self.curvature_input
+            .set_rect(layout.curvature_input.with_added_pos(pos));
+        self.curvature_unit_selector
+            .set_rect(layout.curvature_unit_selector.with_added_pos(pos));
+        self.curvature_measurement_label
+            .set_rect(layout.curvature_measurement_label.with_added_pos(pos));
+        self.curvature_measurement_selector
+            .set_rect(layout.curvature_measurement_selector.with_added_pos(pos));
+        self.tolerance_dimensions_label
+            .set_rect(layout.tolerance_dimensions_label.with_added_pos(pos));
+        self.tolerance_dimensions_input
+            .set_rect(layout.tolerance_dimensions_input.with_added_pos(pos));
+        self.tolerance_dimensions_unit_selector.set_rect(
+            layout
+                .tolerance_dimensions_unit_selector
+                .with_added_pos(pos),
+        );
+        self.tolerance_distance_label
+            .set_rect(layout.tolerance_distance_label.with_added_pos(pos));
+        self.tolerance_distance_input
+            .set_rect(layout.tolerance_distance_input.with_added_pos(pos));
+        self.tolerance_distance_unit_selector
+            .set_rect(layout.tolerance_distance_unit_selector.with_added_pos(pos));
+        self.tolerance_curvature_label
+            .set_rect(layout.tolerance_curvature_label.with_added_pos(pos));
+        self.tolerance_curvature_input
+            .set_rect(layout.tolerance_curvature_input.with_added_pos(pos));
+        self.tolerance_curvature_unit_selector
+            .set_rect(layout.tolerance_curvature_unit_selector.with_added_pos(pos));
+        self.viewport_label
+            .set_rect(layout.viewport_label.with_added_pos(pos));
+        self.viewport_w_input
+            .set_rect(layout.viewport_w_input.with_added_pos(pos));
+        self.viewport_sep
+            .set_rect(layout.viewport_sep.with_added_pos(pos));
+        self.viewport_h_input
+            .set_rect(layout.viewport_h_input.with_added_pos(pos));
+        self.viewport_position_label
+            .set_rect(layout.viewport_position_label.with_added_pos(pos));
+        self.viewport_x_input
+            .set_rect(layout.viewport_x_input.with_added_pos(pos));
+        self.viewport_position_sep
+            .set_rect(layout.viewport_position_sep.with_added_pos(pos));
+        self.viewport_y_input
+            .set_rect(layout.viewport_y_input.with_added_pos(pos));
+        self.viewport_centered_label
+            .set_rect(layout.viewport_centered_label.with_added_pos(pos));
+    }
+    /// Reads the monitor configuration from the inputs, failing if the width, height or distance is missing or if any of the inputs are rejected by `MonitorConfiguration::validate`.
+    ///
+    /// The eye offset is optional, with a missing offset along one axis meaning that the eye is centered along that axis. So are the curvature radius, with a missing one meaning that the monitor is flat, and the resolution.
+    ///
+    /// This describes the whole monitor regardless of the game viewport, see `game_configuration` for the part of the screen the game draws into.
+    pub fn monitor_configuration(&self) -> Result<MonitorConfiguration, InputError> {
+        let width_unit = selected_unit(&self.width_unit_selector);
+        let height_unit = selected_unit(&self.height_unit_selector);
+        let width = length_from_input(&self.width_input.value(), width_unit);
+        let height = length_from_input(&self.height_input.value(), height_unit);
+        let (width, height, distance) = match (width, height, self.distance()) {
+            (Some(width), Some(height), Some(distance)) => (width, height, distance),
+            _ => return Err(InputError::Missing),
+        };
+
+        let eye_offset_h_unit = selected_unit(&self.eye_offset_h_unit_selector);
+        let eye_offset_v_unit = selected_unit(&self.eye_offset_v_unit_selector);
+        let eye_offset_h = length_from_input(&self.eye_offset_h_input.value(), eye_offset_h_unit);
+        let eye_offset_v = length_from_input(&self.eye_offset_v_input.value(), eye_offset_v_unit);
+        let eye_offset = if eye_offset_h.is_some() || eye_offset_v.is_some() {
+            Some(EyeOffset {
+                horizontal: eye_offset_h.unwrap_or_default(),
+                vertical: eye_offset_v.unwrap_or_default(),
+            })
+        } else {
+            None
+        };
+
+        let curvature_unit = selected_unit(&self.curvature_unit_selector);
+        let curvature =
+            length_from_input(&self.curvature_input.value(), curvature_unit).map(|radius| {
+                let (_, width_measurement) =
+                    CURVED_WIDTH_MEASUREMENTS[self.curvature_measurement_selector.value() as usize];
+                Curvature {
+                    radius,
+                    width_measurement,
+                }
+            });
+
+        let resolution_w = self.resolution_w_input.value().parse::<u32>();
+        let resolution_h = self.resolution_h_input.value().parse::<u32>();
+        let resolution = match (resolution_w, resolution_h) {
+            (Ok(w), Ok(h)) if w > 0 && h > 0 => Some([w, h]),
+            _ => None,
+        };
+
+        MonitorConfiguration {
+            dimensions: MonitorDimensions::WidthAndHeight { width, height },
+            distance,
+            eye_offset,
+            curvature,
+            resolution,
+        }
+        .validate()
+        .map_err(InputError::Invalid)
+    }
+    /// Reads the configuration of the part of the screen which the game draws into. Unless a game viewport is given, this is the same as `monitor_configuration`, otherwise it's the configuration of the viewport as returned by `MonitorConfiguration::viewport`.
+    ///
+    /// The output tabs which describe the game's view use this, while those which are about the monitor itself, such as the solver, use `monitor_configuration`.
+    pub fn game_configuration(&self) -> Result<MonitorConfiguration, InputError> {
+        let monitor_conf = self.monitor_configuration()?;
+        match self.viewport(monitor_conf.resolution)? {
+            Some(viewport) => monitor_conf
+                .viewport(viewport)
+                .ok_or(InputError::UnusableViewport),
+            None => Ok(monitor_conf),
+        }
+    }
+    /// Reads the game viewport from the inputs, or returns `None` if its size is missing, in which case the game fills the whole screen. A missing position means that the viewport is centered along that axis.
+    ///
+    /// The viewport is given in pixels, so the resolution has to be known for it to be used.
+    fn viewport(&self, resolution: Option<[u32; 2]>) -> Result<Option<Viewport>, InputError> {
+        let width = self.viewport_w_input.value().parse::<u32>();
+        let height = self.viewport_h_input.value().parse::<u32>();
+        let (width, height) = match (width, height) {
+            (Ok(width), Ok(height)) => (width, height),
+            _ => return Ok(None),
+        };
+        let [screen_width, screen_height] =
+            resolution.ok_or(InputError::ViewportNeedsResolution)?;
+        let left = match self.viewport_x_input.value().parse::<u32>() {
+            Ok(left) => left,
+            Err(..) => screen_width.saturating_sub(width) / 2,
+        };
+        let top = match self.viewport_y_input.value().parse::<u32>() {
+            Ok(top) => top,
+            Err(..) => screen_height.saturating_sub(height) / 2,
+        };
+        Ok(Some(Viewport::Pixels {
+            left,
+            top,
+            width,
+            height,
+        }))
+    }
+
+    /// Reads the measurement tolerances from the inputs, with missing ones meaning that the measurement is exact.
+    pub fn tolerances(&self) -> Tolerances {
+        let tolerance = |input: &Input, unit_selector: &Choice| {
+            length_from_input(&input.value(), selected_unit(unit_selector))
+                .map(|tolerance| tolerance.abs())
+                .unwrap_or_default()
+        };
+        Tolerances {
+            dimensions: tolerance(
+                &self.tolerance_dimensions_input,
+                &self.tolerance_dimensions_unit_selector,
+            ),
+            distance: tolerance(
+                &self.tolerance_distance_input,
+                &self.tolerance_distance_unit_selector,
+            ),
+            curvature_radius: tolerance(
+                &self.tolerance_curvature_input,
+                &self.tolerance_curvature_unit_selector,
+            ),
+        }
+    }
+
+    /// Reads the viewing distance from the inputs, or returns `None` if it's missing.
+    pub fn distance(&self) -> Option<Length> {
+        let distance_unit = selected_unit(&self.distance_unit_selector);
+        length_from_input(&self.distance_input.value(), distance_unit)
     }
 
     pub fn width_or_height_change_handler(ui: &RcUi) {
         let mut _p = ui.borrow_mut();
         let p = &mut _p.as_mut().unwrap().monitor_properties;
-        let width = p.width_input.value().parse::<f64>();
-        let height = p.height_input.value().parse::<f64>();
-        if let (Ok(width), Ok(height)) = (width, height) {
-            let width_unit = p.width_unit_selector.value().try_into().unwrap();
-            let height_unit = p.height_unit_selector.value().try_into().unwrap();
-            let dimensions = MonitorDimensions::WidthAndHeight {
-                width: length_from_unit(width, width_unit),
-                height: length_from_unit(height, height_unit),
-            };
-            let diagonal = convert_units(
-                dimensions.diagonal(),
-                p.diagonal_unit_selector.value().try_into().unwrap(),
-            );
-            let aspect = dimensions.aspect();
+        let width_unit = selected_unit(&p.width_unit_selector);
+        let height_unit = selected_unit(&p.height_unit_selector);
+        let width = length_from_input(&p.width_input.value(), width_unit);
+        let height = length_from_input(&p.height_input.value(), height_unit);
+        if let (Some(width), Some(height)) = (width, height) {
+            let dimensions = MonitorDimensions::WidthAndHeight { width, height };
+            let diagonal = selected_unit(&p.diagonal_unit_selector).value_of(dimensions.diagonal());
             p.diagonal_input.set_value(&friendly_ftoa(diagonal));
-            let [n, d] = find_common_aspect_ratio(aspect, 0.1).unwrap_or([aspect, 1.0]);
-            p.aspect_n_input.set_value(&friendly_ftoa(n));
-            p.aspect_d_input.set_value(&friendly_ftoa(d));
+            // The exact ratio goes into the input, since the diagonal or aspect ratio handler
+            // parses it back and would otherwise change the width and height to a common ratio
+            let exact = match dimensions.aspect_ratio() {
+                Some(exact) => exact.to_string(),
+                None => format!("{}:1", friendly_ftoa(dimensions.aspect())),
+            };
+            let tooltip = match find_common_aspect_ratio(dimensions.aspect(), 0.1) {
+                Some(common) if friendly_aspect(common) != exact => {
+                    format!("Close to {}. {}", friendly_aspect(common), ASPECT_HINT)
+                }
+                _ => ASPECT_HINT.to_string(),
+            };
+            p.aspect_input.set_value(&exact);
+            p.aspect_input.set_tooltip(&tooltip);
 
             drop(_p);
             OutputTabs::update(ui);
@@ -171,18 +586,15 @@ impl MonitorProperties {
     fn diagonal_or_aspect_change_handler(ui: &RcUi) {
         let mut _p = ui.borrow_mut();
         let p = &mut _p.as_mut().unwrap().monitor_properties;
-        let diagonal = p.diagonal_input.value().parse::<f64>();
-        let aspect_n = p.aspect_n_input.value().parse::<f64>();
-        let aspect_d = p.aspect_d_input.value().parse::<f64>();
-        if let (Ok(diagonal), [Ok(n), Ok(d)]) = (diagonal, [aspect_n, aspect_d]) {
-            let diagonal_unit = p.diagonal_unit_selector.value().try_into().unwrap();
-            let dimensions = MonitorDimensions::DiagonalAndAspect {
-                diagonal: length_from_unit(diagonal, diagonal_unit),
-                aspect: n / d,
-            };
+        let diagonal_unit = selected_unit(&p.diagonal_unit_selector);
+        let diagonal = length_from_input(&p.diagonal_input.value(), diagonal_unit);
+        let aspect = p.aspect_input.value().parse::<AspectRatio>();
+        p.aspect_input.set_tooltip(ASPECT_HINT);
+        if let (Some(diagonal), Ok(aspect)) = (diagonal, aspect) {
+            let dimensions = MonitorDimensions::DiagonalAndAspect { diagonal, aspect };
             let [width, height] = dimensions.width_and_height();
-            let width = convert_units(width, p.width_unit_selector.value().try_into().unwrap());
-            let height = convert_units(height, p.height_unit_selector.value().try_into().unwrap());
+            let width = selected_unit(&p.width_unit_selector).value_of(width);
+            let height = selected_unit(&p.height_unit_selector).value_of(height);
             p.width_input.set_value(&friendly_ftoa(width));
             p.height_input.set_value(&friendly_ftoa(height));
 
@@ -197,7 +609,7 @@ impl LayoutGen<'_> for MonitorProperties {
     type Layout = MonitorPropertiesLayout;
 
     fn generate_layout(&self, _: Self::Arguments) -> Self::Layout {
-        const NUM_LINES: i32 = 3;
+        const NUM_LINES: i32 = 7;
 
         let height_l1;
         // Start out with this to include padding.
@@ -250,17 +662,8 @@ impl LayoutGen<'_> for MonitorProperties {
         );
         width_l2 += aspect_label.w();
 
-        let aspect_n_input = Rect(aspect_label.to_right(5), Size(65, height_l2));
-        width_l2 += aspect_n_input.w() + 5;
-
-        let aspect_sep = Rect(
-            aspect_n_input.to_right(1),
-            self.aspect_sep.measure_label().repack(),
-        );
-        width_l2 += aspect_sep.w() + 1;
-
-        let aspect_d_input = Rect(aspect_sep.to_right(1), Size(65, height_l2));
-        width_l2 += aspect_d_input.w() + 1;
+        let aspect_input = Rect(aspect_label.to_right(5), Size(90, height_l2));
+        width_l2 += aspect_input.w() + 5;
 
         let height_l3;
         let mut width_l3 = GROUP_H_PADDING * 2;
@@ -278,14 +681,186 @@ impl LayoutGen<'_> for MonitorProperties {
         let distance_unit_selector = Rect(distance_input.to_right(5), Size(105, height_l3));
         width_l3 += distance_unit_selector.w() + 5;
 
-        let total_width = [width_l1, width_l2, width_l3]
-            .iter()
-            .copied()
-            .max()
-            .unwrap();
+        let resolution_label = Rect(
+            distance_unit_selector.to_right(0),
+            self.resolution_label.measure_label().repack(),
+        );
+        width_l3 += resolution_label.w();
+
+        let resolution_w_input = Rect(resolution_label.to_right(5), Size(65, height_l3));
+        width_l3 += resolution_w_input.w() + 5;
+
+        let resolution_sep = Rect(
+            resolution_w_input.to_right(1),
+            self.resolution_sep.measure_label().repack(),
+        );
+        width_l3 += resolution_sep.w() + 1;
+
+        let resolution_h_input = Rect(resolution_sep.to_right(1), Size(65, height_l3));
+        width_l3 += resolution_h_input.w() + 1;
+
+        let height_l4;
+        let mut width_l4 = GROUP_H_PADDING * 2;
+
+        let eye_offset_h_label = Rect(
+            distance_label.to_bottom(LINE_V_PADDING),
+            self.eye_offset_h_label.measure_label().repack(),
+        );
+        height_l4 = eye_offset_h_label.h() + ADDED_HEIGHT;
+        width_l4 += eye_offset_h_label.w();
+
+        let eye_offset_h_input = Rect(eye_offset_h_label.to_right(5), Size(70, height_l4));
+        width_l4 += eye_offset_h_input.w() + 5;
+
+        let eye_offset_h_unit_selector = Rect(eye_offset_h_input.to_right(5), Size(105, height_l4));
+        width_l4 += eye_offset_h_unit_selector.w() + 5;
+
+        let eye_offset_v_label = Rect(
+            eye_offset_h_unit_selector.to_right(0),
+            self.eye_offset_v_label.measure_label().repack(),
+        );
+        width_l4 += eye_offset_v_label.w();
+
+        let eye_offset_v_input = Rect(eye_offset_v_label.to_right(5), Size(70, height_l4));
+        width_l4 += eye_offset_v_input.w() + 5;
+
+        let eye_offset_v_unit_selector = Rect(eye_offset_v_input.to_right(5), Size(105, height_l4));
+        width_l4 += eye_offset_v_unit_selector.w() + 5;
+
+        let height_l5;
+        let mut width_l5 = GROUP_H_PADDING * 2;
+
+        let curvature_label = Rect(
+            eye_offset_h_label.to_bottom(LINE_V_PADDING),
+            self.curvature_label.measure_label().repack(),
+        );
+        height_l5 = curvature_label.h() + ADDED_HEIGHT;
+        width_l5 += curvature_label.w();
+
+        let curvature_input = Rect(curvature_label.to_right(5), Size(70, height_l5));
+        width_l5 += curvature_input.w() + 5;
+
+        let curvature_unit_selector = Rect(curvature_input.to_right(5), Size(105, height_l5));
+        width_l5 += curvature_unit_selector.w() + 5;
+
+        let curvature_measurement_label = Rect(
+            curvature_unit_selector.to_right(0),
+            self.curvature_measurement_label.measure_label().repack(),
+        );
+        width_l5 += curvature_measurement_label.w();
+
+        let curvature_measurement_selector = Rect(
+            curvature_measurement_label.to_right(5),
+            Size(125, height_l5),
+        );
+        width_l5 += curvature_measurement_selector.w() + 5;
+
+        let height_l6;
+        let mut width_l6 = GROUP_H_PADDING * 2;
+
+        let tolerance_dimensions_label = Rect(
+            curvature_label.to_bottom(LINE_V_PADDING),
+            self.tolerance_dimensions_label.measure_label().repack(),
+        );
+        height_l6 = tolerance_dimensions_label.h() + ADDED_HEIGHT;
+        width_l6 += tolerance_dimensions_label.w();
+
+        let tolerance_dimensions_input =
+            Rect(tolerance_dimensions_label.to_right(5), Size(70, height_l6));
+        width_l6 += tolerance_dimensions_input.w() + 5;
+
+        let tolerance_dimensions_unit_selector =
+            Rect(tolerance_dimensions_input.to_right(5), Size(105, height_l6));
+        width_l6 += tolerance_dimensions_unit_selector.w() + 5;
+
+        let tolerance_distance_label = Rect(
+            tolerance_dimensions_unit_selector.to_right(0),
+            self.tolerance_distance_label.measure_label().repack(),
+        );
+        width_l6 += tolerance_distance_label.w();
+
+        let tolerance_distance_input =
+            Rect(tolerance_distance_label.to_right(5), Size(70, height_l6));
+        width_l6 += tolerance_distance_input.w() + 5;
+
+        let tolerance_distance_unit_selector =
+            Rect(tolerance_distance_input.to_right(5), Size(105, height_l6));
+        width_l6 += tolerance_distance_unit_selector.w() + 5;
+
+        let tolerance_curvature_label = Rect(
+            tolerance_distance_unit_selector.to_right(0),
+            self.tolerance_curvature_label.measure_label().repack(),
+        );
+        width_l6 += tolerance_curvature_label.w();
+
+        let tolerance_curvature_input =
+            Rect(tolerance_curvature_label.to_right(5), Size(70, height_l6));
+        width_l6 += tolerance_curvature_input.w() + 5;
+
+        let tolerance_curvature_unit_selector =
+            Rect(tolerance_curvature_input.to_right(5), Size(105, height_l6));
+        width_l6 += tolerance_curvature_unit_selector.w() + 5;
+
+        let height_l7;
+        let mut width_l7 = GROUP_H_PADDING * 2;
+
+        let viewport_label = Rect(
+            tolerance_dimensions_label.to_bottom(LINE_V_PADDING),
+            self.viewport_label.measure_label().repack(),
+        );
+        height_l7 = viewport_label.h() + ADDED_HEIGHT;
+        width_l7 += viewport_label.w();
+
+        let viewport_w_input = Rect(viewport_label.to_right(5), Size(65, height_l7));
+        width_l7 += viewport_w_input.w() + 5;
+
+        let viewport_sep = Rect(
+            viewport_w_input.to_right(1),
+            self.viewport_sep.measure_label().repack(),
+        );
+        width_l7 += viewport_sep.w() + 1;
+
+        let viewport_h_input = Rect(viewport_sep.to_right(1), Size(65, height_l7));
+        width_l7 += viewport_h_input.w() + 1;
+
+        let viewport_position_label = Rect(
+            viewport_h_input.to_right(5),
+            self.viewport_position_label.measure_label().repack(),
+        );
+        width_l7 += viewport_position_label.w() + 5;
+
+        let viewport_x_input = Rect(viewport_position_label.to_right(5), Size(65, height_l7));
+        width_l7 += viewport_x_input.w() + 5;
+
+        let viewport_position_sep = Rect(
+            viewport_x_input.to_right(1),
+            self.viewport_position_sep.measure_label().repack(),
+        );
+        width_l7 += viewport_position_sep.w() + 1;
+
+        let viewport_y_input = Rect(viewport_position_sep.to_right(1), Size(65, height_l7));
+        width_l7 += viewport_y_input.w() + 1;
+
+        let viewport_centered_label = Rect(
+            viewport_y_input.to_right(5),
+            self.viewport_centered_label.measure_label().repack(),
+        );
+        width_l7 += viewport_centered_label.w() + 5;
+
+        let total_width = [
+            width_l1, width_l2, width_l3, width_l4, width_l5, width_l6, width_l7,
+        ]
+        .iter()
+        .copied()
+        .max()
+        .unwrap();
         let total_height = height_l1
             + height_l2
             + height_l3
+            + height_l4
+            + height_l5
+            + height_l6
+            + height_l7
             + LINE_V_PADDING * (NUM_LINES - 1)
             + GROUP_V_PADDING * 2;
         MonitorPropertiesLayout {
@@ -300,12 +875,43 @@ impl LayoutGen<'_> for MonitorProperties {
             diagonal_input,
             diagonal_unit_selector,
             aspect_label,
-            aspect_n_input,
-            aspect_sep,
-            aspect_d_input,
+            aspect_input,
             distance_label,
             distance_input,
             distance_unit_selector,
+            resolution_label,
+            resolution_w_input,
+            resolution_sep,
+            resolution_h_input,
+            eye_offset_h_label,
+            eye_offset_h_input,
+            eye_offset_h_unit_selector,
+            eye_offset_v_label,
+            eye_offset_v_input,
+            eye_offset_v_unit_selector,
+            curvature_label,
+            curvature_input,
+            curvature_unit_selector,
+            curvature_measurement_label,
+            curvature_measurement_selector,
+            tolerance_dimensions_label,
+            tolerance_dimensions_input,
+            tolerance_dimensions_unit_selector,
+            tolerance_distance_label,
+            tolerance_distance_input,
+            tolerance_distance_unit_selector,
+            tolerance_curvature_label,
+            tolerance_curvature_input,
+            tolerance_curvature_unit_selector,
+            viewport_label,
+            viewport_w_input,
+            viewport_sep,
+            viewport_h_input,
+            viewport_position_label,
+            viewport_x_input,
+            viewport_position_sep,
+            viewport_y_input,
+            viewport_centered_label,
         }
     }
 }
@@ -314,6 +920,17 @@ make_layout!(pub MonitorPropertiesLayout, has
     width_label, width_input, width_unit_selector,
     height_label, height_input, height_unit_selector,
     diagonal_label, diagonal_input, diagonal_unit_selector,
-    aspect_label, aspect_n_input, aspect_sep, aspect_d_input,
+    aspect_label, aspect_input,
     distance_label, distance_input, distance_unit_selector,
+    resolution_label, resolution_w_input, resolution_sep, resolution_h_input,
+    eye_offset_h_label, eye_offset_h_input, eye_offset_h_unit_selector,
+    eye_offset_v_label, eye_offset_v_input, eye_offset_v_unit_selector,
+    curvature_label, curvature_input, curvature_unit_selector,
+    curvature_measurement_label, curvature_measurement_selector,
+    tolerance_dimensions_label, tolerance_dimensions_input, tolerance_dimensions_unit_selector,
+    tolerance_distance_label, tolerance_distance_input, tolerance_distance_unit_selector,
+    tolerance_curvature_label, tolerance_curvature_input, tolerance_curvature_unit_selector,
+    viewport_label, viewport_w_input, viewport_sep, viewport_h_input,
+    viewport_position_label, viewport_x_input, viewport_position_sep, viewport_y_input,
+    viewport_centered_label,
 );
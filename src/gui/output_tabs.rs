@@ -1,33 +1,75 @@
 use crate::{
-    util::PosExt, Focused, FocusedLayout, LayoutGen, PortalLike, PortalLikeLayout, Position, RcUi,
-    Rect, Size, GROUP_H_PADDING, GROUP_V_PADDING,
+    util::PosExt, Advisor, AdvisorLayout, Focused, FocusedLayout, InputError, LayoutGen, Pixels,
+    PixelsLayout, PortalLike, PortalLikeLayout, Position, Projections, ProjectionsLayout, RcUi,
+    Rect, Scopes, ScopesLayout, Size, Solver, SolverLayout, Stereo, StereoLayout, Surround,
+    SurroundLayout, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
+};
+use fltk::{
+    draw,
+    enums::{Align, FrameType},
+    frame::Frame,
+    group::Tabs,
+    prelude::*,
 };
-use core::cmp::max;
-use fltk::{group::Tabs, prelude::*};
 
 #[derive(Clone)]
 pub struct OutputTabs {
     pub tabs: Tabs,
     pub portal_like: PortalLike,
     pub focused: Focused,
+    pub pixels: Pixels,
+    pub projections: Projections,
+    pub scopes: Scopes,
+    pub stereo: Stereo,
+    pub surround: Surround,
+    pub solver: Solver,
+    pub advisor: Advisor,
+    pub status: Frame,
 }
 impl OutputTabs {
     pub fn new(ui: &RcUi) -> Self {
         let tabs = Tabs::default();
-        let portal_like = PortalLike::new();
+        let portal_like = PortalLike::new(ui);
         let focused = Focused::new(ui);
+        let pixels = Pixels::new(ui);
+        let projections = Projections::new(ui);
+        let scopes = Scopes::new(ui);
+        let stereo = Stereo::new(ui);
+        let surround = Surround::new(ui);
+        let solver = Solver::new(ui);
+        let advisor = Advisor::new(ui);
         tabs.end();
+        // Has a background so that a shorter message paints over the previous one
+        let mut status = Frame::default();
+        status.set_frame(FrameType::FlatBox);
+        status.set_align(Align::Left | Align::Inside);
         Self {
             tabs,
             portal_like,
             focused,
+            pixels,
+            projections,
+            scopes,
+            stereo,
+            surround,
+            solver,
+            advisor,
+            status,
         }
     }
+    #[allow(clippy::too_many_arguments)] // One layout per tab, same as in Ui::apply_layout
     pub fn apply_layout(
         &mut self,
         layout: &OutputTabsLayout,
         portal_like_layout: &PortalLikeLayout,
         focused_layout: &FocusedLayout,
+        pixels_layout: &PixelsLayout,
+        projections_layout: &ProjectionsLayout,
+        scopes_layout: &ScopesLayout,
+        stereo_layout: &StereoLayout,
+        surround_layout: &SurroundLayout,
+        solver_layout: &SolverLayout,
+        advisor_layout: &AdvisorLayout,
         pos: Position,
     ) {
         self.tabs.set_rect(layout.tabs.with_added_pos(pos));
@@ -37,30 +79,134 @@ impl OutputTabs {
 
         self.focused
             .apply_layout(focused_layout, layout.focused.pos() + pos);
+
+        self.pixels
+            .apply_layout(pixels_layout, layout.pixels.pos() + pos);
+
+        self.projections
+            .apply_layout(projections_layout, layout.projections.pos() + pos);
+
+        self.scopes
+            .apply_layout(scopes_layout, layout.scopes.pos() + pos);
+
+        self.stereo
+            .apply_layout(stereo_layout, layout.stereo.pos() + pos);
+
+        self.surround
+            .apply_layout(surround_layout, layout.surround.pos() + pos);
+
+        self.solver
+            .apply_layout(solver_layout, layout.solver.pos() + pos);
+
+        self.advisor
+            .apply_layout(advisor_layout, layout.advisor.pos() + pos);
+
+        self.status.set_rect(layout.status.with_added_pos(pos));
     }
     pub fn update(ui: &RcUi) {
+        Self::update_status(ui);
         PortalLike::update(ui);
         Focused::update(ui);
+        Pixels::update(ui);
+        Projections::update(ui);
+        Scopes::update(ui);
+        Stereo::update(ui);
+        Surround::update(ui);
+        Solver::update(ui);
+        Advisor::update(ui);
+    }
+    /// Shows below the tabs why the monitor properties were rejected, or what's suspicious about them, since that affects all of the tabs, along with inputs of single tabs which are rejected without an error of their own.
+    fn update_status(ui: &RcUi) {
+        let mut _u = ui.borrow_mut();
+        let u = _u.as_mut().unwrap();
+        let monitor_status = match u.monitor_properties.game_configuration() {
+            Ok(monitor_conf) => {
+                let warnings = monitor_conf
+                    .warnings()
+                    .map(|w| w.to_string())
+                    .collect::<Vec<_>>();
+                if warnings.is_empty() {
+                    String::new()
+                } else {
+                    format!("Suspicious setup, check the units: {}", warnings.join("; "))
+                }
+            }
+            Err(InputError::Missing) => String::new(),
+            Err(error) => format!("Invalid input: {}", error),
+        };
+        let ot = &mut u.output_tabs;
+        let status = match ot.surround.side_count_error() {
+            Some(error) if monitor_status.is_empty() => error,
+            Some(error) => format!("{}. {}", monitor_status, error),
+            None => monitor_status,
+        };
+        ot.status.set_label(&status);
+        ot.status.redraw();
     }
 }
 impl<'a> LayoutGen<'a> for OutputTabs {
-    type Arguments = (&'a PortalLikeLayout, &'a FocusedLayout, i32);
+    type Arguments = (
+        &'a PortalLikeLayout,
+        &'a FocusedLayout,
+        &'a PixelsLayout,
+        &'a ProjectionsLayout,
+        &'a ScopesLayout,
+        &'a StereoLayout,
+        &'a SurroundLayout,
+        &'a SolverLayout,
+        &'a AdvisorLayout,
+        i32,
+    );
     type Layout = OutputTabsLayout;
 
     fn generate_layout(
         &self,
-        (portal_like_layout, focused_layout, fill_width): Self::Arguments,
+        (
+            portal_like_layout,
+            focused_layout,
+            pixels_layout,
+            projections_layout,
+            scopes_layout,
+            stereo_layout,
+            surround_layout,
+            solver_layout,
+            advisor_layout,
+            fill_width,
+        ): Self::Arguments,
     ) -> Self::Layout {
         const TABS_HEADER_HEIGHT: i32 = 21;
 
         let Size(pl_w, pl_h) = portal_like_layout.total_size;
         let Size(fo_w, fo_h) = focused_layout.total_size;
-        let aggregate_width = [pl_w, fo_w, fill_width - GROUP_H_PADDING * 2]
+        let Size(px_w, px_h) = pixels_layout.total_size;
+        let Size(pr_w, pr_h) = projections_layout.total_size;
+        let Size(sc_w, sc_h) = scopes_layout.total_size;
+        let Size(st_w, st_h) = stereo_layout.total_size;
+        let Size(su_w, su_h) = surround_layout.total_size;
+        let Size(so_w, so_h) = solver_layout.total_size;
+        let Size(ad_w, ad_h) = advisor_layout.total_size;
+        let aggregate_width = [
+            pl_w,
+            fo_w,
+            px_w,
+            pr_w,
+            sc_w,
+            st_w,
+            su_w,
+            so_w,
+            ad_w,
+            fill_width - GROUP_H_PADDING * 2,
+        ]
+        .iter()
+        .copied()
+        .max()
+        .unwrap();
+        let aggregate_height = [pl_h, fo_h, px_h, pr_h, sc_h, st_h, su_h, so_h, ad_h]
             .iter()
             .copied()
             .max()
-            .unwrap();
-        let aggregate_height = max(pl_h, fo_h) + TABS_HEADER_HEIGHT;
+            .unwrap()
+            + TABS_HEADER_HEIGHT;
 
         let tabs = Rect(
             Position(GROUP_H_PADDING, GROUP_V_PADDING),
@@ -74,9 +220,44 @@ impl<'a> LayoutGen<'a> for OutputTabs {
             tabs.pos() + Position(0, TABS_HEADER_HEIGHT),
             focused_layout.total_size,
         );
+        let pixels = Rect(
+            tabs.pos() + Position(0, TABS_HEADER_HEIGHT),
+            pixels_layout.total_size,
+        );
+        let projections = Rect(
+            tabs.pos() + Position(0, TABS_HEADER_HEIGHT),
+            projections_layout.total_size,
+        );
+        let scopes = Rect(
+            tabs.pos() + Position(0, TABS_HEADER_HEIGHT),
+            scopes_layout.total_size,
+        );
+        let stereo = Rect(
+            tabs.pos() + Position(0, TABS_HEADER_HEIGHT),
+            stereo_layout.total_size,
+        );
+        let surround = Rect(
+            tabs.pos() + Position(0, TABS_HEADER_HEIGHT),
+            surround_layout.total_size,
+        );
+        let solver = Rect(
+            tabs.pos() + Position(0, TABS_HEADER_HEIGHT),
+            solver_layout.total_size,
+        );
+        let advisor = Rect(
+            tabs.pos() + Position(0, TABS_HEADER_HEIGHT),
+            advisor_layout.total_size,
+        );
+
+        // The status is empty most of the time, so it can't be measured like the other labels
+        draw::set_font(self.status.label_font(), self.status.label_size() as u32);
+        let status = Rect(
+            tabs.to_bottom(LINE_V_PADDING),
+            Size(tabs.w(), draw::height()),
+        );
 
         let total_width = GROUP_H_PADDING * 2 + tabs.w();
-        let total_height = GROUP_V_PADDING * 2 + tabs.h();
+        let total_height = GROUP_V_PADDING * 2 + tabs.h() + LINE_V_PADDING + status.h();
         let total_size = Size(total_width, total_height);
 
         OutputTabsLayout {
@@ -84,8 +265,16 @@ impl<'a> LayoutGen<'a> for OutputTabs {
             tabs,
             portal_like,
             focused,
+            pixels,
+            projections,
+            scopes,
+            stereo,
+            surround,
+            solver,
+            advisor,
+            status,
         }
     }
 }
 
-make_layout!(pub OutputTabsLayout, has tabs, portal_like, focused);
+make_layout!(pub OutputTabsLayout, has tabs, portal_like, focused, pixels, projections, scopes, stereo, surround, solver, advisor, status);
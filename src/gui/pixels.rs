@@ -0,0 +1,385 @@
+use crate::{
+    build_unit_selector, build_updating_selector,
+    layout::{LayoutGen, Position, Rect, Size},
+    util::{friendly_ftoa, selected_unit, PosExt, Repack, NORMAL_VISUAL_ACUITY, VISUAL_ACUITIES},
+    Number::*,
+    RcUi, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
+};
+use fltk::{frame::Frame, group::Group, input::FloatInput, menu::Choice, prelude::*};
+use fpvsetup::{LengthUnit, VisualAcuity};
+use uom::si::{angle::minute, length::millimeter};
+
+#[derive(Clone)]
+pub struct Pixels {
+    pub containing_group: Group,
+    pub ppi_label: Frame,
+    pub ppi_output: FloatInput,
+    pub dot_pitch_label_1: Frame,
+    pub dot_pitch_output: FloatInput,
+    pub dot_pitch_label_2: Frame,
+    pub average_ppd_label: Frame,
+    pub average_ppd_output: FloatInput,
+    pub center_ppd_label: Frame,
+    pub center_ppd_output: FloatInput,
+    pub edge_ppd_label: Frame,
+    pub edge_ppd_output: FloatInput,
+    pub acuity_label: Frame,
+    pub acuity_selector: Choice,
+    pub pixel_angle_label_1: Frame,
+    pub pixel_angle_output: FloatInput,
+    pub pixel_angle_label_2: Frame,
+    pub pixels_visible_warning: Frame,
+    pub retina_distance_label: Frame,
+    pub retina_distance_output: FloatInput,
+    pub retina_distance_unit_selector: Choice,
+}
+impl Pixels {
+    pub fn new(ui: &RcUi) -> Self {
+        let containing_group = Group::default().with_label("Pixels");
+
+        let ppi_label = Frame::default().with_label("Pixels per inch:");
+        let mut ppi_output = FloatInput::default();
+        ppi_output.set_readonly(true);
+        let dot_pitch_label_1 = Frame::default().with_label(", dot pitch:");
+        let mut dot_pitch_output = FloatInput::default();
+        dot_pitch_output.set_readonly(true);
+        let dot_pitch_label_2 = Frame::default().with_label("mm");
+
+        let average_ppd_label = Frame::default().with_label("Pixels per degree, average:");
+        let mut average_ppd_output = FloatInput::default();
+        average_ppd_output.set_readonly(true);
+        let center_ppd_label = Frame::default().with_label(", at the center:");
+        let mut center_ppd_output = FloatInput::default();
+        center_ppd_output.set_readonly(true);
+        let edge_ppd_label = Frame::default().with_label(", at the edges:");
+        let mut edge_ppd_output = FloatInput::default();
+        edge_ppd_output.set_readonly(true);
+
+        let acuity_label = Frame::default().with_label("Visual acuity:");
+        let mut acuity_selector =
+            build_updating_selector(ui, VISUAL_ACUITIES.iter().map(|(label, _)| *label));
+        acuity_selector.set_value(NORMAL_VISUAL_ACUITY);
+        let pixel_angle_label_1 = Frame::default().with_label(", pixel size:");
+        let mut pixel_angle_output = FloatInput::default();
+        pixel_angle_output.set_readonly(true);
+        let pixel_angle_label_2 = Frame::default().with_label("arcminutes");
+        let mut pixels_visible_warning =
+            Frame::default().with_label("(individual pixels can be seen)");
+        pixels_visible_warning.hide();
+
+        let retina_distance_label =
+            Frame::default().with_label("Pixels can no longer be seen beyond");
+        let mut retina_distance_output = FloatInput::default();
+        retina_distance_output.set_readonly(true);
+        let retina_distance_unit_selector = build_unit_selector(
+            &retina_distance_output,
+            Some(LengthUnit::Centimeters),
+            Plural,
+            false,
+        );
+
+        containing_group.end();
+
+        Self {
+            containing_group,
+            ppi_label,
+            ppi_output,
+            dot_pitch_label_1,
+            dot_pitch_output,
+            dot_pitch_label_2,
+            average_ppd_label,
+            average_ppd_output,
+            center_ppd_label,
+            center_ppd_output,
+            edge_ppd_label,
+            edge_ppd_output,
+            acuity_label,
+            acuity_selector,
+            pixel_angle_label_1,
+            pixel_angle_output,
+            pixel_angle_label_2,
+            pixels_visible_warning,
+            retina_distance_label,
+            retina_distance_output,
+            retina_distance_unit_selector,
+        }
+    }
+    pub fn apply_layout(&mut self, layout: &PixelsLayout, pos: Position) {
+        self.containing_group
+            .set_rect(layout.containing_group.with_added_pos(pos));
+        self.ppi_label
+            .set_rect(layout.ppi_label.with_added_pos(pos));
+        self.ppi_output
+            .set_rect(layout.ppi_output.with_added_pos(pos));
+        self.dot_pitch_label_1
+            .set_rect(layout.dot_pitch_label_1.with_added_pos(pos));
+        self.dot_pitch_output
+            .set_rect(layout.dot_pitch_output.with_added_pos(pos));
+        self.dot_pitch_label_2
+            .set_rect(layout.dot_pitch_label_2.with_added_pos(pos));
+        self.average_ppd_label
+            .set_rect(layout.average_ppd_label.with_added_pos(pos));
+        self.average_ppd_output
+            .set_rect(layout.average_ppd_output.with_added_pos(pos));
+        self.center_ppd_label
+            .set_rect(layout.center_ppd_label.with_added_pos(pos));
+        self.center_ppd_output
+            .set_rect(layout.center_ppd_output.with_added_pos(pos));
+        self.edge_ppd_label
+            .set_rect(layout.edge_ppd_label.with_added_pos(pos));
+        self.edge_ppd_output
+            .set_rect(layout.edge_ppd_output.with_added_pos(pos));
+        self.acuity_label
+            .set_rect(layout.acuity_label.with_added_pos(pos));
+        self.acuity_selector
+            .set_rect(layout.acuity_selector.with_added_pos(pos));
+        self.pixel_angle_label_1
+            .set_rect(layout.pixel_angle_label_1.with_added_pos(pos));
+        self.pixel_angle_output
+            .set_rect(layout.pixel_angle_output.with_added_pos(pos));
+        self.pixel_angle_label_2
+            .set_rect(layout.pixel_angle_label_2.with_added_pos(pos));
+        self.pixels_visible_warning
+            .set_rect(layout.pixels_visible_warning.with_added_pos(pos));
+        self.retina_distance_label
+            .set_rect(layout.retina_distance_label.with_added_pos(pos));
+        self.retina_distance_output
+            .set_rect(layout.retina_distance_output.with_added_pos(pos));
+        self.retina_distance_unit_selector
+            .set_rect(layout.retina_distance_unit_selector.with_added_pos(pos));
+    }
+    pub fn clear(&mut self) {
+        for output in [
+            &mut self.ppi_output,
+            &mut self.dot_pitch_output,
+            &mut self.average_ppd_output,
+            &mut self.center_ppd_output,
+            &mut self.edge_ppd_output,
+            &mut self.pixel_angle_output,
+            &mut self.retina_distance_output,
+        ]
+        .iter_mut()
+        {
+            output.set_value("");
+        }
+        self.pixels_visible_warning.hide();
+    }
+    pub fn update(ui: &RcUi) {
+        let mut _u = ui.borrow_mut();
+        let u = _u.as_mut().unwrap();
+        let mp = &mut u.monitor_properties;
+        let px = &mut u.output_tabs.pixels;
+        if let Ok(monitor_conf) = mp.game_configuration() {
+            let show = |output: &mut FloatInput, value: Option<f64>| {
+                output.set_value(&value.map(friendly_ftoa).unwrap_or_default())
+            };
+            show(&mut px.ppi_output, monitor_conf.pixels_per_inch());
+            show(
+                &mut px.dot_pitch_output,
+                monitor_conf.dot_pitch().map(|p| p.get::<millimeter>()),
+            );
+            show(
+                &mut px.average_ppd_output,
+                monitor_conf.average_pixels_per_degree(),
+            );
+            show(
+                &mut px.center_ppd_output,
+                monitor_conf.center_pixels_per_degree(),
+            );
+            show(
+                &mut px.edge_ppd_output,
+                monitor_conf.edge_pixels_per_degree(),
+            );
+
+            let (_, [numerator, denominator]) =
+                VISUAL_ACUITIES[px.acuity_selector.value() as usize];
+            let acuity = VisualAcuity::from_snellen(numerator, denominator);
+            let analysis = monitor_conf.acuity_analysis(acuity);
+            show(
+                &mut px.pixel_angle_output,
+                analysis.map(|a| a.pixel_angle.get::<minute>()),
+            );
+            let retina_distance_unit = selected_unit(&px.retina_distance_unit_selector);
+            show(
+                &mut px.retina_distance_output,
+                analysis.map(|a| retina_distance_unit.value_of(a.retina_distance)),
+            );
+            if matches!(analysis, Some(a) if a.pixels_resolvable()) {
+                px.pixels_visible_warning.show();
+            } else {
+                px.pixels_visible_warning.hide();
+            }
+        } else {
+            px.clear();
+        }
+    }
+}
+impl LayoutGen<'_> for Pixels {
+    type Layout = PixelsLayout;
+    type Arguments = ();
+
+    fn generate_layout(&self, _: Self::Arguments) -> Self::Layout {
+        const NUM_LINES: i32 = 4;
+
+        let height_l1;
+        let mut width_l1 = GROUP_H_PADDING * 2;
+
+        let ppi_label = Rect(
+            Position(GROUP_H_PADDING, GROUP_V_PADDING),
+            self.ppi_label.measure_label().repack(),
+        );
+        height_l1 = ppi_label.h() + ADDED_HEIGHT;
+        width_l1 += ppi_label.w();
+
+        let ppi_output = Rect(ppi_label.to_right(5), Size(70, height_l1));
+        width_l1 += ppi_output.w() + 5;
+
+        let dot_pitch_label_1 = Rect(
+            ppi_output.to_right(0),
+            self.dot_pitch_label_1.measure_label().repack(),
+        );
+        width_l1 += dot_pitch_label_1.w();
+
+        let dot_pitch_output = Rect(dot_pitch_label_1.to_right(5), Size(70, height_l1));
+        width_l1 += dot_pitch_output.w() + 5;
+
+        let dot_pitch_label_2 = Rect(
+            dot_pitch_output.to_right(5),
+            self.dot_pitch_label_2.measure_label().repack(),
+        );
+        width_l1 += dot_pitch_label_2.w() + 5;
+
+        let height_l2;
+        let mut width_l2 = GROUP_H_PADDING * 2;
+
+        let average_ppd_label = Rect(
+            ppi_label.to_bottom(LINE_V_PADDING),
+            self.average_ppd_label.measure_label().repack(),
+        );
+        height_l2 = average_ppd_label.h() + ADDED_HEIGHT;
+        width_l2 += average_ppd_label.w();
+
+        let average_ppd_output = Rect(average_ppd_label.to_right(5), Size(70, height_l2));
+        width_l2 += average_ppd_output.w() + 5;
+
+        let center_ppd_label = Rect(
+            average_ppd_output.to_right(0),
+            self.center_ppd_label.measure_label().repack(),
+        );
+        width_l2 += center_ppd_label.w();
+
+        let center_ppd_output = Rect(center_ppd_label.to_right(5), Size(70, height_l2));
+        width_l2 += center_ppd_output.w() + 5;
+
+        let edge_ppd_label = Rect(
+            center_ppd_output.to_right(0),
+            self.edge_ppd_label.measure_label().repack(),
+        );
+        width_l2 += edge_ppd_label.w();
+
+        let edge_ppd_output = Rect(edge_ppd_label.to_right(5), Size(70, height_l2));
+        width_l2 += edge_ppd_output.w() + 5;
+
+        let height_l3;
+        let mut width_l3 = GROUP_H_PADDING * 2;
+
+        let acuity_label = Rect(
+            average_ppd_label.to_bottom(LINE_V_PADDING),
+            self.acuity_label.measure_label().repack(),
+        );
+        height_l3 = acuity_label.h() + ADDED_HEIGHT;
+        width_l3 += acuity_label.w();
+
+        let acuity_selector = Rect(acuity_label.to_right(5), Size(140, height_l3));
+        width_l3 += acuity_selector.w() + 5;
+
+        let pixel_angle_label_1 = Rect(
+            acuity_selector.to_right(0),
+            self.pixel_angle_label_1.measure_label().repack(),
+        );
+        width_l3 += pixel_angle_label_1.w();
+
+        let pixel_angle_output = Rect(pixel_angle_label_1.to_right(5), Size(70, height_l3));
+        width_l3 += pixel_angle_output.w() + 5;
+
+        let pixel_angle_label_2 = Rect(
+            pixel_angle_output.to_right(5),
+            self.pixel_angle_label_2.measure_label().repack(),
+        );
+        width_l3 += pixel_angle_label_2.w() + 5;
+
+        let pixels_visible_warning = Rect(
+            pixel_angle_label_2.to_right(5),
+            self.pixels_visible_warning.measure_label().repack(),
+        );
+        width_l3 += pixels_visible_warning.w() + 5;
+
+        let height_l4;
+        let mut width_l4 = GROUP_H_PADDING * 2;
+
+        let retina_distance_label = Rect(
+            acuity_label.to_bottom(LINE_V_PADDING),
+            self.retina_distance_label.measure_label().repack(),
+        );
+        height_l4 = retina_distance_label.h() + ADDED_HEIGHT;
+        width_l4 += retina_distance_label.w();
+
+        let retina_distance_output = Rect(retina_distance_label.to_right(5), Size(70, height_l4));
+        width_l4 += retina_distance_output.w() + 5;
+
+        let retina_distance_unit_selector =
+            Rect(retina_distance_output.to_right(5), Size(105, height_l4));
+        width_l4 += retina_distance_unit_selector.w() + 5;
+
+        let total_width = [width_l1, width_l2, width_l3, width_l4]
+            .iter()
+            .copied()
+            .max()
+            .unwrap();
+        let total_height = height_l1
+            + height_l2
+            + height_l3
+            + height_l4
+            + LINE_V_PADDING * (NUM_LINES - 1)
+            + GROUP_V_PADDING * 2;
+        let total_size = Size(total_width, total_height);
+        let containing_group = Rect(Position(0, 0), total_size);
+        PixelsLayout {
+            total_size,
+            containing_group,
+            ppi_label,
+            ppi_output,
+            dot_pitch_label_1,
+            dot_pitch_output,
+            dot_pitch_label_2,
+            average_ppd_label,
+            average_ppd_output,
+            center_ppd_label,
+            center_ppd_output,
+            edge_ppd_label,
+            edge_ppd_output,
+            acuity_label,
+            acuity_selector,
+            pixel_angle_label_1,
+            pixel_angle_output,
+            pixel_angle_label_2,
+            pixels_visible_warning,
+            retina_distance_label,
+            retina_distance_output,
+            retina_distance_unit_selector,
+        }
+    }
+}
+
+make_layout!(pub PixelsLayout, has
+    containing_group,
+    ppi_label, ppi_output,
+    dot_pitch_label_1, dot_pitch_output, dot_pitch_label_2,
+    average_ppd_label, average_ppd_output,
+    center_ppd_label, center_ppd_output,
+    edge_ppd_label, edge_ppd_output,
+    acuity_label, acuity_selector,
+    pixel_angle_label_1, pixel_angle_output, pixel_angle_label_2,
+    pixels_visible_warning,
+    retina_distance_label, retina_distance_output, retina_distance_unit_selector,
+);
@@ -1,41 +1,88 @@
 use crate::{
-    build_unit_selector,
-    util::{convert_units, friendly_ftoa, length_from_unit, PosExt, Unit, DEGREE_SIGN},
+    build_fov_convention_selector, build_sensor_selector, build_unit_selector,
+    util::{
+        friendly_degrees, friendly_fov_interval, friendly_ftoa, selected_unit, PosExt,
+        CURVATURE_CENTER_TOLERANCE, FOV_CONVENTIONS, SENSOR_SIZES,
+    },
     LayoutGen,
     Number::*,
     Position, RcUi, Rect, Repack, Size, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING,
     LINE_V_PADDING,
 };
 use fltk::{frame::Frame, group::Group, input::FloatInput, menu::Choice, prelude::*};
-use fpvsetup::{MonitorConfiguration, MonitorDimensions};
-use std::{cmp::max, convert::TryInto};
-use uom::si::angle::degree;
+use fpvsetup::LengthUnit;
+use uom::si::length::millimeter;
 
 #[derive(Clone)]
 pub struct PortalLike {
     pub containing_group: Group,
     pub fov_label: Frame,
     pub fov_output: FloatInput,
+    pub vertical_fov_label: Frame,
+    pub vertical_fov_output: FloatInput,
+    pub diagonal_fov_label: Frame,
+    pub diagonal_fov_output: FloatInput,
+    pub convention_label: Frame,
+    pub convention_selector: Choice,
+    pub convention_output_label: Frame,
+    pub convention_output: FloatInput,
     pub move_label_1: Frame,
     pub move_output: FloatInput,
     pub move_unit_selector: Choice,
     pub move_label_2: Frame,
     pub move_units_output: FloatInput,
     pub move_label_3: Frame,
+    pub frustum_left_label: Frame,
+    pub frustum_left_output: FloatInput,
+    pub frustum_right_label: Frame,
+    pub frustum_right_output: FloatInput,
+    pub frustum_top_label: Frame,
+    pub frustum_top_output: FloatInput,
+    pub frustum_bottom_label: Frame,
+    pub frustum_bottom_output: FloatInput,
+    pub lens_shift_x_label: Frame,
+    pub lens_shift_x_output: FloatInput,
+    pub lens_shift_y_label: Frame,
+    pub lens_shift_y_output: FloatInput,
+    pub curvature_center_label: Frame,
+    pub curvature_center_output: FloatInput,
+    pub curvature_center_unit_selector: Choice,
+    pub curvature_center_warning: Frame,
+    pub focal_length_label: Frame,
+    pub sensor_selector: Choice,
+    pub focal_length_output_label: Frame,
+    pub focal_length_output: FloatInput,
+    pub equivalent_focal_length_label: Frame,
+    pub equivalent_focal_length_output: FloatInput,
+    pub millimeters_label: Frame,
 }
 impl PortalLike {
-    pub fn new() -> Self {
+    pub fn new(ui: &RcUi) -> Self {
         let containing_group = Group::default().with_label("Portal-like");
 
-        let fov_label = Frame::default().with_label("Field of view:");
+        let fov_label = Frame::default().with_label("Horizontal field of view:");
         let mut fov_output = FloatInput::default();
         fov_output.set_readonly(true);
 
+        let vertical_fov_label = Frame::default().with_label(", vertical:");
+        let mut vertical_fov_output = FloatInput::default();
+        vertical_fov_output.set_readonly(true);
+
+        let diagonal_fov_label = Frame::default().with_label(", diagonal:");
+        let mut diagonal_fov_output = FloatInput::default();
+        diagonal_fov_output.set_readonly(true);
+
+        let convention_label = Frame::default().with_label("In-game field of view,");
+        let convention_selector = build_fov_convention_selector(ui);
+        let convention_output_label = Frame::default().with_label(":");
+        let mut convention_output = FloatInput::default();
+        convention_output.set_readonly(true);
+
         let move_label_1 = Frame::default().with_label("Move the camera back");
         let mut move_output = FloatInput::default();
         move_output.set_readonly(true);
         let move_unit_selector =
-            build_unit_selector(&move_output, Some(Unit::Meters), Plural, false);
+            build_unit_selector(&move_output, Some(LengthUnit::Meters), Plural, false);
 
         let mut move_label_2 = Frame::default().with_label("(");
         move_label_2.hide();
@@ -45,18 +92,93 @@ impl PortalLike {
         let mut move_label_3 = Frame::default().with_label("units)");
         move_label_3.hide();
 
+        let frustum_left_label = Frame::default().with_label("Frustum angles, left:");
+        let mut frustum_left_output = FloatInput::default();
+        frustum_left_output.set_readonly(true);
+        let frustum_right_label = Frame::default().with_label(", right:");
+        let mut frustum_right_output = FloatInput::default();
+        frustum_right_output.set_readonly(true);
+        let frustum_top_label = Frame::default().with_label(", top:");
+        let mut frustum_top_output = FloatInput::default();
+        frustum_top_output.set_readonly(true);
+        let frustum_bottom_label = Frame::default().with_label(", bottom:");
+        let mut frustum_bottom_output = FloatInput::default();
+        frustum_bottom_output.set_readonly(true);
+
+        let lens_shift_x_label = Frame::default().with_label("Lens shift, X:");
+        let mut lens_shift_x_output = FloatInput::default();
+        lens_shift_x_output.set_readonly(true);
+        let lens_shift_y_label = Frame::default().with_label(", Y:");
+        let mut lens_shift_y_output = FloatInput::default();
+        lens_shift_y_output.set_readonly(true);
+
+        let curvature_center_label =
+            Frame::default().with_label("Distance behind the curvature center:");
+        let mut curvature_center_output = FloatInput::default();
+        curvature_center_output.set_readonly(true);
+        let curvature_center_unit_selector = build_unit_selector(
+            &curvature_center_output,
+            Some(LengthUnit::Centimeters),
+            Plural,
+            false,
+        );
+        let mut curvature_center_warning =
+            Frame::default().with_label("(too far off for the curvature to look right)");
+        curvature_center_warning.hide();
+
+        let focal_length_label = Frame::default().with_label("Focal length with a");
+        let sensor_selector = build_sensor_selector(ui);
+        let focal_length_output_label = Frame::default().with_label("sensor:");
+        let mut focal_length_output = FloatInput::default();
+        focal_length_output.set_readonly(true);
+        let equivalent_focal_length_label = Frame::default().with_label("mm, 35 mm equivalent:");
+        let mut equivalent_focal_length_output = FloatInput::default();
+        equivalent_focal_length_output.set_readonly(true);
+        let millimeters_label = Frame::default().with_label("mm");
+
         containing_group.end();
 
         Self {
             containing_group,
             fov_label,
             fov_output,
+            vertical_fov_label,
+            vertical_fov_output,
+            diagonal_fov_label,
+            diagonal_fov_output,
+            convention_label,
+            convention_selector,
+            convention_output_label,
+            convention_output,
             move_label_1,
             move_output,
             move_unit_selector,
             move_label_2,
             move_units_output,
             move_label_3,
+            frustum_left_label,
+            frustum_left_output,
+            frustum_right_label,
+            frustum_right_output,
+            frustum_top_label,
+            frustum_top_output,
+            frustum_bottom_label,
+            frustum_bottom_output,
+            lens_shift_x_label,
+            lens_shift_x_output,
+            lens_shift_y_label,
+            lens_shift_y_output,
+            curvature_center_label,
+            curvature_center_output,
+            curvature_center_unit_selector,
+            curvature_center_warning,
+            focal_length_label,
+            sensor_selector,
+            focal_length_output_label,
+            focal_length_output,
+            equivalent_focal_length_label,
+            equivalent_focal_length_output,
+            millimeters_label,
         }
     }
     pub fn apply_layout(&mut self, layout: &PortalLikeLayout, pos: Position) {
@@ -66,6 +188,22 @@ impl PortalLike {
             .set_rect(layout.fov_label.with_added_pos(pos));
         self.fov_output
             .set_rect(layout.fov_output.with_added_pos(pos));
+        self.vertical_fov_label
+            .set_rect(layout.vertical_fov_label.with_added_pos(pos));
+        self.vertical_fov_output
+            .set_rect(layout.vertical_fov_output.with_added_pos(pos));
+        self.diagonal_fov_label
+            .set_rect(layout.diagonal_fov_label.with_added_pos(pos));
+        self.diagonal_fov_output
+            .set_rect(layout.diagonal_fov_output.with_added_pos(pos));
+        self.convention_label
+            .set_rect(layout.convention_label.with_added_pos(pos));
+        self.convention_selector
+            .set_rect(layout.convention_selector.with_added_pos(pos));
+        self.convention_output_label
+            .set_rect(layout.convention_output_label.with_added_pos(pos));
+        self.convention_output
+            .set_rect(layout.convention_output.with_added_pos(pos));
         self.move_label_1
             .set_rect(layout.move_label_1.with_added_pos(pos));
         self.move_output
@@ -78,6 +216,76 @@ impl PortalLike {
             .set_rect(layout.move_units_output.with_added_pos(pos));
         self.move_label_3
             .set_rect(layout.move_label_3.with_added_pos(pos));
+        self.frustum_left_label
+            .set_rect(layout.frustum_left_label.with_added_pos(pos));
+        self.frustum_left_output
+            .set_rect(layout.frustum_left_output.with_added_pos(pos));
+        self.frustum_right_label
+            .set_rect(layout.frustum_right_label.with_added_pos(pos));
+        self.frustum_right_output
+            .set_rect(layout.frustum_right_output.with_added_pos(pos));
+        self.frustum_top_label
+            .set_rect(layout.frustum_top_label.with_added_pos(pos));
+        self.frustum_top_output
+            .set_rect(layout.frustum_top_output.with_added_pos(pos));
+        self.frustum_bottom_label
+            .set_rect(layout.frustum_bottom_label.with_added_pos(pos));
+        self.frustum_bottom_output
+            .set_rect(layout.frustum_bottom_output.with_added_pos(pos));
+        self.lens_shift_x_label
+            .set_rect(layout.lens_shift_x_label.with_added_pos(pos));
+        self.lens_shift_x_output
+            .set_rect(layout.lens_shift_x_output.with_added_pos(pos));
+        self.lens_shift_y_label
+            .set_rect(layout.lens_shift_y_label.with_added_pos(pos));
+        self.lens_shift_y_output
+            .set_rect(layout.lens_shift_y_output.with_added_pos(pos));
+        self.curvature_center_label
+            .set_rect(layout.curvature_center_label.with_added_pos(pos));
+        self.curvature_center_output
+            .set_rect(layout.curvature_center_output.with_added_pos(pos));
+        self.curvature_center_unit_selector
+            .set_rect(layout.curvature_center_unit_selector.with_added_pos(pos));
+        self.curvature_center_warning
+            .set_rect(layout.curvature_center_warning.with_added_pos(pos));
+        self.focal_length_label
+            .set_rect(layout.focal_length_label.with_added_pos(pos));
+        self.sensor_selector
+            .set_rect(layout.sensor_selector.with_added_pos(pos));
+        self.focal_length_output_label
+            .set_rect(layout.focal_length_output_label.with_added_pos(pos));
+        self.focal_length_output
+            .set_rect(layout.focal_length_output.with_added_pos(pos));
+        self.equivalent_focal_length_label
+            .set_rect(layout.equivalent_focal_length_label.with_added_pos(pos));
+        self.equivalent_focal_length_output
+            .set_rect(layout.equivalent_focal_length_output.with_added_pos(pos));
+        self.millimeters_label
+            .set_rect(layout.millimeters_label.with_added_pos(pos));
+    }
+    pub fn clear(&mut self) {
+        for output in [
+            &mut self.fov_output,
+            &mut self.vertical_fov_output,
+            &mut self.diagonal_fov_output,
+            &mut self.convention_output,
+            &mut self.move_output,
+            &mut self.move_units_output,
+            &mut self.frustum_left_output,
+            &mut self.frustum_right_output,
+            &mut self.frustum_top_output,
+            &mut self.frustum_bottom_output,
+            &mut self.lens_shift_x_output,
+            &mut self.lens_shift_y_output,
+            &mut self.curvature_center_output,
+            &mut self.focal_length_output,
+            &mut self.equivalent_focal_length_output,
+        ]
+        .iter_mut()
+        {
+            output.set_value("");
+        }
+        self.curvature_center_warning.hide();
     }
     pub fn update(ui: &RcUi) {
         let mut _u = ui.borrow_mut();
@@ -85,31 +293,53 @@ impl PortalLike {
         let mp = &mut u.monitor_properties;
         let pl = &mut u.output_tabs.portal_like;
         let us = &mut u.unit_setup;
-        let width = mp.width_input.value().parse::<f64>();
-        let height = mp.width_input.value().parse::<f64>();
-        let distance = mp.distance_input.value().parse::<f64>();
         let app_per_real = us.app_per_real_input.value().parse::<f64>();
-        if let (Ok(width), Ok(height), Ok(distance)) = (width, height, distance) {
-            let move_unit = pl.move_unit_selector.value().try_into().unwrap();
-            let width_unit = mp.width_unit_selector.value().try_into().unwrap();
-            let height_unit = mp.height_unit_selector.value().try_into().unwrap();
-            let distance_unit = mp.distance_unit_selector.value().try_into().unwrap();
-            let width = length_from_unit(width, width_unit);
-            let height = length_from_unit(height, height_unit);
-            let distance = length_from_unit(distance, distance_unit);
-
-            let mov = convert_units(distance, move_unit);
-            let monitor_conf = MonitorConfiguration {
-                dimensions: MonitorDimensions::WidthAndHeight { width, height },
-                distance,
-            };
-            let fov = monitor_conf.fov();
-
-            pl.fov_output.set_value(&format!(
-                "{}{}",
-                &friendly_ftoa(fov.get::<degree>()),
-                DEGREE_SIGN,
+        if let Ok(monitor_conf) = mp.game_configuration() {
+            let move_unit = selected_unit(&pl.move_unit_selector);
+            let mov = move_unit.value_of(monitor_conf.distance);
+
+            let fov_interval = monitor_conf.fov_interval(mp.tolerances());
+            pl.fov_output
+                .set_value(&friendly_fov_interval(monitor_conf.fov(), fov_interval));
+            pl.vertical_fov_output
+                .set_value(&friendly_degrees(monitor_conf.vertical_fov()));
+            pl.diagonal_fov_output
+                .set_value(&friendly_degrees(monitor_conf.diagonal_fov()));
+            let (_, convention) = FOV_CONVENTIONS[pl.convention_selector.value() as usize];
+            pl.convention_output.set_value(&friendly_degrees(
+                monitor_conf.fov_for_convention(convention),
+            ));
+            let frustum = monitor_conf.frustum();
+            pl.frustum_left_output
+                .set_value(&friendly_degrees(frustum.left));
+            pl.frustum_right_output
+                .set_value(&friendly_degrees(frustum.right));
+            pl.frustum_top_output
+                .set_value(&friendly_degrees(frustum.top));
+            pl.frustum_bottom_output
+                .set_value(&friendly_degrees(frustum.bottom));
+            let [shift_x, shift_y] = frustum.lens_shift();
+            pl.lens_shift_x_output.set_value(&friendly_ftoa(shift_x));
+            pl.lens_shift_y_output.set_value(&friendly_ftoa(shift_y));
+            let (_, sensor) = SENSOR_SIZES[pl.sensor_selector.value() as usize];
+            pl.focal_length_output.set_value(&friendly_ftoa(
+                monitor_conf.focal_length(sensor).get::<millimeter>(),
             ));
+            pl.equivalent_focal_length_output.set_value(&friendly_ftoa(
+                monitor_conf.equivalent_focal_length().get::<millimeter>(),
+            ));
+            if let Some(offset) = monitor_conf.distance_from_curvature_center() {
+                let curvature_center_unit = selected_unit(&pl.curvature_center_unit_selector);
+                pl.curvature_center_output
+                    .set_value(&friendly_ftoa(curvature_center_unit.value_of(offset)));
+            } else {
+                pl.curvature_center_output.set_value("");
+            }
+            if monitor_conf.is_far_from_curvature_center(CURVATURE_CENTER_TOLERANCE) {
+                pl.curvature_center_warning.show();
+            } else {
+                pl.curvature_center_warning.hide();
+            }
             pl.move_output.set_value(&friendly_ftoa(mov));
             if let Ok(app_per_real) = app_per_real {
                 pl.move_units_output
@@ -122,6 +352,8 @@ impl PortalLike {
                 pl.move_units_output.hide();
                 pl.move_label_3.hide();
             }
+        } else {
+            pl.clear();
         }
     }
 }
@@ -129,7 +361,7 @@ impl LayoutGen<'_> for PortalLike {
     type Arguments = ();
     type Layout = PortalLikeLayout;
     fn generate_layout(&self, _: Self::Arguments) -> Self::Layout {
-        const NUM_LINES: i32 = 2;
+        const NUM_LINES: i32 = 7;
 
         let height_l1;
         let mut width_l1 = GROUP_H_PADDING * 2;
@@ -141,42 +373,220 @@ impl LayoutGen<'_> for PortalLike {
         height_l1 = fov_label.h() + ADDED_HEIGHT;
         width_l1 += fov_label.w();
 
-        let fov_output = Rect(fov_label.to_right(5), Size(70, height_l1));
-        width_l1 += fov_output.w();
+        let fov_output = Rect(fov_label.to_right(5), Size(110, height_l1));
+        width_l1 += fov_output.w() + 5;
+
+        let vertical_fov_label = Rect(
+            fov_output.to_right(0),
+            self.vertical_fov_label.measure_label().repack(),
+        );
+        width_l1 += vertical_fov_label.w();
+
+        let vertical_fov_output = Rect(vertical_fov_label.to_right(5), Size(70, height_l1));
+        width_l1 += vertical_fov_output.w() + 5;
+
+        let diagonal_fov_label = Rect(
+            vertical_fov_output.to_right(0),
+            self.diagonal_fov_label.measure_label().repack(),
+        );
+        width_l1 += diagonal_fov_label.w();
+
+        let diagonal_fov_output = Rect(diagonal_fov_label.to_right(5), Size(70, height_l1));
+        width_l1 += diagonal_fov_output.w() + 5;
 
         let height_l2;
         let mut width_l2 = GROUP_H_PADDING * 2;
-        let move_label_1 = Rect(
+
+        let convention_label = Rect(
             fov_label.to_bottom(LINE_V_PADDING),
+            self.convention_label.measure_label().repack(),
+        );
+        height_l2 = convention_label.h() + ADDED_HEIGHT;
+        width_l2 += convention_label.w();
+
+        let convention_selector = Rect(convention_label.to_right(5), Size(160, height_l2));
+        width_l2 += convention_selector.w() + 5;
+
+        let convention_output_label = Rect(
+            convention_selector.to_right(0),
+            self.convention_output_label.measure_label().repack(),
+        );
+        width_l2 += convention_output_label.w();
+
+        let convention_output = Rect(convention_output_label.to_right(5), Size(70, height_l2));
+        width_l2 += convention_output.w() + 5;
+
+        let height_l3;
+        let mut width_l3 = GROUP_H_PADDING * 2;
+        let move_label_1 = Rect(
+            convention_label.to_bottom(LINE_V_PADDING),
             self.move_label_1.measure_label().repack(),
         );
-        height_l2 = move_label_1.h() + ADDED_HEIGHT;
-        width_l2 += move_label_1.w();
+        height_l3 = move_label_1.h() + ADDED_HEIGHT;
+        width_l3 += move_label_1.w();
 
-        let move_output = Rect(move_label_1.to_right(5), Size(70, height_l2));
-        width_l2 += move_output.w();
+        let move_output = Rect(move_label_1.to_right(5), Size(70, height_l3));
+        width_l3 += move_output.w();
 
-        let move_unit_selector = Rect(move_output.to_right(5), Size(105, height_l2));
-        width_l2 += move_unit_selector.w();
+        let move_unit_selector = Rect(move_output.to_right(5), Size(105, height_l3));
+        width_l3 += move_unit_selector.w();
 
         let move_label_2 = Rect(
             move_unit_selector.to_right(5),
             self.move_label_2.measure_label().repack(),
         );
-        width_l2 += move_label_2.w();
+        width_l3 += move_label_2.w();
 
-        let move_units_output = Rect(move_label_2.to_right(2), Size(70, height_l2));
-        width_l2 += move_units_output.w();
+        let move_units_output = Rect(move_label_2.to_right(2), Size(70, height_l3));
+        width_l3 += move_units_output.w();
 
         let move_label_3 = Rect(
             move_units_output.to_right(5),
             self.move_label_3.measure_label().repack(),
         );
-        width_l2 += move_label_3.w();
+        width_l3 += move_label_3.w();
+
+        let height_l4;
+        let mut width_l4 = GROUP_H_PADDING * 2;
+
+        let frustum_left_label = Rect(
+            move_label_1.to_bottom(LINE_V_PADDING),
+            self.frustum_left_label.measure_label().repack(),
+        );
+        height_l4 = frustum_left_label.h() + ADDED_HEIGHT;
+        width_l4 += frustum_left_label.w();
+
+        let frustum_left_output = Rect(frustum_left_label.to_right(5), Size(70, height_l4));
+        width_l4 += frustum_left_output.w() + 5;
+
+        let frustum_right_label = Rect(
+            frustum_left_output.to_right(0),
+            self.frustum_right_label.measure_label().repack(),
+        );
+        width_l4 += frustum_right_label.w();
+
+        let frustum_right_output = Rect(frustum_right_label.to_right(5), Size(70, height_l4));
+        width_l4 += frustum_right_output.w() + 5;
+
+        let frustum_top_label = Rect(
+            frustum_right_output.to_right(0),
+            self.frustum_top_label.measure_label().repack(),
+        );
+        width_l4 += frustum_top_label.w();
+
+        let frustum_top_output = Rect(frustum_top_label.to_right(5), Size(70, height_l4));
+        width_l4 += frustum_top_output.w() + 5;
+
+        let frustum_bottom_label = Rect(
+            frustum_top_output.to_right(0),
+            self.frustum_bottom_label.measure_label().repack(),
+        );
+        width_l4 += frustum_bottom_label.w();
+
+        let frustum_bottom_output = Rect(frustum_bottom_label.to_right(5), Size(70, height_l4));
+        width_l4 += frustum_bottom_output.w() + 5;
+
+        let height_l5;
+        let mut width_l5 = GROUP_H_PADDING * 2;
+
+        let lens_shift_x_label = Rect(
+            frustum_left_label.to_bottom(LINE_V_PADDING),
+            self.lens_shift_x_label.measure_label().repack(),
+        );
+        height_l5 = lens_shift_x_label.h() + ADDED_HEIGHT;
+        width_l5 += lens_shift_x_label.w();
+
+        let lens_shift_x_output = Rect(lens_shift_x_label.to_right(5), Size(70, height_l5));
+        width_l5 += lens_shift_x_output.w() + 5;
+
+        let lens_shift_y_label = Rect(
+            lens_shift_x_output.to_right(0),
+            self.lens_shift_y_label.measure_label().repack(),
+        );
+        width_l5 += lens_shift_y_label.w();
+
+        let lens_shift_y_output = Rect(lens_shift_y_label.to_right(5), Size(70, height_l5));
+        width_l5 += lens_shift_y_output.w() + 5;
+
+        let height_l6;
+        let mut width_l6 = GROUP_H_PADDING * 2;
+
+        let curvature_center_label = Rect(
+            lens_shift_x_label.to_bottom(LINE_V_PADDING),
+            self.curvature_center_label.measure_label().repack(),
+        );
+        height_l6 = curvature_center_label.h() + ADDED_HEIGHT;
+        width_l6 += curvature_center_label.w();
+
+        let curvature_center_output = Rect(curvature_center_label.to_right(5), Size(70, height_l6));
+        width_l6 += curvature_center_output.w() + 5;
+
+        let curvature_center_unit_selector =
+            Rect(curvature_center_output.to_right(5), Size(105, height_l6));
+        width_l6 += curvature_center_unit_selector.w() + 5;
+
+        let curvature_center_warning = Rect(
+            curvature_center_unit_selector.to_right(5),
+            self.curvature_center_warning.measure_label().repack(),
+        );
+        width_l6 += curvature_center_warning.w();
+
+        let height_l7;
+        let mut width_l7 = GROUP_H_PADDING * 2;
+
+        let focal_length_label = Rect(
+            curvature_center_label.to_bottom(LINE_V_PADDING),
+            self.focal_length_label.measure_label().repack(),
+        );
+        height_l7 = focal_length_label.h() + ADDED_HEIGHT;
+        width_l7 += focal_length_label.w();
+
+        let sensor_selector = Rect(focal_length_label.to_right(5), Size(120, height_l7));
+        width_l7 += sensor_selector.w() + 5;
+
+        let focal_length_output_label = Rect(
+            sensor_selector.to_right(5),
+            self.focal_length_output_label.measure_label().repack(),
+        );
+        width_l7 += focal_length_output_label.w() + 5;
+
+        let focal_length_output = Rect(focal_length_output_label.to_right(5), Size(70, height_l7));
+        width_l7 += focal_length_output.w() + 5;
+
+        let equivalent_focal_length_label = Rect(
+            focal_length_output.to_right(5),
+            self.equivalent_focal_length_label.measure_label().repack(),
+        );
+        width_l7 += equivalent_focal_length_label.w() + 5;
+
+        let equivalent_focal_length_output = Rect(
+            equivalent_focal_length_label.to_right(5),
+            Size(70, height_l7),
+        );
+        width_l7 += equivalent_focal_length_output.w() + 5;
+
+        let millimeters_label = Rect(
+            equivalent_focal_length_output.to_right(5),
+            self.millimeters_label.measure_label().repack(),
+        );
+        width_l7 += millimeters_label.w() + 5;
 
-        let total_width = max(width_l1, width_l2);
-        let total_height =
-            height_l1 + height_l2 + LINE_V_PADDING * (NUM_LINES - 1) + GROUP_V_PADDING * 2;
+        let total_width = [
+            width_l1, width_l2, width_l3, width_l4, width_l5, width_l6, width_l7,
+        ]
+        .iter()
+        .copied()
+        .max()
+        .unwrap();
+        let total_height = height_l1
+            + height_l2
+            + height_l3
+            + height_l4
+            + height_l5
+            + height_l6
+            + height_l7
+            + LINE_V_PADDING * (NUM_LINES - 1)
+            + GROUP_V_PADDING * 2;
 
         let total_size = Size(total_width, total_height);
         PortalLikeLayout {
@@ -184,12 +594,43 @@ impl LayoutGen<'_> for PortalLike {
             containing_group: Rect(Position(0, 0), total_size),
             fov_label,
             fov_output,
+            vertical_fov_label,
+            vertical_fov_output,
+            diagonal_fov_label,
+            diagonal_fov_output,
+            convention_label,
+            convention_selector,
+            convention_output_label,
+            convention_output,
             move_label_1,
             move_output,
             move_unit_selector,
             move_label_2,
             move_units_output,
             move_label_3,
+            frustum_left_label,
+            frustum_left_output,
+            frustum_right_label,
+            frustum_right_output,
+            frustum_top_label,
+            frustum_top_output,
+            frustum_bottom_label,
+            frustum_bottom_output,
+            lens_shift_x_label,
+            lens_shift_x_output,
+            lens_shift_y_label,
+            lens_shift_y_output,
+            curvature_center_label,
+            curvature_center_output,
+            curvature_center_unit_selector,
+            curvature_center_warning,
+            focal_length_label,
+            sensor_selector,
+            focal_length_output_label,
+            focal_length_output,
+            equivalent_focal_length_label,
+            equivalent_focal_length_output,
+            millimeters_label,
         }
     }
 }
@@ -197,6 +638,19 @@ impl LayoutGen<'_> for PortalLike {
 make_layout!(pub PortalLikeLayout, has
     containing_group,
     fov_label, fov_output,
+    vertical_fov_label, vertical_fov_output,
+    diagonal_fov_label, diagonal_fov_output,
+    convention_label, convention_selector, convention_output_label, convention_output,
     move_label_1, move_output, move_unit_selector,
     move_label_2, move_units_output, move_label_3,
+    frustum_left_label, frustum_left_output,
+    frustum_right_label, frustum_right_output,
+    frustum_top_label, frustum_top_output,
+    frustum_bottom_label, frustum_bottom_output,
+    lens_shift_x_label, lens_shift_x_output,
+    lens_shift_y_label, lens_shift_y_output,
+    curvature_center_label, curvature_center_output, curvature_center_unit_selector,
+    curvature_center_warning,
+    focal_length_label, sensor_selector, focal_length_output_label, focal_length_output,
+    equivalent_focal_length_label, equivalent_focal_length_output, millimeters_label,
 );
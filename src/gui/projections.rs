@@ -0,0 +1,261 @@
+use crate::{
+    build_updating_selector,
+    layout::{LayoutGen, Position, Rect, Size},
+    util::{friendly_degrees, friendly_ftoa, PosExt, Repack, PROJECTION_MODELS},
+    RcUi, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
+};
+use fltk::{frame::Frame, group::Group, input::FloatInput, menu::Choice, prelude::*};
+use fpvsetup::ProjectionModel;
+use std::rc::Rc;
+use uom::si::{angle::degree, f64::Angle};
+
+#[derive(Clone)]
+pub struct Projections {
+    pub containing_group: Group,
+    pub model_label: Frame,
+    pub model_selector: Choice,
+    pub panini_distance_label: Frame,
+    pub panini_distance_input: FloatInput,
+    pub fov_label: Frame,
+    pub fov_output: FloatInput,
+    pub vertical_fov_label: Frame,
+    pub vertical_fov_output: FloatInput,
+    pub panini_solver_label_1: Frame,
+    pub panini_solver_input: FloatInput,
+    pub panini_solver_label_2: Frame,
+    pub panini_solver_output: FloatInput,
+}
+impl Projections {
+    pub fn new(ui: &RcUi) -> Self {
+        let containing_group = Group::default().with_label("Projections");
+
+        let model_label = Frame::default().with_label("Projection:");
+        let model_selector =
+            build_updating_selector(ui, PROJECTION_MODELS.iter().map(|(label, _)| *label));
+        let panini_distance_label = Frame::default().with_label(", Panini distance:");
+        let mut panini_distance_input = FloatInput::default();
+        panini_distance_input.set_value("1");
+        let r = Rc::clone(ui);
+        panini_distance_input.set_callback(move || Self::update(&r));
+        panini_distance_input.set_trigger(CallbackTrigger::Changed);
+
+        let fov_label = Frame::default().with_label("Horizontal field of view:");
+        let mut fov_output = FloatInput::default();
+        fov_output.set_readonly(true);
+        let vertical_fov_label = Frame::default().with_label(", vertical:");
+        let mut vertical_fov_output = FloatInput::default();
+        vertical_fov_output.set_readonly(true);
+
+        let panini_solver_label_1 =
+            Frame::default().with_label("Panini distance for a vertical field of view of");
+        let mut panini_solver_input = FloatInput::default();
+        let r = Rc::clone(ui);
+        panini_solver_input.set_callback(move || Self::update(&r));
+        panini_solver_input.set_trigger(CallbackTrigger::Changed);
+        let panini_solver_label_2 = Frame::default().with_label("°:");
+        let mut panini_solver_output = FloatInput::default();
+        panini_solver_output.set_readonly(true);
+
+        containing_group.end();
+
+        Self {
+            containing_group,
+            model_label,
+            model_selector,
+            panini_distance_label,
+            panini_distance_input,
+            fov_label,
+            fov_output,
+            vertical_fov_label,
+            vertical_fov_output,
+            panini_solver_label_1,
+            panini_solver_input,
+            panini_solver_label_2,
+            panini_solver_output,
+        }
+    }
+    pub fn apply_layout(&mut self, layout: &ProjectionsLayout, pos: Position) {
+        self.containing_group
+            .set_rect(layout.containing_group.with_added_pos(pos));
+        self.model_label
+            .set_rect(layout.model_label.with_added_pos(pos));
+        self.model_selector
+            .set_rect(layout.model_selector.with_added_pos(pos));
+        self.panini_distance_label
+            .set_rect(layout.panini_distance_label.with_added_pos(pos));
+        self.panini_distance_input
+            .set_rect(layout.panini_distance_input.with_added_pos(pos));
+        self.fov_label
+            .set_rect(layout.fov_label.with_added_pos(pos));
+        self.fov_output
+            .set_rect(layout.fov_output.with_added_pos(pos));
+        self.vertical_fov_label
+            .set_rect(layout.vertical_fov_label.with_added_pos(pos));
+        self.vertical_fov_output
+            .set_rect(layout.vertical_fov_output.with_added_pos(pos));
+        self.panini_solver_label_1
+            .set_rect(layout.panini_solver_label_1.with_added_pos(pos));
+        self.panini_solver_input
+            .set_rect(layout.panini_solver_input.with_added_pos(pos));
+        self.panini_solver_label_2
+            .set_rect(layout.panini_solver_label_2.with_added_pos(pos));
+        self.panini_solver_output
+            .set_rect(layout.panini_solver_output.with_added_pos(pos));
+    }
+    pub fn clear(&mut self) {
+        for output in [
+            &mut self.fov_output,
+            &mut self.vertical_fov_output,
+            &mut self.panini_solver_output,
+        ]
+        .iter_mut()
+        {
+            output.set_value("");
+        }
+    }
+    pub fn update(ui: &RcUi) {
+        let mut _u = ui.borrow_mut();
+        let u = _u.as_mut().unwrap();
+        let mp = &mut u.monitor_properties;
+        let pr = &mut u.output_tabs.projections;
+        if let Ok(monitor_conf) = mp.game_configuration() {
+            let (_, mut model) = PROJECTION_MODELS[pr.model_selector.value() as usize];
+            if let ProjectionModel::Panini { distance } = &mut model {
+                match pr.panini_distance_input.value().parse::<f64>() {
+                    Ok(d) => *distance = d,
+                    Err(..) => return pr.clear(),
+                }
+            }
+            let fov = monitor_conf.projection_fov(model);
+            pr.fov_output.set_value(&friendly_degrees(fov.horizontal));
+            pr.vertical_fov_output
+                .set_value(&friendly_degrees(fov.vertical));
+
+            let panini_distance =
+                pr.panini_solver_input
+                    .value()
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|vertical_fov| {
+                        monitor_conf
+                            .panini_distance_for_vertical_fov(Angle::new::<degree>(vertical_fov))
+                    });
+            pr.panini_solver_output
+                .set_value(&panini_distance.map(friendly_ftoa).unwrap_or_default());
+        } else {
+            pr.clear();
+        }
+    }
+}
+impl LayoutGen<'_> for Projections {
+    type Layout = ProjectionsLayout;
+    type Arguments = ();
+
+    fn generate_layout(&self, _: Self::Arguments) -> Self::Layout {
+        const NUM_LINES: i32 = 3;
+
+        let height_l1;
+        let mut width_l1 = GROUP_H_PADDING * 2;
+
+        let model_label = Rect(
+            Position(GROUP_H_PADDING, GROUP_V_PADDING),
+            self.model_label.measure_label().repack(),
+        );
+        height_l1 = model_label.h() + ADDED_HEIGHT;
+        width_l1 += model_label.w();
+
+        let model_selector = Rect(model_label.to_right(5), Size(170, height_l1));
+        width_l1 += model_selector.w() + 5;
+
+        let panini_distance_label = Rect(
+            model_selector.to_right(0),
+            self.panini_distance_label.measure_label().repack(),
+        );
+        width_l1 += panini_distance_label.w();
+
+        let panini_distance_input = Rect(panini_distance_label.to_right(5), Size(70, height_l1));
+        width_l1 += panini_distance_input.w() + 5;
+
+        let height_l2;
+        let mut width_l2 = GROUP_H_PADDING * 2;
+
+        let fov_label = Rect(
+            model_label.to_bottom(LINE_V_PADDING),
+            self.fov_label.measure_label().repack(),
+        );
+        height_l2 = fov_label.h() + ADDED_HEIGHT;
+        width_l2 += fov_label.w();
+
+        let fov_output = Rect(fov_label.to_right(5), Size(70, height_l2));
+        width_l2 += fov_output.w() + 5;
+
+        let vertical_fov_label = Rect(
+            fov_output.to_right(0),
+            self.vertical_fov_label.measure_label().repack(),
+        );
+        width_l2 += vertical_fov_label.w();
+
+        let vertical_fov_output = Rect(vertical_fov_label.to_right(5), Size(70, height_l2));
+        width_l2 += vertical_fov_output.w() + 5;
+
+        let height_l3;
+        let mut width_l3 = GROUP_H_PADDING * 2;
+
+        let panini_solver_label_1 = Rect(
+            fov_label.to_bottom(LINE_V_PADDING),
+            self.panini_solver_label_1.measure_label().repack(),
+        );
+        height_l3 = panini_solver_label_1.h() + ADDED_HEIGHT;
+        width_l3 += panini_solver_label_1.w();
+
+        let panini_solver_input = Rect(panini_solver_label_1.to_right(5), Size(70, height_l3));
+        width_l3 += panini_solver_input.w() + 5;
+
+        let panini_solver_label_2 = Rect(
+            panini_solver_input.to_right(0),
+            self.panini_solver_label_2.measure_label().repack(),
+        );
+        width_l3 += panini_solver_label_2.w();
+
+        let panini_solver_output = Rect(panini_solver_label_2.to_right(5), Size(70, height_l3));
+        width_l3 += panini_solver_output.w() + 5;
+
+        let total_width = [width_l1, width_l2, width_l3]
+            .iter()
+            .copied()
+            .max()
+            .unwrap();
+        let total_height = height_l1
+            + height_l2
+            + height_l3
+            + LINE_V_PADDING * (NUM_LINES - 1)
+            + GROUP_V_PADDING * 2;
+        let total_size = Size(total_width, total_height);
+        let containing_group = Rect(Position(0, 0), total_size);
+        ProjectionsLayout {
+            total_size,
+            containing_group,
+            model_label,
+            model_selector,
+            panini_distance_label,
+            panini_distance_input,
+            fov_label,
+            fov_output,
+            vertical_fov_label,
+            vertical_fov_output,
+            panini_solver_label_1,
+            panini_solver_input,
+            panini_solver_label_2,
+            panini_solver_output,
+        }
+    }
+}
+
+make_layout!(pub ProjectionsLayout, has
+    containing_group,
+    model_label, model_selector,
+    panini_distance_label, panini_distance_input,
+    fov_label, fov_output,
+    vertical_fov_label, vertical_fov_output,
+    panini_solver_label_1, panini_solver_input, panini_solver_label_2, panini_solver_output,
+);
@@ -0,0 +1,271 @@
+use crate::{
+    build_fov_convention_selector,
+    layout::{LayoutGen, Position, Rect, Size},
+    util::{friendly_degrees, friendly_ftoa, PosExt, Repack, FOV_CONVENTIONS},
+    RcUi, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
+};
+use fltk::{frame::Frame, group::Group, input::FloatInput, menu::Choice, prelude::*};
+use std::rc::Rc;
+use uom::si::{angle::degree, f64::Angle};
+
+#[derive(Clone)]
+pub struct Scopes {
+    pub containing_group: Group,
+    pub convention_label: Frame,
+    pub convention_selector: Choice,
+    pub magnification_label_1: Frame,
+    pub magnification_input: FloatInput,
+    pub magnification_label_2: Frame,
+    pub zoomed_fov_output: FloatInput,
+    pub zoomed_convention_label: Frame,
+    pub zoomed_convention_output: FloatInput,
+    pub effective_label_1: Frame,
+    pub effective_input: FloatInput,
+    pub effective_label_2: Frame,
+    pub effective_output: FloatInput,
+    pub effective_label_3: Frame,
+}
+impl Scopes {
+    pub fn new(ui: &RcUi) -> Self {
+        let containing_group = Group::default().with_label("Scopes");
+
+        let convention_label = Frame::default().with_label("In-game field of view:");
+        let convention_selector = build_fov_convention_selector(ui);
+
+        let magnification_label_1 = Frame::default().with_label("Scope magnification:");
+        let mut magnification_input = FloatInput::default();
+        magnification_input.set_value("4");
+        let r = Rc::clone(ui);
+        magnification_input.set_callback(move || Self::update(&r));
+        magnification_input.set_trigger(CallbackTrigger::Changed);
+        let magnification_label_2 =
+            Frame::default().with_label("×, zoomed horizontal field of view:");
+        let mut zoomed_fov_output = FloatInput::default();
+        zoomed_fov_output.set_readonly(true);
+        let zoomed_convention_label = Frame::default().with_label(", in-game:");
+        let mut zoomed_convention_output = FloatInput::default();
+        zoomed_convention_output.set_readonly(true);
+
+        let effective_label_1 = Frame::default().with_label("An in-game field of view of");
+        let mut effective_input = FloatInput::default();
+        let r = Rc::clone(ui);
+        effective_input.set_callback(move || Self::update(&r));
+        effective_input.set_trigger(CallbackTrigger::Changed);
+        let effective_label_2 = Frame::default().with_label("° magnifies by");
+        let mut effective_output = FloatInput::default();
+        effective_output.set_readonly(true);
+        let effective_label_3 = Frame::default().with_label("×");
+
+        containing_group.end();
+
+        Self {
+            containing_group,
+            convention_label,
+            convention_selector,
+            magnification_label_1,
+            magnification_input,
+            magnification_label_2,
+            zoomed_fov_output,
+            zoomed_convention_label,
+            zoomed_convention_output,
+            effective_label_1,
+            effective_input,
+            effective_label_2,
+            effective_output,
+            effective_label_3,
+        }
+    }
+    pub fn apply_layout(&mut self, layout: &ScopesLayout, pos: Position) {
+        self.containing_group
+            .set_rect(layout.containing_group.with_added_pos(pos));
+        self.convention_label
+            .set_rect(layout.convention_label.with_added_pos(pos));
+        self.convention_selector
+            .set_rect(layout.convention_selector.with_added_pos(pos));
+        self.magnification_label_1
+            .set_rect(layout.magnification_label_1.with_added_pos(pos));
+        self.magnification_input
+            .set_rect(layout.magnification_input.with_added_pos(pos));
+        self.magnification_label_2
+            .set_rect(layout.magnification_label_2.with_added_pos(pos));
+        self.zoomed_fov_output
+            .set_rect(layout.zoomed_fov_output.with_added_pos(pos));
+        self.zoomed_convention_label
+            .set_rect(layout.zoomed_convention_label.with_added_pos(pos));
+        self.zoomed_convention_output
+            .set_rect(layout.zoomed_convention_output.with_added_pos(pos));
+        self.effective_label_1
+            .set_rect(layout.effective_label_1.with_added_pos(pos));
+        self.effective_input
+            .set_rect(layout.effective_input.with_added_pos(pos));
+        self.effective_label_2
+            .set_rect(layout.effective_label_2.with_added_pos(pos));
+        self.effective_output
+            .set_rect(layout.effective_output.with_added_pos(pos));
+        self.effective_label_3
+            .set_rect(layout.effective_label_3.with_added_pos(pos));
+    }
+    pub fn clear(&mut self) {
+        for output in [
+            &mut self.zoomed_fov_output,
+            &mut self.zoomed_convention_output,
+            &mut self.effective_output,
+        ]
+        .iter_mut()
+        {
+            output.set_value("");
+        }
+    }
+    pub fn update(ui: &RcUi) {
+        let mut _u = ui.borrow_mut();
+        let u = _u.as_mut().unwrap();
+        let mp = &mut u.monitor_properties;
+        let sc = &mut u.output_tabs.scopes;
+        if let Ok(monitor_conf) = mp.game_configuration() {
+            let aspect = monitor_conf.dimensions.aspect();
+            let (_, convention) = FOV_CONVENTIONS[sc.convention_selector.value() as usize];
+
+            let zoomed_fov = sc
+                .magnification_input
+                .value()
+                .parse::<f64>()
+                .ok()
+                .map(|magnification| monitor_conf.scope_fov(magnification));
+            sc.zoomed_fov_output
+                .set_value(&zoomed_fov.map(friendly_degrees).unwrap_or_default());
+            sc.zoomed_convention_output.set_value(
+                &zoomed_fov
+                    .map(|fov| friendly_degrees(convention.value_for(fov, aspect)))
+                    .unwrap_or_default(),
+            );
+
+            let magnification = sc.effective_input.value().parse::<f64>().ok().map(|value| {
+                let fov = convention.horizontal_fov_for(Angle::new::<degree>(value), aspect);
+                monitor_conf.magnification_for_fov(fov)
+            });
+            sc.effective_output
+                .set_value(&magnification.map(friendly_ftoa).unwrap_or_default());
+        } else {
+            sc.clear();
+        }
+    }
+}
+impl LayoutGen<'_> for Scopes {
+    type Layout = ScopesLayout;
+    type Arguments = ();
+
+    fn generate_layout(&self, _: Self::Arguments) -> Self::Layout {
+        const NUM_LINES: i32 = 3;
+
+        let height_l1;
+        let mut width_l1 = GROUP_H_PADDING * 2;
+
+        let convention_label = Rect(
+            Position(GROUP_H_PADDING, GROUP_V_PADDING),
+            self.convention_label.measure_label().repack(),
+        );
+        height_l1 = convention_label.h() + ADDED_HEIGHT;
+        width_l1 += convention_label.w();
+
+        let convention_selector = Rect(convention_label.to_right(5), Size(160, height_l1));
+        width_l1 += convention_selector.w() + 5;
+
+        let height_l2;
+        let mut width_l2 = GROUP_H_PADDING * 2;
+
+        let magnification_label_1 = Rect(
+            convention_label.to_bottom(LINE_V_PADDING),
+            self.magnification_label_1.measure_label().repack(),
+        );
+        height_l2 = magnification_label_1.h() + ADDED_HEIGHT;
+        width_l2 += magnification_label_1.w();
+
+        let magnification_input = Rect(magnification_label_1.to_right(5), Size(70, height_l2));
+        width_l2 += magnification_input.w() + 5;
+
+        let magnification_label_2 = Rect(
+            magnification_input.to_right(0),
+            self.magnification_label_2.measure_label().repack(),
+        );
+        width_l2 += magnification_label_2.w();
+
+        let zoomed_fov_output = Rect(magnification_label_2.to_right(5), Size(70, height_l2));
+        width_l2 += zoomed_fov_output.w() + 5;
+
+        let zoomed_convention_label = Rect(
+            zoomed_fov_output.to_right(0),
+            self.zoomed_convention_label.measure_label().repack(),
+        );
+        width_l2 += zoomed_convention_label.w();
+
+        let zoomed_convention_output =
+            Rect(zoomed_convention_label.to_right(5), Size(70, height_l2));
+        width_l2 += zoomed_convention_output.w() + 5;
+
+        let height_l3;
+        let mut width_l3 = GROUP_H_PADDING * 2;
+
+        let effective_label_1 = Rect(
+            magnification_label_1.to_bottom(LINE_V_PADDING),
+            self.effective_label_1.measure_label().repack(),
+        );
+        height_l3 = effective_label_1.h() + ADDED_HEIGHT;
+        width_l3 += effective_label_1.w();
+
+        let effective_input = Rect(effective_label_1.to_right(5), Size(70, height_l3));
+        width_l3 += effective_input.w() + 5;
+
+        let effective_label_2 = Rect(
+            effective_input.to_right(0),
+            self.effective_label_2.measure_label().repack(),
+        );
+        width_l3 += effective_label_2.w();
+
+        let effective_output = Rect(effective_label_2.to_right(5), Size(70, height_l3));
+        width_l3 += effective_output.w() + 5;
+
+        let effective_label_3 = Rect(
+            effective_output.to_right(0),
+            self.effective_label_3.measure_label().repack(),
+        );
+        width_l3 += effective_label_3.w();
+
+        let total_width = [width_l1, width_l2, width_l3]
+            .iter()
+            .copied()
+            .max()
+            .unwrap();
+        let total_height = height_l1
+            + height_l2
+            + height_l3
+            + LINE_V_PADDING * (NUM_LINES - 1)
+            + GROUP_V_PADDING * 2;
+        let total_size = Size(total_width, total_height);
+        let containing_group = Rect(Position(0, 0), total_size);
+        ScopesLayout {
+            total_size,
+            containing_group,
+            convention_label,
+            convention_selector,
+            magnification_label_1,
+            magnification_input,
+            magnification_label_2,
+            zoomed_fov_output,
+            zoomed_convention_label,
+            zoomed_convention_output,
+            effective_label_1,
+            effective_input,
+            effective_label_2,
+            effective_output,
+            effective_label_3,
+        }
+    }
+}
+
+make_layout!(pub ScopesLayout, has
+    containing_group,
+    convention_label, convention_selector,
+    magnification_label_1, magnification_input, magnification_label_2, zoomed_fov_output,
+    zoomed_convention_label, zoomed_convention_output,
+    effective_label_1, effective_input, effective_label_2, effective_output, effective_label_3,
+);
@@ -0,0 +1,211 @@
+use crate::{
+    build_fov_convention_selector, build_unit_selector, build_updating_selector,
+    layout::{LayoutGen, Position, Rect, Size},
+    util::{friendly_ftoa, selected_unit, PosExt, Repack, FOV_CONVENTIONS},
+    Number::*,
+    RcUi, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
+};
+use fltk::{frame::Frame, group::Group, input::FloatInput, menu::Choice, prelude::*};
+use fpvsetup::{LengthUnit, MonitorDimensions};
+use std::{cmp::max, rc::Rc};
+use uom::si::{angle::degree, f64::Angle};
+
+/// The quantities which can be solved for, along with their labels.
+static SOLVE_FOR: &[(&str, SolveFor)] = &[
+    ("viewing distance", SolveFor::Distance),
+    ("monitor width", SolveFor::Width),
+    ("monitor diagonal", SolveFor::Diagonal),
+];
+
+#[derive(Copy, Clone)]
+enum SolveFor {
+    Distance,
+    Width,
+    Diagonal,
+}
+
+#[derive(Clone)]
+pub struct Solver {
+    pub containing_group: Group,
+    pub target_fov_label_1: Frame,
+    pub target_fov_input: FloatInput,
+    pub target_fov_label_2: Frame,
+    pub convention_selector: Choice,
+    pub solve_for_label: Frame,
+    pub solve_for_selector: Choice,
+    pub result_label: Frame,
+    pub result_output: FloatInput,
+    pub result_unit_selector: Choice,
+}
+impl Solver {
+    pub fn new(ui: &RcUi) -> Self {
+        let containing_group = Group::default().with_label("Solver");
+
+        let target_fov_label_1 = Frame::default().with_label("Target field of view:");
+        let mut target_fov_input = FloatInput::default();
+        let r = Rc::clone(ui);
+        target_fov_input.set_callback(move || Self::update(&r));
+        target_fov_input.set_trigger(CallbackTrigger::Changed);
+        let target_fov_label_2 = Frame::default().with_label("°, in-game");
+        let convention_selector = build_fov_convention_selector(ui);
+
+        let solve_for_label = Frame::default().with_label("Solve for");
+        let solve_for_selector =
+            build_updating_selector(ui, SOLVE_FOR.iter().map(|(label, _)| *label));
+        let result_label = Frame::default().with_label(":");
+        let mut result_output = FloatInput::default();
+        result_output.set_readonly(true);
+        let result_unit_selector =
+            build_unit_selector(&result_output, Some(LengthUnit::Centimeters), Plural, false);
+
+        containing_group.end();
+
+        Self {
+            containing_group,
+            target_fov_label_1,
+            target_fov_input,
+            target_fov_label_2,
+            convention_selector,
+            solve_for_label,
+            solve_for_selector,
+            result_label,
+            result_output,
+            result_unit_selector,
+        }
+    }
+    pub fn apply_layout(&mut self, layout: &SolverLayout, pos: Position) {
+        self.containing_group
+            .set_rect(layout.containing_group.with_added_pos(pos));
+        self.target_fov_label_1
+            .set_rect(layout.target_fov_label_1.with_added_pos(pos));
+        self.target_fov_input
+            .set_rect(layout.target_fov_input.with_added_pos(pos));
+        self.target_fov_label_2
+            .set_rect(layout.target_fov_label_2.with_added_pos(pos));
+        self.convention_selector
+            .set_rect(layout.convention_selector.with_added_pos(pos));
+        self.solve_for_label
+            .set_rect(layout.solve_for_label.with_added_pos(pos));
+        self.solve_for_selector
+            .set_rect(layout.solve_for_selector.with_added_pos(pos));
+        self.result_label
+            .set_rect(layout.result_label.with_added_pos(pos));
+        self.result_output
+            .set_rect(layout.result_output.with_added_pos(pos));
+        self.result_unit_selector
+            .set_rect(layout.result_unit_selector.with_added_pos(pos));
+    }
+    pub fn update(ui: &RcUi) {
+        let mut _u = ui.borrow_mut();
+        let u = _u.as_mut().unwrap();
+        let mp = &mut u.monitor_properties;
+        let so = &mut u.output_tabs.solver;
+        let target_fov = so.target_fov_input.value().parse::<f64>();
+        if let (Ok(monitor_conf), Ok(target_fov)) = (mp.monitor_configuration(), target_fov) {
+            let aspect = monitor_conf.dimensions.aspect();
+            let (_, convention) = FOV_CONVENTIONS[so.convention_selector.value() as usize];
+            let fov = convention.horizontal_fov_for(Angle::new::<degree>(target_fov), aspect);
+            // Solved through the curvature, if there is one, so that the result agrees with the
+            // portal-like FOV
+            let (_, solve_for) = SOLVE_FOR[so.solve_for_selector.value() as usize];
+            let result = match solve_for {
+                SolveFor::Distance => Some(monitor_conf.distance_for_fov(fov)),
+                SolveFor::Width => monitor_conf
+                    .dimensions_for_fov(fov)
+                    .map(|dimensions| dimensions.width_and_height()[0]),
+                SolveFor::Diagonal => monitor_conf
+                    .dimensions_for_fov(fov)
+                    .map(MonitorDimensions::diagonal),
+            };
+            let result_unit = selected_unit(&so.result_unit_selector);
+            so.result_output.set_value(
+                &result
+                    .map(|result| friendly_ftoa(result_unit.value_of(result)))
+                    .unwrap_or_default(),
+            );
+        } else {
+            so.result_output.set_value("");
+        }
+    }
+}
+impl LayoutGen<'_> for Solver {
+    type Layout = SolverLayout;
+    type Arguments = ();
+
+    fn generate_layout(&self, _: Self::Arguments) -> Self::Layout {
+        const NUM_LINES: i32 = 2;
+
+        let height_l1;
+        let mut width_l1 = GROUP_H_PADDING * 2;
+
+        let target_fov_label_1 = Rect(
+            Position(GROUP_H_PADDING, GROUP_V_PADDING),
+            self.target_fov_label_1.measure_label().repack(),
+        );
+        height_l1 = target_fov_label_1.h() + ADDED_HEIGHT;
+        width_l1 += target_fov_label_1.w();
+
+        let target_fov_input = Rect(target_fov_label_1.to_right(5), Size(70, height_l1));
+        width_l1 += target_fov_input.w() + 5;
+
+        let target_fov_label_2 = Rect(
+            target_fov_input.to_right(0),
+            self.target_fov_label_2.measure_label().repack(),
+        );
+        width_l1 += target_fov_label_2.w();
+
+        let convention_selector = Rect(target_fov_label_2.to_right(5), Size(160, height_l1));
+        width_l1 += convention_selector.w() + 5;
+
+        let height_l2;
+        let mut width_l2 = GROUP_H_PADDING * 2;
+
+        let solve_for_label = Rect(
+            target_fov_label_1.to_bottom(LINE_V_PADDING),
+            self.solve_for_label.measure_label().repack(),
+        );
+        height_l2 = solve_for_label.h() + ADDED_HEIGHT;
+        width_l2 += solve_for_label.w();
+
+        let solve_for_selector = Rect(solve_for_label.to_right(5), Size(140, height_l2));
+        width_l2 += solve_for_selector.w() + 5;
+
+        let result_label = Rect(
+            solve_for_selector.to_right(0),
+            self.result_label.measure_label().repack(),
+        );
+        width_l2 += result_label.w();
+
+        let result_output = Rect(result_label.to_right(5), Size(70, height_l2));
+        width_l2 += result_output.w() + 5;
+
+        let result_unit_selector = Rect(result_output.to_right(5), Size(105, height_l2));
+        width_l2 += result_unit_selector.w() + 5;
+
+        let total_width = max(width_l1, width_l2);
+        let total_height =
+            height_l1 + height_l2 + LINE_V_PADDING * (NUM_LINES - 1) + GROUP_V_PADDING * 2;
+        let total_size = Size(total_width, total_height);
+        let containing_group = Rect(Position(0, 0), total_size);
+        SolverLayout {
+            total_size,
+            containing_group,
+            target_fov_label_1,
+            target_fov_input,
+            target_fov_label_2,
+            convention_selector,
+            solve_for_label,
+            solve_for_selector,
+            result_label,
+            result_output,
+            result_unit_selector,
+        }
+    }
+}
+
+make_layout!(pub SolverLayout, has
+    containing_group,
+    target_fov_label_1, target_fov_input, target_fov_label_2, convention_selector,
+    solve_for_label, solve_for_selector,
+    result_label, result_output, result_unit_selector,
+);
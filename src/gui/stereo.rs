@@ -0,0 +1,568 @@
+use crate::{
+    build_unit_selector,
+    layout::{LayoutGen, Position, Rect, Size},
+    util::{friendly_degrees, friendly_ftoa, length_from_input, selected_unit, PosExt, Repack},
+    Number::*,
+    RcUi, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
+};
+use fltk::{
+    frame::Frame,
+    group::Group,
+    input::{FloatInput, Input},
+    menu::Choice,
+    prelude::*,
+};
+use fpvsetup::{Frustum, LengthUnit};
+use std::rc::Rc;
+use uom::si::{angle::degree, f64::Angle};
+
+#[derive(Clone)]
+pub struct Stereo {
+    pub containing_group: Group,
+    pub ipd_label: Frame,
+    pub ipd_input: Input,
+    pub ipd_unit_selector: Choice,
+    pub max_disparity_label_1: Frame,
+    pub max_disparity_input: FloatInput,
+    pub max_disparity_label_2: Frame,
+    pub left_eye_label: Frame,
+    pub left_eye_left_output: FloatInput,
+    pub left_eye_right_label: Frame,
+    pub left_eye_right_output: FloatInput,
+    pub left_eye_shift_label: Frame,
+    pub left_eye_shift_output: FloatInput,
+    pub right_eye_label: Frame,
+    pub right_eye_left_output: FloatInput,
+    pub right_eye_right_label: Frame,
+    pub right_eye_right_output: FloatInput,
+    pub right_eye_shift_label: Frame,
+    pub right_eye_shift_output: FloatInput,
+    pub separation_label: Frame,
+    pub separation_output: FloatInput,
+    pub zero_parallax_label: Frame,
+    pub zero_parallax_output: FloatInput,
+    pub depth_range_label_1: Frame,
+    pub near_output: FloatInput,
+    pub depth_range_label_2: Frame,
+    pub far_output: FloatInput,
+    pub depth_range_unit_selector: Choice,
+    pub depth_label_1: Frame,
+    pub depth_input: Input,
+    pub depth_unit_selector: Choice,
+    pub depth_label_2: Frame,
+    pub disparity_output: FloatInput,
+    pub disparity_warning: Frame,
+}
+impl Stereo {
+    pub fn new(ui: &RcUi) -> Self {
+        let containing_group = Group::default().with_label("Stereo");
+
+        let ipd_label = Frame::default().with_label("Interpupillary distance:");
+        let mut ipd_input = Input::default();
+        ipd_input.set_value("6.3");
+        let r = Rc::clone(ui);
+        ipd_input.set_callback(move || Self::update(&r));
+        ipd_input.set_trigger(CallbackTrigger::Changed);
+        let ipd_unit_selector =
+            build_unit_selector(&ipd_input, Some(LengthUnit::Centimeters), Plural, false);
+        let max_disparity_label_1 = Frame::default().with_label(", comfortable disparity up to");
+        let mut max_disparity_input = FloatInput::default();
+        max_disparity_input.set_value("1");
+        let r = Rc::clone(ui);
+        max_disparity_input.set_callback(move || Self::update(&r));
+        max_disparity_input.set_trigger(CallbackTrigger::Changed);
+        let max_disparity_label_2 = Frame::default().with_label("°");
+
+        let left_eye_label = Frame::default().with_label("Left eye frustum angles, left:");
+        let mut left_eye_left_output = FloatInput::default();
+        left_eye_left_output.set_readonly(true);
+        let left_eye_right_label = Frame::default().with_label(", right:");
+        let mut left_eye_right_output = FloatInput::default();
+        left_eye_right_output.set_readonly(true);
+        let left_eye_shift_label = Frame::default().with_label(", lens shift:");
+        let mut left_eye_shift_output = FloatInput::default();
+        left_eye_shift_output.set_readonly(true);
+
+        let right_eye_label = Frame::default().with_label("Right eye frustum angles, left:");
+        let mut right_eye_left_output = FloatInput::default();
+        right_eye_left_output.set_readonly(true);
+        let right_eye_right_label = Frame::default().with_label(", right:");
+        let mut right_eye_right_output = FloatInput::default();
+        right_eye_right_output.set_readonly(true);
+        let right_eye_shift_label = Frame::default().with_label(", lens shift:");
+        let mut right_eye_shift_output = FloatInput::default();
+        right_eye_shift_output.set_readonly(true);
+
+        let separation_label =
+            Frame::default().with_label("In application units, camera separation:");
+        let mut separation_output = FloatInput::default();
+        separation_output.set_readonly(true);
+        let zero_parallax_label = Frame::default().with_label(", zero parallax distance:");
+        let mut zero_parallax_output = FloatInput::default();
+        zero_parallax_output.set_readonly(true);
+
+        let depth_range_label_1 = Frame::default().with_label("Comfortable depths from");
+        let mut near_output = FloatInput::default();
+        near_output.set_readonly(true);
+        let depth_range_label_2 = Frame::default().with_label("to");
+        let mut far_output = FloatInput::default();
+        far_output.set_readonly(true);
+        let depth_range_unit_selector =
+            build_unit_selector(&near_output, Some(LengthUnit::Centimeters), Plural, false);
+
+        let depth_label_1 = Frame::default().with_label("Disparity at a depth of");
+        let mut depth_input = Input::default();
+        let r = Rc::clone(ui);
+        depth_input.set_callback(move || Self::update(&r));
+        depth_input.set_trigger(CallbackTrigger::Changed);
+        let depth_unit_selector =
+            build_unit_selector(&depth_input, Some(LengthUnit::Centimeters), Plural, false);
+        let depth_label_2 = Frame::default().with_label(":");
+        let mut disparity_output = FloatInput::default();
+        disparity_output.set_readonly(true);
+        let mut disparity_warning = Frame::default().with_label("(too large to be comfortable)");
+        disparity_warning.hide();
+
+        containing_group.end();
+
+        Self {
+            containing_group,
+            ipd_label,
+            ipd_input,
+            ipd_unit_selector,
+            max_disparity_label_1,
+            max_disparity_input,
+            max_disparity_label_2,
+            left_eye_label,
+            left_eye_left_output,
+            left_eye_right_label,
+            left_eye_right_output,
+            left_eye_shift_label,
+            left_eye_shift_output,
+            right_eye_label,
+            right_eye_left_output,
+            right_eye_right_label,
+            right_eye_right_output,
+            right_eye_shift_label,
+            right_eye_shift_output,
+            separation_label,
+            separation_output,
+            zero_parallax_label,
+            zero_parallax_output,
+            depth_range_label_1,
+            near_output,
+            depth_range_label_2,
+            far_output,
+            depth_range_unit_selector,
+            depth_label_1,
+            depth_input,
+            depth_unit_selector,
+            depth_label_2,
+            disparity_output,
+            disparity_warning,
+        }
+    }
+    pub fn apply_layout(&mut self, layout: &StereoLayout, pos: Position) {
+        self.containing_group
+            .set_rect(layout.containing_group.with_added_pos(pos));
+        self.ipd_label
+            .set_rect(layout.ipd_label.with_added_pos(pos));
+        self.ipd_input
+            .set_rect(layout.ipd_input.with_added_pos(pos));
+        self.ipd_unit_selector
+            .set_rect(layout.ipd_unit_selector.with_added_pos(pos));
+        self.max_disparity_label_1
+            .set_rect(layout.max_disparity_label_1.with_added_pos(pos));
+        self.max_disparity_input
+            .set_rect(layout.max_disparity_input.with_added_pos(pos));
+        self.max_disparity_label_2
+            .set_rect(layout.max_disparity_label_2.with_added_pos(pos));
+        self.left_eye_label
+            .set_rect(layout.left_eye_label.with_added_pos(pos));
+        self.left_eye_left_output
+            .set_rect(layout.left_eye_left_output.with_added_pos(pos));
+        self.left_eye_right_label
+            .set_rect(layout.left_eye_right_label.with_added_pos(pos));
+        self.left_eye_right_output
+            .set_rect(layout.left_eye_right_output.with_added_pos(pos));
+        self.left_eye_shift_label
+            .set_rect(layout.left_eye_shift_label.with_added_pos(pos));
+        self.left_eye_shift_output
+            .set_rect(layout.left_eye_shift_output.with_added_pos(pos));
+        self.right_eye_label
+            .set_rect(layout.right_eye_label.with_added_pos(pos));
+        self.right_eye_left_output
+            .set_rect(layout.right_eye_left_output.with_added_pos(pos));
+        self.right_eye_right_label
+            .set_rect(layout.right_eye_right_label.with_added_pos(pos));
+        self.right_eye_right_output
+            .set_rect(layout.right_eye_right_output.with_added_pos(pos));
+        self.right_eye_shift_label
+            .set_rect(layout.right_eye_shift_label.with_added_pos(pos));
+        self.right_eye_shift_output
+            .set_rect(layout.right_eye_shift_output.with_added_pos(pos));
+        self.separation_label
+            .set_rect(layout.separation_label.with_added_pos(pos));
+        self.separation_output
+            .set_rect(layout.separation_output.with_added_pos(pos));
+        self.zero_parallax_label
+            .set_rect(layout.zero_parallax_label.with_added_pos(pos));
+        self.zero_parallax_output
+            .set_rect(layout.zero_parallax_output.with_added_pos(pos));
+        self.depth_range_label_1
+            .set_rect(layout.depth_range_label_1.with_added_pos(pos));
+        self.near_output
+            .set_rect(layout.near_output.with_added_pos(pos));
+        self.depth_range_label_2
+            .set_rect(layout.depth_range_label_2.with_added_pos(pos));
+        self.far_output
+            .set_rect(layout.far_output.with_added_pos(pos));
+        self.depth_range_unit_selector
+            .set_rect(layout.depth_range_unit_selector.with_added_pos(pos));
+        self.depth_label_1
+            .set_rect(layout.depth_label_1.with_added_pos(pos));
+        self.depth_input
+            .set_rect(layout.depth_input.with_added_pos(pos));
+        self.depth_unit_selector
+            .set_rect(layout.depth_unit_selector.with_added_pos(pos));
+        self.depth_label_2
+            .set_rect(layout.depth_label_2.with_added_pos(pos));
+        self.disparity_output
+            .set_rect(layout.disparity_output.with_added_pos(pos));
+        self.disparity_warning
+            .set_rect(layout.disparity_warning.with_added_pos(pos));
+    }
+    pub fn clear(&mut self) {
+        for output in [
+            &mut self.left_eye_left_output,
+            &mut self.left_eye_right_output,
+            &mut self.left_eye_shift_output,
+            &mut self.right_eye_left_output,
+            &mut self.right_eye_right_output,
+            &mut self.right_eye_shift_output,
+            &mut self.separation_output,
+            &mut self.zero_parallax_output,
+            &mut self.near_output,
+            &mut self.far_output,
+            &mut self.disparity_output,
+        ]
+        .iter_mut()
+        {
+            output.set_value("");
+        }
+        self.disparity_warning.hide();
+    }
+    pub fn update(ui: &RcUi) {
+        let mut _u = ui.borrow_mut();
+        let u = _u.as_mut().unwrap();
+        let mp = &mut u.monitor_properties;
+        let us = &mut u.unit_setup;
+        let st = &mut u.output_tabs.stereo;
+        let ipd_unit = selected_unit(&st.ipd_unit_selector);
+        let ipd = length_from_input(&st.ipd_input.value(), ipd_unit);
+        let max_disparity = st.max_disparity_input.value().parse::<f64>();
+        if let (Ok(monitor_conf), Some(ipd), Ok(max_disparity)) =
+            (mp.game_configuration(), ipd, max_disparity)
+        {
+            let max_disparity = Angle::new::<degree>(max_disparity);
+
+            let view = monitor_conf.stereo_view(ipd);
+            let show_frustum = |frustum: Frustum,
+                                left: &mut FloatInput,
+                                right: &mut FloatInput,
+                                shift: &mut FloatInput| {
+                left.set_value(&friendly_degrees(frustum.left));
+                right.set_value(&friendly_degrees(frustum.right));
+                shift.set_value(&friendly_ftoa(frustum.lens_shift()[0]));
+            };
+            show_frustum(
+                view.left,
+                &mut st.left_eye_left_output,
+                &mut st.left_eye_right_output,
+                &mut st.left_eye_shift_output,
+            );
+            show_frustum(
+                view.right,
+                &mut st.right_eye_left_output,
+                &mut st.right_eye_right_output,
+                &mut st.right_eye_shift_output,
+            );
+            let app_units = |length| us.app_units(length).map(friendly_ftoa).unwrap_or_default();
+            st.separation_output.set_value(&app_units(view.separation));
+            st.zero_parallax_output
+                .set_value(&app_units(view.zero_parallax_distance));
+
+            let depth_range_unit = selected_unit(&st.depth_range_unit_selector);
+            let in_unit = |length| friendly_ftoa(depth_range_unit.value_of(length));
+            let [near, far] = monitor_conf.comfortable_depth_range(ipd, max_disparity);
+            st.near_output.set_value(&in_unit(near));
+            st.far_output.set_value(&in_unit(far));
+
+            let depth_unit = selected_unit(&st.depth_unit_selector);
+            if let Some(depth) = length_from_input(&st.depth_input.value(), depth_unit) {
+                st.disparity_output.set_value(&friendly_degrees(
+                    monitor_conf.angular_disparity(ipd, depth),
+                ));
+                if monitor_conf.is_disparity_comfortable(ipd, depth, max_disparity) {
+                    st.disparity_warning.hide();
+                } else {
+                    st.disparity_warning.show();
+                }
+            } else {
+                st.disparity_output.set_value("");
+                st.disparity_warning.hide();
+            }
+        } else {
+            st.clear();
+        }
+    }
+}
+impl LayoutGen<'_> for Stereo {
+    type Layout = StereoLayout;
+    type Arguments = ();
+
+    fn generate_layout(&self, _: Self::Arguments) -> Self::Layout {
+        const NUM_LINES: i32 = 6;
+
+        let height_l1;
+        let mut width_l1 = GROUP_H_PADDING * 2;
+
+        let ipd_label = Rect(
+            Position(GROUP_H_PADDING, GROUP_V_PADDING),
+            self.ipd_label.measure_label().repack(),
+        );
+        height_l1 = ipd_label.h() + ADDED_HEIGHT;
+        width_l1 += ipd_label.w();
+
+        let ipd_input = Rect(ipd_label.to_right(5), Size(70, height_l1));
+        width_l1 += ipd_input.w() + 5;
+
+        let ipd_unit_selector = Rect(ipd_input.to_right(5), Size(105, height_l1));
+        width_l1 += ipd_unit_selector.w() + 5;
+
+        let max_disparity_label_1 = Rect(
+            ipd_unit_selector.to_right(0),
+            self.max_disparity_label_1.measure_label().repack(),
+        );
+        width_l1 += max_disparity_label_1.w();
+
+        let max_disparity_input = Rect(max_disparity_label_1.to_right(5), Size(70, height_l1));
+        width_l1 += max_disparity_input.w() + 5;
+
+        let max_disparity_label_2 = Rect(
+            max_disparity_input.to_right(0),
+            self.max_disparity_label_2.measure_label().repack(),
+        );
+        width_l1 += max_disparity_label_2.w();
+
+        let height_l2;
+        let mut width_l2 = GROUP_H_PADDING * 2;
+
+        let left_eye_label = Rect(
+            ipd_label.to_bottom(LINE_V_PADDING),
+            self.left_eye_label.measure_label().repack(),
+        );
+        height_l2 = left_eye_label.h() + ADDED_HEIGHT;
+        width_l2 += left_eye_label.w();
+
+        let left_eye_left_output = Rect(left_eye_label.to_right(5), Size(70, height_l2));
+        width_l2 += left_eye_left_output.w() + 5;
+
+        let left_eye_right_label = Rect(
+            left_eye_left_output.to_right(0),
+            self.left_eye_right_label.measure_label().repack(),
+        );
+        width_l2 += left_eye_right_label.w();
+
+        let left_eye_right_output = Rect(left_eye_right_label.to_right(5), Size(70, height_l2));
+        width_l2 += left_eye_right_output.w() + 5;
+
+        let left_eye_shift_label = Rect(
+            left_eye_right_output.to_right(0),
+            self.left_eye_shift_label.measure_label().repack(),
+        );
+        width_l2 += left_eye_shift_label.w();
+
+        let left_eye_shift_output = Rect(left_eye_shift_label.to_right(5), Size(70, height_l2));
+        width_l2 += left_eye_shift_output.w() + 5;
+
+        let height_l3;
+        let mut width_l3 = GROUP_H_PADDING * 2;
+
+        let right_eye_label = Rect(
+            left_eye_label.to_bottom(LINE_V_PADDING),
+            self.right_eye_label.measure_label().repack(),
+        );
+        height_l3 = right_eye_label.h() + ADDED_HEIGHT;
+        width_l3 += right_eye_label.w();
+
+        let right_eye_left_output = Rect(right_eye_label.to_right(5), Size(70, height_l3));
+        width_l3 += right_eye_left_output.w() + 5;
+
+        let right_eye_right_label = Rect(
+            right_eye_left_output.to_right(0),
+            self.right_eye_right_label.measure_label().repack(),
+        );
+        width_l3 += right_eye_right_label.w();
+
+        let right_eye_right_output = Rect(right_eye_right_label.to_right(5), Size(70, height_l3));
+        width_l3 += right_eye_right_output.w() + 5;
+
+        let right_eye_shift_label = Rect(
+            right_eye_right_output.to_right(0),
+            self.right_eye_shift_label.measure_label().repack(),
+        );
+        width_l3 += right_eye_shift_label.w();
+
+        let right_eye_shift_output = Rect(right_eye_shift_label.to_right(5), Size(70, height_l3));
+        width_l3 += right_eye_shift_output.w() + 5;
+
+        let height_l4;
+        let mut width_l4 = GROUP_H_PADDING * 2;
+
+        let separation_label = Rect(
+            right_eye_label.to_bottom(LINE_V_PADDING),
+            self.separation_label.measure_label().repack(),
+        );
+        height_l4 = separation_label.h() + ADDED_HEIGHT;
+        width_l4 += separation_label.w();
+
+        let separation_output = Rect(separation_label.to_right(5), Size(70, height_l4));
+        width_l4 += separation_output.w() + 5;
+
+        let zero_parallax_label = Rect(
+            separation_output.to_right(0),
+            self.zero_parallax_label.measure_label().repack(),
+        );
+        width_l4 += zero_parallax_label.w();
+
+        let zero_parallax_output = Rect(zero_parallax_label.to_right(5), Size(70, height_l4));
+        width_l4 += zero_parallax_output.w() + 5;
+
+        let height_l5;
+        let mut width_l5 = GROUP_H_PADDING * 2;
+
+        let depth_range_label_1 = Rect(
+            separation_label.to_bottom(LINE_V_PADDING),
+            self.depth_range_label_1.measure_label().repack(),
+        );
+        height_l5 = depth_range_label_1.h() + ADDED_HEIGHT;
+        width_l5 += depth_range_label_1.w();
+
+        let near_output = Rect(depth_range_label_1.to_right(5), Size(70, height_l5));
+        width_l5 += near_output.w() + 5;
+
+        let depth_range_label_2 = Rect(
+            near_output.to_right(5),
+            self.depth_range_label_2.measure_label().repack(),
+        );
+        width_l5 += depth_range_label_2.w() + 5;
+
+        let far_output = Rect(depth_range_label_2.to_right(5), Size(70, height_l5));
+        width_l5 += far_output.w() + 5;
+
+        let depth_range_unit_selector = Rect(far_output.to_right(5), Size(105, height_l5));
+        width_l5 += depth_range_unit_selector.w() + 5;
+
+        let height_l6;
+        let mut width_l6 = GROUP_H_PADDING * 2;
+
+        let depth_label_1 = Rect(
+            depth_range_label_1.to_bottom(LINE_V_PADDING),
+            self.depth_label_1.measure_label().repack(),
+        );
+        height_l6 = depth_label_1.h() + ADDED_HEIGHT;
+        width_l6 += depth_label_1.w();
+
+        let depth_input = Rect(depth_label_1.to_right(5), Size(70, height_l6));
+        width_l6 += depth_input.w() + 5;
+
+        let depth_unit_selector = Rect(depth_input.to_right(5), Size(105, height_l6));
+        width_l6 += depth_unit_selector.w() + 5;
+
+        let depth_label_2 = Rect(
+            depth_unit_selector.to_right(0),
+            self.depth_label_2.measure_label().repack(),
+        );
+        width_l6 += depth_label_2.w();
+
+        let disparity_output = Rect(depth_label_2.to_right(5), Size(70, height_l6));
+        width_l6 += disparity_output.w() + 5;
+
+        let disparity_warning = Rect(
+            disparity_output.to_right(5),
+            self.disparity_warning.measure_label().repack(),
+        );
+        width_l6 += disparity_warning.w() + 5;
+
+        let total_width = [width_l1, width_l2, width_l3, width_l4, width_l5, width_l6]
+            .iter()
+            .copied()
+            .max()
+            .unwrap();
+        let total_height = height_l1
+            + height_l2
+            + height_l3
+            + height_l4
+            + height_l5
+            + height_l6
+            + LINE_V_PADDING * (NUM_LINES - 1)
+            + GROUP_V_PADDING * 2;
+        let total_size = Size(total_width, total_height);
+        let containing_group = Rect(Position(0, 0), total_size);
+        StereoLayout {
+            total_size,
+            containing_group,
+            ipd_label,
+            ipd_input,
+            ipd_unit_selector,
+            max_disparity_label_1,
+            max_disparity_input,
+            max_disparity_label_2,
+            left_eye_label,
+            left_eye_left_output,
+            left_eye_right_label,
+            left_eye_right_output,
+            left_eye_shift_label,
+            left_eye_shift_output,
+            right_eye_label,
+            right_eye_left_output,
+            right_eye_right_label,
+            right_eye_right_output,
+            right_eye_shift_label,
+            right_eye_shift_output,
+            separation_label,
+            separation_output,
+            zero_parallax_label,
+            zero_parallax_output,
+            depth_range_label_1,
+            near_output,
+            depth_range_label_2,
+            far_output,
+            depth_range_unit_selector,
+            depth_label_1,
+            depth_input,
+            depth_unit_selector,
+            depth_label_2,
+            disparity_output,
+            disparity_warning,
+        }
+    }
+}
+
+make_layout!(pub StereoLayout, has
+    containing_group,
+    ipd_label, ipd_input, ipd_unit_selector,
+    max_disparity_label_1, max_disparity_input, max_disparity_label_2,
+    left_eye_label, left_eye_left_output,
+    left_eye_right_label, left_eye_right_output,
+    left_eye_shift_label, left_eye_shift_output,
+    right_eye_label, right_eye_left_output,
+    right_eye_right_label, right_eye_right_output,
+    right_eye_shift_label, right_eye_shift_output,
+    separation_label, separation_output,
+    zero_parallax_label, zero_parallax_output,
+    depth_range_label_1, near_output, depth_range_label_2, far_output, depth_range_unit_selector,
+    depth_label_1, depth_input, depth_unit_selector,
+    depth_label_2, disparity_output, disparity_warning,
+);
@@ -0,0 +1,574 @@
+use crate::{
+    build_shared_unit_selector,
+    layout::{LayoutGen, Position, Rect, Size},
+    output_tabs::OutputTabs,
+    util::{friendly_degrees, friendly_ftoa, length_from_input, selected_unit, PosExt, Repack},
+    Number::*,
+    RcUi, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
+};
+use fltk::{
+    frame::Frame,
+    group::Group,
+    input::{FloatInput, Input, IntInput},
+    menu::Choice,
+    output::MultilineOutput,
+    prelude::*,
+};
+use fpvsetup::{LengthUnit, MonitorDimensions, SurroundRig, SurroundScreen};
+use std::{cmp::max, fmt::Write, rc::Rc};
+use uom::si::{angle::degree, f64::Angle};
+
+/// How many lines of the per-screen output are visible without scrolling.
+const SCREENS_OUTPUT_LINES: i32 = 5;
+/// The largest number of screens on each side of the center screen which the tab has rows for.
+const MAX_SIDE_SCREENS: usize = 3;
+/// The number of screen rows, which is also the index of the row after the last one.
+const SCREEN_ROWS: usize = MAX_SIDE_SCREENS * 2 + 1;
+/// The width of the inputs in the screen rows.
+const SCREEN_INPUT_WIDTH: i32 = 70;
+
+/// The inputs for a single screen of the rig. The rows are ordered from left to right, with the center screen in the middle.
+#[derive(Clone)]
+pub struct SurroundScreenRow {
+    pub label: Frame,
+    pub width_input: Input,
+    pub height_input: Input,
+    pub bezel_input: Input,
+    pub yaw_input: FloatInput,
+}
+impl SurroundScreenRow {
+    fn new(ui: &RcUi, index: usize) -> Self {
+        let label = Frame::default().with_label(&row_label(index));
+        let mut width_input = Input::default();
+        let mut height_input = Input::default();
+        let mut bezel_input = Input::default();
+        let mut yaw_input = FloatInput::default();
+        for input in [&mut width_input, &mut height_input, &mut bezel_input].iter_mut() {
+            let r = Rc::clone(ui);
+            input.set_callback(move || Surround::update(&r));
+            input.set_trigger(CallbackTrigger::Changed);
+        }
+        let r = Rc::clone(ui);
+        yaw_input.set_callback(move || Surround::update(&r));
+        yaw_input.set_trigger(CallbackTrigger::Changed);
+        if index == MAX_SIDE_SCREENS {
+            // The angles of the other screens are relative to the center one
+            yaw_input.set_value("0");
+        }
+        Self {
+            label,
+            width_input,
+            height_input,
+            bezel_input,
+            yaw_input,
+        }
+    }
+    fn apply_layout(&mut self, layout: &SurroundScreenRowLayout, pos: Position) {
+        self.label.set_rect(layout.label.with_added_pos(pos));
+        self.width_input
+            .set_rect(layout.width_input.with_added_pos(pos));
+        self.height_input
+            .set_rect(layout.height_input.with_added_pos(pos));
+        self.bezel_input
+            .set_rect(layout.bezel_input.with_added_pos(pos));
+        self.yaw_input
+            .set_rect(layout.yaw_input.with_added_pos(pos));
+    }
+    /// Greys out the row if the screen isn't part of the rig. The size and angle inputs of the center screen always stay inactive.
+    fn set_used(&mut self, used: bool, center: bool) {
+        set_active(&mut self.label, used);
+        set_active(&mut self.bezel_input, used);
+        set_active(&mut self.width_input, used && !center);
+        set_active(&mut self.height_input, used && !center);
+        set_active(&mut self.yaw_input, used && !center);
+    }
+}
+
+#[derive(Clone)]
+pub struct Surround {
+    pub containing_group: Group,
+    pub left_count_label: Frame,
+    pub left_count_input: IntInput,
+    pub right_count_label: Frame,
+    pub right_count_input: IntInput,
+    pub unit_label: Frame,
+    pub unit_selector: Choice,
+    pub width_header: Frame,
+    pub height_header: Frame,
+    pub bezel_header: Frame,
+    pub yaw_header: Frame,
+    pub screen_rows: Vec<SurroundScreenRow>,
+    pub blank_hint_label: Frame,
+    pub total_fov_label: Frame,
+    pub total_fov_output: FloatInput,
+    pub screens_output: MultilineOutput,
+}
+impl Surround {
+    pub fn new(ui: &RcUi) -> Self {
+        let containing_group = Group::default().with_label("Surround");
+
+        let left_count_label = Frame::default().with_label(&format!(
+            "Screens to the left (up to {}):",
+            MAX_SIDE_SCREENS
+        ));
+        let mut left_count_input = IntInput::default();
+        left_count_input.set_value("1");
+        let r = Rc::clone(ui);
+        // The status below the tabs reports screen counts over the limit
+        left_count_input.set_callback(move || OutputTabs::update(&r));
+        left_count_input.set_trigger(CallbackTrigger::Changed);
+        let right_count_label = Frame::default().with_label(", to the right:");
+        let mut right_count_input = IntInput::default();
+        right_count_input.set_value("1");
+        let r = Rc::clone(ui);
+        right_count_input.set_callback(move || OutputTabs::update(&r));
+        right_count_input.set_trigger(CallbackTrigger::Changed);
+
+        let width_header = Frame::default().with_label("Width");
+        let height_header = Frame::default().with_label("Height");
+        let bezel_header = Frame::default().with_label("Bezel");
+        let yaw_header = Frame::default().with_label("Angle (°)");
+        let screen_rows = (0..SCREEN_ROWS)
+            .map(|index| SurroundScreenRow::new(ui, index))
+            .collect::<Vec<_>>();
+
+        let unit_label = Frame::default().with_label(", lengths in");
+        let length_inputs = screen_rows
+            .iter()
+            .flat_map(|row| {
+                vec![
+                    row.width_input.clone(),
+                    row.height_input.clone(),
+                    row.bezel_input.clone(),
+                ]
+            })
+            .collect();
+        let unit_selector =
+            build_shared_unit_selector(length_inputs, Some(LengthUnit::Centimeters), Plural, false);
+
+        let blank_hint_label = Frame::default().with_label(
+            "Blank sizes are those of the center screen, blank angles turn the screen to face the eye",
+        );
+
+        let total_fov_label = Frame::default().with_label("Total horizontal field of view:");
+        let mut total_fov_output = FloatInput::default();
+        total_fov_output.set_readonly(true);
+
+        let screens_output = MultilineOutput::default();
+
+        containing_group.end();
+
+        let mut surround = Self {
+            containing_group,
+            left_count_label,
+            left_count_input,
+            right_count_label,
+            right_count_input,
+            unit_label,
+            unit_selector,
+            width_header,
+            height_header,
+            bezel_header,
+            yaw_header,
+            screen_rows,
+            blank_hint_label,
+            total_fov_label,
+            total_fov_output,
+            screens_output,
+        };
+        surround.update_rows();
+        surround
+    }
+    pub fn apply_layout(&mut self, layout: &SurroundLayout, pos: Position) {
+        self.containing_group
+            .set_rect(layout.containing_group.with_added_pos(pos));
+        self.left_count_label
+            .set_rect(layout.left_count_label.with_added_pos(pos));
+        self.left_count_input
+            .set_rect(layout.left_count_input.with_added_pos(pos));
+        self.right_count_label
+            .set_rect(layout.right_count_label.with_added_pos(pos));
+        self.right_count_input
+            .set_rect(layout.right_count_input.with_added_pos(pos));
+        self.unit_label
+            .set_rect(layout.unit_label.with_added_pos(pos));
+        self.unit_selector
+            .set_rect(layout.unit_selector.with_added_pos(pos));
+        self.width_header
+            .set_rect(layout.width_header.with_added_pos(pos));
+        self.height_header
+            .set_rect(layout.height_header.with_added_pos(pos));
+        self.bezel_header
+            .set_rect(layout.bezel_header.with_added_pos(pos));
+        self.yaw_header
+            .set_rect(layout.yaw_header.with_added_pos(pos));
+        for (row, row_layout) in self.screen_rows.iter_mut().zip(&layout.screen_rows) {
+            row.apply_layout(row_layout, pos);
+        }
+        self.blank_hint_label
+            .set_rect(layout.blank_hint_label.with_added_pos(pos));
+        self.total_fov_label
+            .set_rect(layout.total_fov_label.with_added_pos(pos));
+        self.total_fov_output
+            .set_rect(layout.total_fov_output.with_added_pos(pos));
+        self.screens_output
+            .set_rect(layout.screens_output.with_added_pos(pos));
+    }
+    pub fn clear(&mut self) {
+        self.total_fov_output.set_value("");
+        self.screens_output.set_value("");
+    }
+    /// Returns the number of screens to the left and to the right of the center screen, or `None` if either input is empty, invalid or over the limit.
+    fn side_counts(&self) -> Option<[usize; 2]> {
+        let count = |input: &IntInput| {
+            let count = input.value().parse::<usize>().ok()?;
+            if count <= MAX_SIDE_SCREENS {
+                Some(count)
+            } else {
+                None
+            }
+        };
+        Some([
+            count(&self.left_count_input)?,
+            count(&self.right_count_input)?,
+        ])
+    }
+    /// Returns why a screen count is rejected if it's over the limit, to be shown in the status below the tabs.
+    pub fn side_count_error(&self) -> Option<String> {
+        let over_limit = [&self.left_count_input, &self.right_count_input]
+            .iter()
+            .any(|input| matches!(input.value().parse::<usize>(), Ok(count) if count > MAX_SIDE_SCREENS));
+        if over_limit {
+            Some(format!(
+                "The Surround tab supports up to {} screens on each side",
+                MAX_SIDE_SCREENS
+            ))
+        } else {
+            None
+        }
+    }
+    /// Greys out the rows of the screens which aren't part of the rig. The size and angle of the center screen can't be changed here, since they come from the monitor properties and the angles of the other screens are relative to it.
+    fn update_rows(&mut self) {
+        let side_counts = self.side_counts();
+        let [left_count, right_count] = side_counts.unwrap_or([0, 0]);
+        for (index, row) in self.screen_rows.iter_mut().enumerate() {
+            let used = side_counts.is_some()
+                && index + left_count >= MAX_SIDE_SCREENS
+                && index <= MAX_SIDE_SCREENS + right_count;
+            row.set_used(used, index == MAX_SIDE_SCREENS);
+        }
+    }
+    pub fn update(ui: &RcUi) {
+        let mut _u = ui.borrow_mut();
+        let u = _u.as_mut().unwrap();
+        let mp = &mut u.monitor_properties;
+        let su = &mut u.output_tabs.surround;
+        su.update_rows();
+        if let (Ok(monitor_conf), Some([left_count, right_count])) =
+            (mp.monitor_configuration(), su.side_counts())
+        {
+            let unit = selected_unit(&su.unit_selector);
+            let length = |input: &Input| length_from_input(&input.value(), unit);
+            let [center_width, center_height] = monitor_conf.dimensions.width_and_height();
+            let center_row = &mut su.screen_rows[MAX_SIDE_SCREENS];
+            center_row
+                .width_input
+                .set_value(&friendly_ftoa(unit.value_of(center_width)));
+            center_row
+                .height_input
+                .set_value(&friendly_ftoa(unit.value_of(center_height)));
+
+            let rows =
+                &su.screen_rows[MAX_SIDE_SCREENS - left_count..=MAX_SIDE_SCREENS + right_count];
+            let mut screens = rows
+                .iter()
+                .map(|row| SurroundScreen {
+                    dimensions: MonitorDimensions::WidthAndHeight {
+                        width: length(&row.width_input).unwrap_or(center_width),
+                        height: length(&row.height_input).unwrap_or(center_height),
+                    },
+                    yaw: Angle::default(),
+                    bezel: length(&row.bezel_input).unwrap_or_default(),
+                })
+                .collect::<Vec<_>>();
+            let center = left_count;
+            screens[center].dimensions = monitor_conf.dimensions;
+            // Turn the screens from the center outwards, since the optimal angle for each one
+            // depends on the angles of those closer to the center
+            let mut optimal_yaws = vec![Angle::default(); screens.len()];
+            let side_indices = (1..=max(left_count, right_count)).flat_map(|i| {
+                let left = center.checked_sub(i);
+                let right = Some(center + i).filter(|&index| index <= center + right_count);
+                left.into_iter().chain(right)
+            });
+            for index in side_indices {
+                let optimal_yaw = SurroundRig {
+                    screens: &screens,
+                    center,
+                    distance: monitor_conf.distance,
+                }
+                .optimal_yaw(index);
+                optimal_yaws[index] = optimal_yaw;
+                screens[index].yaw = match rows[index].yaw_input.value().parse::<f64>() {
+                    Ok(yaw) => Angle::new::<degree>(yaw),
+                    Err(_) => optimal_yaw,
+                };
+            }
+            let rig = SurroundRig {
+                screens: &screens,
+                center,
+                distance: monitor_conf.distance,
+            };
+
+            su.total_fov_output
+                .set_value(&friendly_degrees(rig.total_fov()));
+            let mut screens_text = String::new();
+            for (i, view) in rig.screen_views().enumerate() {
+                let [lens_shift_x, _] = view.configuration.frustum().lens_shift();
+                let _ = write!(
+                    screens_text,
+                    "{} field of view {}, camera yaw {}, lens shift {}",
+                    row_label(MAX_SIDE_SCREENS - left_count + i),
+                    friendly_degrees(view.fov()),
+                    friendly_degrees(view.camera_yaw),
+                    friendly_ftoa(lens_shift_x),
+                );
+                if i != center {
+                    let _ = write!(
+                        screens_text,
+                        ", optimal angle {}",
+                        friendly_degrees(optimal_yaws[i])
+                    );
+                }
+                screens_text.push('\n');
+            }
+            su.screens_output.set_value(screens_text.trim_end());
+        } else {
+            su.clear();
+        }
+    }
+}
+impl LayoutGen<'_> for Surround {
+    type Layout = SurroundLayout;
+    type Arguments = ();
+
+    fn generate_layout(&self, _: Self::Arguments) -> Self::Layout {
+        const NUM_LINES: i32 = 5 + SCREEN_ROWS as i32;
+
+        let height_l1;
+        let mut width_l1 = GROUP_H_PADDING * 2;
+
+        let left_count_label = Rect(
+            Position(GROUP_H_PADDING, GROUP_V_PADDING),
+            self.left_count_label.measure_label().repack(),
+        );
+        height_l1 = left_count_label.h() + ADDED_HEIGHT;
+        width_l1 += left_count_label.w();
+
+        let left_count_input = Rect(left_count_label.to_right(5), Size(40, height_l1));
+        width_l1 += left_count_input.w() + 5;
+
+        let right_count_label = Rect(
+            left_count_input.to_right(0),
+            self.right_count_label.measure_label().repack(),
+        );
+        width_l1 += right_count_label.w();
+
+        let right_count_input = Rect(right_count_label.to_right(5), Size(40, height_l1));
+        width_l1 += right_count_input.w() + 5;
+
+        let unit_label = Rect(
+            right_count_input.to_right(0),
+            self.unit_label.measure_label().repack(),
+        );
+        width_l1 += unit_label.w();
+
+        let unit_selector = Rect(unit_label.to_right(5), Size(105, height_l1));
+        width_l1 += unit_selector.w() + 5;
+
+        // The screen rows form a table, with the labels of the rows in the first column and the
+        // inputs under the column headers
+        let row_labels_width = self
+            .screen_rows
+            .iter()
+            .map(|row| row.label.measure_label().0)
+            .max()
+            .unwrap();
+        let column_width = |header: &Frame| max(header.measure_label().0, SCREEN_INPUT_WIDTH);
+        let column_widths = [
+            column_width(&self.width_header),
+            column_width(&self.height_header),
+            column_width(&self.bezel_header),
+            column_width(&self.yaw_header),
+        ];
+        let mut column_xs = [0; 4];
+        let mut x = GROUP_H_PADDING + row_labels_width + 5;
+        for (column_x, column_width) in column_xs.iter_mut().zip(&column_widths) {
+            *column_x = x;
+            x += column_width + 5;
+        }
+        let width_table = x - 5 + GROUP_H_PADDING;
+
+        let height_header_line = self.width_header.measure_label().1;
+        let header_y = left_count_label.to_bottom(LINE_V_PADDING).y();
+        let header = |column: usize| {
+            Rect(
+                Position(column_xs[column], header_y),
+                Size(column_widths[column], height_header_line),
+            )
+        };
+        let width_header = header(0);
+        let height_header = header(1);
+        let bezel_header = header(2);
+        let yaw_header = header(3);
+
+        let mut screen_rows = [SurroundScreenRowLayout::default(); SCREEN_ROWS];
+        let mut row_y = width_header.to_bottom(LINE_V_PADDING).y();
+        let mut height_rows = 0;
+        for (row, row_layout) in self.screen_rows.iter().zip(screen_rows.iter_mut()) {
+            let label = Rect(
+                Position(GROUP_H_PADDING, row_y),
+                row.label.measure_label().repack(),
+            );
+            let height = label.h() + ADDED_HEIGHT;
+            let input = |column: usize| {
+                Rect(
+                    Position(column_xs[column], row_y),
+                    Size(column_widths[column], height),
+                )
+            };
+            *row_layout = SurroundScreenRowLayout {
+                total_size: Size(width_table, height),
+                label,
+                width_input: input(0),
+                height_input: input(1),
+                bezel_input: input(2),
+                yaw_input: input(3),
+            };
+            row_y += height + LINE_V_PADDING;
+            height_rows += height;
+        }
+
+        let height_l10;
+        let mut width_l10 = GROUP_H_PADDING * 2;
+
+        let blank_hint_label = Rect(
+            Position(GROUP_H_PADDING, row_y),
+            self.blank_hint_label.measure_label().repack(),
+        );
+        height_l10 = blank_hint_label.h();
+        width_l10 += blank_hint_label.w();
+
+        let height_l11;
+        let mut width_l11 = GROUP_H_PADDING * 2;
+
+        let total_fov_label = Rect(
+            blank_hint_label.to_bottom(LINE_V_PADDING),
+            self.total_fov_label.measure_label().repack(),
+        );
+        height_l11 = total_fov_label.h() + ADDED_HEIGHT;
+        width_l11 += total_fov_label.w();
+
+        let total_fov_output = Rect(total_fov_label.to_right(5), Size(70, height_l11));
+        width_l11 += total_fov_output.w() + 5;
+
+        let width_all = [width_l1, width_table, width_l10, width_l11]
+            .iter()
+            .copied()
+            .max()
+            .unwrap();
+        let height_l12 = height_l11 * SCREENS_OUTPUT_LINES;
+        let screens_output = Rect(
+            total_fov_label.to_bottom(LINE_V_PADDING),
+            Size(width_all - GROUP_H_PADDING * 2, height_l12),
+        );
+
+        let total_width = width_all;
+        let total_height = height_l1
+            + height_header_line
+            + height_rows
+            + height_l10
+            + height_l11
+            + height_l12
+            + LINE_V_PADDING * (NUM_LINES - 1)
+            + GROUP_V_PADDING * 2;
+        let total_size = Size(total_width, total_height);
+        let containing_group = Rect(Position(0, 0), total_size);
+        SurroundLayout {
+            total_size,
+            containing_group,
+            left_count_label,
+            left_count_input,
+            right_count_label,
+            right_count_input,
+            unit_label,
+            unit_selector,
+            width_header,
+            height_header,
+            bezel_header,
+            yaw_header,
+            screen_rows,
+            blank_hint_label,
+            total_fov_label,
+            total_fov_output,
+            screens_output,
+        }
+    }
+}
+
+fn set_active(widget: &mut impl WidgetExt, active: bool) {
+    if active {
+        widget.activate();
+    } else {
+        widget.deactivate();
+    }
+}
+/// Returns the label of a screen row, which is also used to name the screen in the output.
+fn row_label(index: usize) -> String {
+    if index < MAX_SIDE_SCREENS {
+        format!("Left screen {}:", MAX_SIDE_SCREENS - index)
+    } else if index > MAX_SIDE_SCREENS {
+        format!("Right screen {}:", index - MAX_SIDE_SCREENS)
+    } else {
+        "Center screen:".to_string()
+    }
+}
+
+make_layout!(pub SurroundScreenRowLayout, has
+    label, width_input, height_input, bezel_input, yaw_input,
+);
+impl Default for SurroundScreenRowLayout {
+    fn default() -> Self {
+        let empty = Rect(Position(0, 0), Size(0, 0));
+        Self {
+            total_size: Size(0, 0),
+            label: empty,
+            width_input: empty,
+            height_input: empty,
+            bezel_input: empty,
+            yaw_input: empty,
+        }
+    }
+}
+
+/// Laid out like the layouts made by `make_layout!`, with an extra layout for each screen row.
+#[derive(Copy, Clone, Debug)]
+pub struct SurroundLayout {
+    pub total_size: Size,
+    pub containing_group: Rect,
+    pub left_count_label: Rect,
+    pub left_count_input: Rect,
+    pub right_count_label: Rect,
+    pub right_count_input: Rect,
+    pub unit_label: Rect,
+    pub unit_selector: Rect,
+    pub width_header: Rect,
+    pub height_header: Rect,
+    pub bezel_header: Rect,
+    pub yaw_header: Rect,
+    pub screen_rows: [SurroundScreenRowLayout; SCREEN_ROWS],
+    pub blank_hint_label: Rect,
+    pub total_fov_label: Rect,
+    pub total_fov_output: Rect,
+    pub screens_output: Rect,
+}
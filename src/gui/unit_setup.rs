@@ -2,14 +2,19 @@ use crate::{
     build_unit_selector,
     layout::{LayoutGen, Position, Rect, Size},
     output_tabs::OutputTabs,
-    util::{convert_units, friendly_ftoa, length_from_unit, PosExt, Repack, Unit},
+    util::{friendly_ftoa, length_from_input, selected_unit, PosExt, Repack},
     Number::*,
     RcUi, ADDED_HEIGHT, GROUP_H_PADDING, GROUP_V_PADDING, LINE_V_PADDING,
 };
 use fltk::{
-    frame::Frame, input::FloatInput, menu::Choice, CallbackTrigger, InputExt, MenuExt, WidgetExt,
+    frame::Frame,
+    input::{FloatInput, Input},
+    menu::Choice,
+    CallbackTrigger, InputExt, WidgetExt,
 };
-use std::{cmp::max, convert::TryInto, rc::Rc};
+use fpvsetup::LengthUnit;
+use std::{cmp::max, rc::Rc};
+use uom::si::f64::Length;
 
 #[derive(Clone)]
 pub struct UnitSetup {
@@ -20,7 +25,7 @@ pub struct UnitSetup {
     pub real_per_app_selector_label: Frame,
     pub real_per_app_unit_selector: Choice,
     pub real_per_app_input_label: Frame,
-    pub real_per_app_input: FloatInput,
+    pub real_per_app_input: Input,
 }
 
 impl UnitSetup {
@@ -31,18 +36,22 @@ impl UnitSetup {
         let r = Rc::clone(ui);
         app_per_real_input.set_callback(move || Self::app_per_real_change_handler(&r));
         app_per_real_input.set_trigger(CallbackTrigger::Changed);
-        let app_per_real_unit_selector =
-            build_unit_selector(&app_per_real_input, Some(Unit::Meters), Singular, true);
+        let app_per_real_unit_selector = build_unit_selector(
+            &app_per_real_input,
+            Some(LengthUnit::Meters),
+            Singular,
+            true,
+        );
 
         let real_per_app_selector_label =
             Frame::default().with_label("Length of one application unit in");
         let real_per_app_input_label = Frame::default().with_label(":");
-        let mut real_per_app_input = FloatInput::default();
+        let mut real_per_app_input = Input::default();
         let r = Rc::clone(ui);
         real_per_app_input.set_callback(move || Self::real_per_app_change_handler(&r));
         real_per_app_input.set_trigger(CallbackTrigger::Changed);
         let real_per_app_unit_selector =
-            build_unit_selector(&real_per_app_input, Some(Unit::Meters), Plural, false);
+            build_unit_selector(&real_per_app_input, Some(LengthUnit::Meters), Plural, false);
         Self {
             app_per_real_selector_label,
             app_per_real_unit_selector,
@@ -72,14 +81,20 @@ impl UnitSetup {
         self.real_per_app_input
             .set_rect(layout.real_per_app_input.with_added_pos(pos));
     }
+    /// Converts a real-world length to application units, or returns `None` if the scale of application units isn't set.
+    pub fn app_units(&self, length: Length) -> Option<f64> {
+        let app_per_real = self.app_per_real_input.value().parse::<f64>().ok()?;
+        let app_per_real_unit = selected_unit(&self.app_per_real_unit_selector);
+        Some(app_per_real_unit.value_of(length) * app_per_real)
+    }
     fn app_per_real_change_handler(ui: &RcUi) {
         let mut _p = ui.borrow_mut();
         let p = &mut _p.as_mut().unwrap().unit_setup;
         if let Ok(app_per_real) = p.app_per_real_input.value().parse::<f64>() {
-            let app_per_real_unit = p.app_per_real_unit_selector.value().try_into().unwrap();
-            let real_per_app_unit = p.real_per_app_unit_selector.value().try_into().unwrap();
-            let app_per_real = length_from_unit(app_per_real, app_per_real_unit);
-            let real_per_app = 1.0 / convert_units(app_per_real, real_per_app_unit);
+            let app_per_real_unit = selected_unit(&p.app_per_real_unit_selector);
+            let real_per_app_unit = selected_unit(&p.real_per_app_unit_selector);
+            let app_per_real = app_per_real_unit.length(app_per_real);
+            let real_per_app = 1.0 / real_per_app_unit.value_of(app_per_real);
             p.real_per_app_input.set_value(&friendly_ftoa(real_per_app));
         }
         drop(_p);
@@ -88,11 +103,12 @@ impl UnitSetup {
     fn real_per_app_change_handler(ui: &RcUi) {
         let mut _p = ui.borrow_mut();
         let p = &mut _p.as_mut().unwrap().unit_setup;
-        if let Ok(real_per_app) = p.real_per_app_input.value().parse::<f64>() {
-            let real_per_app_unit = p.real_per_app_unit_selector.value().try_into().unwrap();
-            let app_per_real_unit = p.app_per_real_unit_selector.value().try_into().unwrap();
-            let real_per_app = length_from_unit(real_per_app, real_per_app_unit);
-            let app_per_real = 1.0 / convert_units(real_per_app, app_per_real_unit);
+        let real_per_app_unit = selected_unit(&p.real_per_app_unit_selector);
+        if let Some(real_per_app) =
+            length_from_input(&p.real_per_app_input.value(), real_per_app_unit)
+        {
+            let app_per_real_unit = selected_unit(&p.app_per_real_unit_selector);
+            let app_per_real = 1.0 / app_per_real_unit.value_of(real_per_app);
             p.app_per_real_input.set_value(&friendly_ftoa(app_per_real));
         }
         drop(_p);
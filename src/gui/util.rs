@@ -1,26 +1,89 @@
 use crate::layout::{Position, Rect, Size};
-use fltk::prelude::WidgetExt;
-use std::{borrow::Cow, convert::TryFrom, num::FpCategory};
-use uom::{
-    si::{
-        f64::Length,
-        length::{centimeter, foot, inch, meter},
-    },
-    Conversion,
+use fltk::{
+    menu::Choice,
+    prelude::{MenuExt, WidgetExt},
+};
+use fpvsetup::{
+    parse_length, AspectRatio, CurvedWidthMeasurement, FisheyeMapping, FovConvention, FovInterval,
+    LengthUnit, ProjectionModel, SensorSize,
+};
+use std::{borrow::Cow, num::FpCategory};
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length},
 };
 
 pub static DEGREE_SIGN: &str = "°";
 pub static NAN_FTOA: &str = "<error>";
 pub static INFINITY_FTOA: &str = "∞";
 
-/// Converts a string which can only either be empty or parsable to a float into an `Option<f64>`.
+/// The FOV conventions selectable in the output tabs, along with their labels.
+pub static FOV_CONVENTIONS: &[(&str, FovConvention)] = &[
+    ("horizontal (Vert-)", FovConvention::Horizontal),
+    ("vertical (Hor+)", FovConvention::Vertical),
+    ("4:3 horizontal (Hor+)", FovConvention::HOR_PLUS_4_3),
+    ("diagonal", FovConvention::Diagonal),
+];
+
+/// The length units selectable next to length inputs and outputs, along with their labels in singular and plural.
+pub static LENGTH_UNITS: &[(&str, &str, LengthUnit)] = &[
+    ("millimeter", "millimeters", LengthUnit::Millimeters),
+    ("centimeter", "centimeters", LengthUnit::Centimeters),
+    ("meter", "meters", LengthUnit::Meters),
+    ("inch", "inches", LengthUnit::Inches),
+    ("foot", "feet", LengthUnit::Feet),
+    ("yard", "yards", LengthUnit::Yards),
+];
+
+/// The sensor sizes selectable in the output tabs, along with their labels.
+pub static SENSOR_SIZES: &[(&str, SensorSize)] = &[
+    ("full-frame", SensorSize::FullFrame),
+    ("Super 35", SensorSize::Super35),
+    ("APS-C", SensorSize::ApsC),
+];
+
+/// The ways to measure the width of a curved monitor selectable in the monitor properties, along with their labels.
+pub static CURVED_WIDTH_MEASUREMENTS: &[(&str, CurvedWidthMeasurement)] = &[
+    ("along the curve", CurvedWidthMeasurement::Arc),
+    ("straight across", CurvedWidthMeasurement::Chord),
+];
+
+/// The projection models selectable in the projections tab, along with their labels. The Panini distance is replaced with the one from the input.
+pub static PROJECTION_MODELS: &[(&str, ProjectionModel)] = &[
+    ("rectilinear", ProjectionModel::Rectilinear),
+    ("cylindrical", ProjectionModel::Cylindrical),
+    ("Panini", ProjectionModel::Panini { distance: 1.0 }),
+    (
+        "fisheye (equidistant)",
+        ProjectionModel::Fisheye(FisheyeMapping::Equidistant),
+    ),
+    (
+        "fisheye (equisolid)",
+        ProjectionModel::Fisheye(FisheyeMapping::Equisolid),
+    ),
+    (
+        "fisheye (stereographic)",
+        ProjectionModel::Fisheye(FisheyeMapping::Stereographic),
+    ),
+];
+
+/// The visual acuities selectable in the pixels tab as Snellen fractions, along with their labels.
+pub static VISUAL_ACUITIES: &[(&str, [f64; 2])] = &[
+    ("20/10 (0.5′)", [20.0, 10.0]),
+    ("20/15 (0.75′)", [20.0, 15.0]),
+    ("20/20 (1′, normal)", [20.0, 20.0]),
+    ("20/30 (1.5′)", [20.0, 30.0]),
+    ("20/40 (2′)", [20.0, 40.0]),
+];
+/// The index of the normal visual acuity in `VISUAL_ACUITIES`, which is selected by default.
+pub const NORMAL_VISUAL_ACUITY: i32 = 2;
+
+/// By how much of the curvature radius the eye can be off the center of the curvature before a warning is shown.
+pub const CURVATURE_CENTER_TOLERANCE: f64 = 0.25;
+
+/// Converts a string into an `Option<f64>`, which is `None` if the string is empty or not a number, such as the `-` or `.` which a float input accepts while the number is being typed.
 pub fn float_from_restricted_string(src: &str) -> Option<f64> {
-    let src = src.trim();
-    if src.is_empty() {
-        None
-    } else {
-        Some(src.parse().unwrap())
-    }
+    src.trim().parse().ok()
 }
 
 /// Converts a float to a string in a friendly representation.
@@ -34,6 +97,24 @@ pub fn friendly_ftoa(val: f64) -> Cow<'static, str> {
         _ => Cow::Owned(friendly_ftoa_base(val)),
     }
 }
+/// Converts an angle to a string in degrees, in the same friendly representation as `friendly_ftoa`.
+pub fn friendly_degrees(val: Angle) -> String {
+    format!("{}{}", friendly_ftoa(val.get::<degree>()), DEGREE_SIGN)
+}
+/// Converts an FOV to a string like `friendly_degrees`, unless the inputs it was calculated from have tolerances, in which case the interval of the possible values is displayed instead.
+pub fn friendly_fov_interval(fov: Angle, interval: FovInterval) -> String {
+    if interval.tolerance() > Angle::default() {
+        interval.to_string()
+    } else {
+        friendly_degrees(fov)
+    }
+}
+pub fn friendly_aspect(val: AspectRatio) -> String {
+    match val.marketing_name() {
+        Some(name) => name.to_string(),
+        None => val.to_string(),
+    }
+}
 fn friendly_ftoa_base(val: f64) -> String {
     let mut formatted = format!("{:.3}", val);
     let mut chars_to_pop = 0;
@@ -51,60 +132,22 @@ fn friendly_ftoa_base(val: f64) -> String {
     formatted
 }
 
-pub fn length_from_unit(val: f64, unit: Unit) -> Length {
-    match unit {
-        Unit::Meters => Length::new::<meter>(val),
-        Unit::Centimeters => Length::new::<centimeter>(val),
-        Unit::Feet => Length::new::<foot>(val),
-        Unit::Inches => Length::new::<inch>(val),
-    }
+/// Reads a length from an input which accepts values with a unit suffix, such as `27in` or `5' 3"`, using the given unit for plain numbers. Returns `None` if the input is empty or invalid.
+pub fn length_from_input(src: &str, unit: LengthUnit) -> Option<Length> {
+    parse_length(src, Some(unit)).ok()
 }
 
-pub fn convert_units(val: Length, b: Unit) -> f64 {
-    match b {
-        Unit::Meters => val.get::<meter>(),
-        Unit::Centimeters => val.get::<centimeter>(),
-        Unit::Feet => val.get::<foot>(),
-        Unit::Inches => val.get::<inch>(),
-    }
+/// Returns the unit selected in a selector built by `build_unit_selector`.
+pub fn selected_unit(selector: &Choice) -> LengthUnit {
+    let (_, _, unit) = LENGTH_UNITS[selector.value() as usize];
+    unit
 }
-
-/// By what number a value of unit A needs to multiplied to yield unit B.
-pub fn conversion_rate(a: Unit, b: Unit) -> f64 {
-    let u2f = |unit| match unit {
-        Unit::Meters => <meter as Conversion<f64>>::coefficient(),
-        Unit::Centimeters => <centimeter as Conversion<f64>>::coefficient(),
-        Unit::Feet => <foot as Conversion<f64>>::coefficient(),
-        Unit::Inches => <inch as Conversion<f64>>::coefficient(),
-    };
-    let (a, b) = (u2f(a), u2f(b));
-    b / a
-}
-
-#[repr(i32)]
-pub enum Unit {
-    Meters = 0,
-    Centimeters = 1,
-    Feet = 2,
-    Inches = 3,
-}
-impl From<Unit> for i32 {
-    fn from(val: Unit) -> Self {
-        val as i32
-    }
-}
-impl TryFrom<i32> for Unit {
-    type Error = ();
-    fn try_from(value: i32) -> Result<Self, Self::Error> {
-        let ok = match value {
-            0 => Self::Meters,
-            1 => Self::Centimeters,
-            2 => Self::Feet,
-            3 => Self::Inches,
-            _ => return Err(()),
-        };
-        Ok(ok)
-    }
+/// Returns the index of the given unit in `LENGTH_UNITS`, for selecting it in a unit selector.
+pub fn unit_index(unit: LengthUnit) -> i32 {
+    LENGTH_UNITS
+        .iter()
+        .position(|&(_, _, u)| u == unit)
+        .unwrap() as i32
 }
 
 pub trait PosExt: WidgetExt {
@@ -132,3 +175,15 @@ impl<T> Repack<(T, T)> for [T; 2] {
         (a, b)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_indices_match_the_table() {
+        for (index, &(_, _, unit)) in LENGTH_UNITS.iter().enumerate() {
+            assert_eq!(unit_index(unit), index as i32);
+        }
+    }
+}
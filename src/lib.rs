@@ -4,56 +4,371 @@
 //!
 //! The "focused" mode will output an FOV for the camera which will represent an accurate scale of objects at a given distance in the 3D world from the camera.
 //!
+//! # Features
+//! - `gui` *(enabled by default)* — builds the GUI tool. Disable the default features when using the crate as a library.
+//! - `serde` — implements `Serialize` and `Deserialize` for the public types, without requiring `std`. Lengths are stored in meters and angles in radians, with the unit spelled out in the field name, e.g. `width_m` or `yaw_rad`, which makes the round trip lossless.
+//! - `libm` — performs the trigonometry and square roots with [libm] instead of the standard library, which makes the library usable without `std`, e.g. on embedded targets. Without it, the library is still `#![no_std]`, but links to `std` for the math.
+//!
 //! # License
 //! The code itself is dual-licensed under the MIT or Apache 2.0 licenses, at your option.
 //!
 //! The icon is the eye emoji from [Twemoji], licensed under [CC-BY 4.0].
 //!
+//! [libm]: https://crates.io/crates/libm " "
 //! [Twemoji]: https://twemoji.twitter.com/ " "
 //! [CC-BY 4.0]: https://creativecommons.org/licenses/by/4.0/ " "
 
 #![no_std]
-#![forbid(unsafe_code, rust_2018_idioms)]
+#![forbid(unsafe_code)]
+// Not forbid, since the code generated by serde_derive allows some of those lints
+#![deny(rust_2018_idioms)]
 #![warn(missing_docs)]
 
+mod acuity;
+mod advisor;
 mod aspect;
-pub use aspect::*;
+mod curvature;
+mod direction;
+/// Single-precision versions of the core types, for use with `f32` engine code. They convert to and from their `f64` counterparts with `From`, and perform the calculations in double precision internally.
+///
+/// The module covers the monitor configuration with its curvature, resolution, acuity, projection, scope, viewport, tolerance and direction calculations, and the frustum. Types without lengths or angles, such as [`Viewport`] and [`ProjectionModel`], are shared with the double-precision API. The rest, i.e. surround setups, stereoscopy, sensitivity conversion, camera sensors, the display advisor and the validation warnings, is only available in double precision.
+///
+/// [`Viewport`]: enum.Viewport.html " "
+/// [`ProjectionModel`]: enum.ProjectionModel.html " "
+pub mod f32;
+mod fov;
+mod frustum;
+mod math;
+mod projection;
+mod projection_model;
+mod quantity;
+mod sensitivity;
+mod sensor;
+#[cfg(feature = "serde")]
+mod serde_units;
+mod stereo;
+mod surround;
+mod tolerance;
+mod validation;
+mod viewport;
+pub use {
+    acuity::*, advisor::*, aspect::*, curvature::*, direction::*, fov::*, frustum::*,
+    projection::*, projection_model::*, quantity::*, sensitivity::*, sensor::*, stereo::*,
+    surround::*, tolerance::*, validation::*, viewport::*,
+};
 
 use core::fmt::{self, Debug, Formatter};
-use uom::{
-    si::{
-        f64::{Angle, Length},
-        length::meter,
-    },
-    typenum::*,
+use uom::si::{
+    angle::{degree, radian},
+    f64::{Angle, Length},
+    length::{inch, meter},
+    ratio::ratio,
 };
 
 /// Measurements of the monitor dimensions and position.
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MonitorConfiguration {
     /// The dimensions of the monitor. Those contain the width and height, diagonal length and aspect ratio of the monitor.
     pub dimensions: MonitorDimensions,
     /// The distance at which the viewer is said to be located from the monitor's surface.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "distance_m", with = "crate::serde_units::meters")
+    )]
     pub distance: Length,
+    /// The position of the eye relative to the center of the monitor, or `None` if the eye is right in front of the center.
+    ///
+    /// Only the [frustum] and the [view directions of pixels] take this into account; all other calculations assume that the eye is centered.
+    ///
+    /// [frustum]: #method.frustum " "
+    /// [view directions of pixels]: #method.direction_at_pixel " "
+    pub eye_offset: Option<EyeOffset>,
+    /// The curvature of the monitor, or `None` if the monitor is flat.
+    ///
+    /// All calculations take this into account by using the [flat equivalent] of the monitor, which means that the horizontal FOV is correct at the center row of the screen, except for the [view directions of pixels], which follow the curve exactly.
+    ///
+    /// [flat equivalent]: #method.flat_equivalent_width_and_height " "
+    /// [view directions of pixels]: #method.direction_at_pixel " "
+    pub curvature: Option<Curvature>,
+    /// The native resolution of the monitor as `[width, height]` in pixels, or `None` if it's unknown.
+    ///
+    /// Only the [pixel density], [pixels per degree] and [view directions of pixels] calculations use this, and they assume square pixels.
+    ///
+    /// [pixel density]: #method.pixels_per_inch " "
+    /// [pixels per degree]: #method.average_pixels_per_degree " "
+    /// [view directions of pixels]: #method.direction_at_pixel " "
+    pub resolution: Option<[u32; 2]>,
 }
 impl MonitorConfiguration {
-    /// Calculates the viewing angle from the viewpoint towards the monitor.
+    /// Calculates the horizontal viewing angle from the viewpoint towards the monitor.
     ///
     /// More exactly, this is the angle at the viewpoint vertex of a triangle constructed from the screen width as a line segment and two line segments between two verticies of the screen width line segment and the viewpoint vertex.
     pub fn fov(self) -> Angle {
-        // Opposite catet, which is half the width of the screen
-        let opposite = self.dimensions.width_and_height()[0] / 2.0;
+        self.fov_along(FovAxis::Horizontal)
+    }
+    /// Calculates the vertical viewing angle from the viewpoint towards the monitor, i.e. the same as [`fov`] but with the screen height instead of the width.
+    ///
+    /// [`fov`]: #method.fov " "
+    pub fn vertical_fov(self) -> Angle {
+        self.fov_along(FovAxis::Vertical)
+    }
+    /// Calculates the diagonal viewing angle from the viewpoint towards the monitor, i.e. the same as [`fov`] but with the screen diagonal instead of the width.
+    ///
+    /// [`fov`]: #method.fov " "
+    pub fn diagonal_fov(self) -> Angle {
+        self.fov_along(FovAxis::Diagonal)
+    }
+    /// Calculates the viewing angle from the viewpoint towards the monitor along the specified axis.
+    pub fn fov_along(self, axis: FovAxis) -> Angle {
+        let [width, height] = self.flat_equivalent_width_and_height();
+        let extent = MonitorDimensions::WidthAndHeight { width, height }.extent_along(axis);
+        // Opposite catet, which is half the extent of the screen along the axis
+        let opposite = extent / 2.0;
         // Adjacent catet, the distance to the screen
         let adjacent = self.distance;
         // Find the angle by the ratio of the opposite catet to the adjacent
         // catet — exactly what the arctangent function does
-        let half_angle = (opposite / adjacent).atan();
+        let half_angle = math::atan((opposite / adjacent).get::<ratio>());
         // Double the angle because we split the viewer-to-screen triangle
         // into two right-angled triangles and got the desired angle of
         // one of them, hence we get the full angle by multiplying by two
         half_angle * 2.0
     }
+    /// Returns the width and height of a flat monitor located at the same distance which would be seen at the same horizontal and vertical angles, i.e. the monitor onto which the image of a curved monitor is projected from the eye.
+    ///
+    /// For flat monitors, those are the width and height from the dimensions. For curved monitors, the height stays the same, while the width is extended by how much closer the edges of the screen are to the eye than its center, unless the edges are not in front of the eye at all, in which case the width is infinite.
+    pub fn flat_equivalent_width_and_height(self) -> [Length; 2] {
+        let [width, height] = self.dimensions.width_and_height();
+        let curvature = match self.curvature {
+            Some(c) => c,
+            None => return [width, height],
+        };
+        // The edges are offset from the center horizontally by half the chord and are closer to
+        // the eye than the center by the sagitta, which gives us a right-angled triangle between
+        // the eye and an edge...
+        let half_chord = curvature.chord_width(width) / 2.0;
+        let edge_distance = self.distance - curvature.sagitta(width);
+        if edge_distance <= Length::default() {
+            return [Length::new::<meter>(f64::INFINITY), height];
+        }
+        // ...which is similar to the triangle between the eye and the edge of the flat equivalent
+        let half_width = half_chord * (self.distance / edge_distance);
+        [half_width * 2.0, height]
+    }
+    /// Calculates the viewing distance from which the monitor is seen at the given horizontal FOV.
+    ///
+    /// This is the inverse of [`fov`] with respect to the distance. Unlike [`MonitorDimensions::distance_for_fov`], this takes the curvature into account.
+    ///
+    /// [`fov`]: #method.fov " "
+    /// [`MonitorDimensions::distance_for_fov`]: enum.MonitorDimensions.html#method.distance_for_fov " "
+    pub fn distance_for_fov(self, fov: Angle) -> Length {
+        let curvature = match self.curvature {
+            Some(c) => c,
+            None => return self.dimensions.distance_for_fov(fov, FovAxis::Horizontal),
+        };
+        // The same triangle between the eye and an edge as in flat_equivalent_width_and_height,
+        // with the distance to the edge found from the angle and the center pushed back by the
+        // sagitta
+        let [width, _] = self.dimensions.width_and_height();
+        let half_chord = curvature.chord_width(width) / 2.0;
+        curvature.sagitta(width) + half_chord / math::tan(fov / 2.0)
+    }
+    /// Calculates the dimensions of a monitor with the same aspect ratio and curvature which is seen at the given horizontal FOV from the same distance, or `None` if a curved monitor can't reach that FOV from there.
+    ///
+    /// This is the inverse of [`fov`] with respect to the dimensions. Unlike [`MonitorDimensions::for_fov`], this takes the curvature into account, with the width measured the way the curvature specifies.
+    ///
+    /// [`fov`]: #method.fov " "
+    /// [`MonitorDimensions::for_fov`]: enum.MonitorDimensions.html#method.for_fov " "
+    pub fn dimensions_for_fov(self, fov: Angle) -> Option<MonitorDimensions> {
+        let width = match self.curvature {
+            Some(c) => c.width_for_fov(fov, self.distance)?,
+            None => self.distance * math::tan(fov / 2.0) * 2.0,
+        };
+        Some(MonitorDimensions::WidthAndHeight {
+            width,
+            height: width / self.dimensions.aspect(),
+        })
+    }
+    /// Returns how much farther away from the monitor than the center of its curvature the eye is located, with negative values meaning that the eye is closer to the monitor than the center, or `None` if the monitor is flat.
+    ///
+    /// Curved monitors are designed to be viewed from the center of their curvature, with all points of the screen equally far from the eye.
+    pub fn distance_from_curvature_center(self) -> Option<Length> {
+        self.curvature.map(|c| self.distance - c.radius)
+    }
+    /// Returns whether the eye is located off the center of the curvature of the monitor by more than the given fraction of the radius. Always returns `false` for flat monitors.
+    pub fn is_far_from_curvature_center(self, tolerance: f64) -> bool {
+        match (self.curvature, self.distance_from_curvature_center()) {
+            (Some(c), Some(offset)) => offset.abs() > c.radius * tolerance,
+            _ => false,
+        }
+    }
+    /// Returns the distance between the centers of two adjacent pixels, i.e. the width of the screen divided by the horizontal resolution, or `None` if the resolution is unknown.
+    ///
+    /// For curved monitors, the width of the screen is measured along the curve, since that's the way the pixels are laid out.
+    pub fn dot_pitch(self) -> Option<Length> {
+        let [horizontal_pixels, _] = self.resolution?;
+        Some(self.panel_width() / horizontal_pixels as f64)
+    }
+    /// Returns the width of the screen along its surface, which is the arc width for curved monitors.
+    fn panel_width(self) -> Length {
+        let [width, _] = self.dimensions.width_and_height();
+        match self.curvature {
+            Some(c) => c.arc_width(width),
+            None => width,
+        }
+    }
+    /// Returns the number of pixels per inch of the screen, or `None` if the resolution is unknown.
+    pub fn pixels_per_inch(self) -> Option<f64> {
+        self.dot_pitch()
+            .map(|pitch| (Length::new::<inch>(1.0) / pitch).get::<ratio>())
+    }
+    /// Returns the number of pixels per degree of the horizontal [FOV], averaged over the whole width of the screen, or `None` if the resolution is unknown.
+    ///
+    /// [FOV]: #method.fov " "
+    pub fn average_pixels_per_degree(self) -> Option<f64> {
+        let [horizontal_pixels, _] = self.resolution?;
+        Some(horizontal_pixels as f64 / self.fov().get::<degree>())
+    }
+    /// Returns the number of pixels per degree at the center of the screen, or `None` if the resolution is unknown. On flat monitors, this is where pixels are the closest to the eye and are thus seen at the largest angle.
+    ///
+    /// This ignores the [eye offset]. On curved monitors the pixels are spaced along the curve, as with the [dot pitch].
+    ///
+    /// [eye offset]: #structfield.eye_offset " "
+    /// [dot pitch]: #method.dot_pitch " "
+    pub fn center_pixels_per_degree(self) -> Option<f64> {
+        // A small step along the screen at its center is seen at an angle which is that step
+        // divided by the distance in radians, same as an arc of a circle with the distance as
+        // the radius
+        let pixels_per_radian = (self.distance / self.dot_pitch()?).get::<ratio>();
+        Some(pixels_per_radian * Angle::new::<degree>(1.0).get::<radian>())
+    }
+    /// Returns the number of pixels per degree at the left and right edges of the screen, where pixels are seen from a different distance and at a slant, or `None` if the resolution is unknown.
+    ///
+    /// This takes the [curvature] into account, so from the center of the curvature the edges have as many pixels per degree as the center of the screen. The [eye offset] is ignored.
+    ///
+    /// [curvature]: #structfield.curvature " "
+    /// [eye offset]: #structfield.eye_offset " "
+    pub fn edge_pixels_per_degree(self) -> Option<f64> {
+        let [width, _] = self.dimensions.width_and_height();
+        // The edge lies half the chord to the side and, on curved monitors, closer to the eye
+        // than the center by the sagitta, same as in flat_equivalent_width_and_height. The surface
+        // there faces along the radius, which is turned outwards by half of the central angle.
+        let (half_chord, edge_distance, [normal_x, normal_y]) = match self.curvature {
+            Some(c) => {
+                let half_central_angle = c.central_angle(width) / 2.0;
+                (
+                    c.chord_width(width) / 2.0,
+                    self.distance - c.sagitta(width),
+                    [math::sin(half_central_angle), math::cos(half_central_angle)],
+                )
+            }
+            None => (width / 2.0, self.distance, [0.0, 1.0]),
+        };
+        // A small step along the screen is seen at an angle which is that step divided by the
+        // distance to it, shrunk by the cosine of the slant between the surface and the line of
+        // sight, which is the dot product of the normal and the direction towards the edge
+        let squared_distance = half_chord * half_chord + edge_distance * edge_distance;
+        let facing = half_chord * normal_x + edge_distance * normal_y;
+        let stretch = (squared_distance / (self.distance * facing)).get::<ratio>();
+        Some(self.center_pixels_per_degree()? * stretch)
+    }
+    /// Returns the angle which a pixel at the center of the screen is seen at, or `None` if the resolution is unknown.
+    ///
+    /// On flat monitors, pixels at the center are the closest to the eye and are thus seen at the largest angle, which makes them the first ones to be noticeable.
+    pub fn pixel_angle(self) -> Option<Angle> {
+        // Same as fov_along, with the pixel in place of the screen
+        let opposite = self.dot_pitch()? / 2.0;
+        Some(math::atan((opposite / self.distance).get::<ratio>()) * 2.0)
+    }
+    /// Compares the size of the pixels to the given visual acuity, or returns `None` if the resolution is unknown.
+    pub fn acuity_analysis(self, acuity: VisualAcuity) -> Option<AcuityAnalysis> {
+        let pixel_angle = self.pixel_angle()?;
+        Some(AcuityAnalysis {
+            pixel_angle,
+            pixel_to_resolvable_ratio: (pixel_angle / acuity.resolvable_angle).get::<ratio>(),
+            retina_distance: acuity.resolvable_distance(self.dot_pitch()?),
+        })
+    }
+    /// Calculates the portal-like view frustum, taking the [eye offset] into account.
+    ///
+    /// If the eye is off-center, the frustum is asymmetric, meaning that the camera needs an off-axis projection, specified either by the [near plane extents] or the [lens shift], to keep the portal effect correct.
+    ///
+    /// [eye offset]: #structfield.eye_offset " "
+    /// [near plane extents]: struct.Frustum.html#method.near_plane_extents " "
+    /// [lens shift]: struct.Frustum.html#method.lens_shift " "
+    pub fn frustum(self) -> Frustum {
+        let [width, height] = self.flat_equivalent_width_and_height();
+        let (horizontal_offset, vertical_offset) = match self.eye_offset {
+            Some(EyeOffset {
+                horizontal,
+                vertical,
+            }) => (horizontal, vertical),
+            None => (Length::default(), Length::default()),
+        };
+        // Same as in fov_along, except each side gets its own right-angled triangle, the opposite
+        // catet of which is the distance between the eye and the edge of the screen in the plane
+        // of the screen
+        let angle = |opposite: Length| math::atan((opposite / self.distance).get::<ratio>());
+        Frustum {
+            left: angle(width / 2.0 + horizontal_offset),
+            right: angle(width / 2.0 - horizontal_offset),
+            top: angle(height / 2.0 - vertical_offset),
+            bottom: angle(height / 2.0 + vertical_offset),
+        }
+    }
+    /// Generates a perspective projection matrix for the portal-like [frustum], which is off-axis if the eye is off-center.
+    ///
+    /// [frustum]: #method.frustum " "
+    pub fn projection_matrix(self, options: ProjectionOptions) -> [f64; 16] {
+        self.frustum().projection_matrix(options)
+    }
+    /// Calculates the value which needs to be given to a game using the specified FOV convention to get the [portal-like FOV].
+    ///
+    /// [portal-like FOV]: #method.fov " "
+    pub fn fov_for_convention(self, convention: FovConvention) -> Angle {
+        convention.value_for(self.fov(), self.dimensions.aspect())
+    }
+    /// Calculates the horizontal FOV which makes a scope with the given magnification look like a real one, i.e. makes objects appear that many times larger than they would through the screen if it was a window, using [`zoomed_fov`] on the [portal-like FOV].
+    ///
+    /// [`zoomed_fov`]: fn.zoomed_fov.html " "
+    /// [portal-like FOV]: #method.fov " "
+    pub fn scope_fov(self, magnification: f64) -> Angle {
+        zoomed_fov(self.fov(), magnification)
+    }
+    /// Calculates the effective magnification of the given horizontal FOV, i.e. how many times larger objects appear with it than with the [portal-like FOV]. Values below 1 mean that objects appear smaller than they would through a window, as is the case with most FOV settings in games.
+    ///
+    /// [portal-like FOV]: #method.fov " "
+    pub fn magnification_for_fov(self, fov: Angle) -> f64 {
+        magnification(self.fov(), fov)
+    }
+    /// Calculates the FOV values which need to be given to a game using the specified projection model for the left and right edges of the screen to show the directions in which they are seen from the eye.
+    ///
+    /// For the rectilinear projection, those are the same as the [horizontal] and [vertical] portal-like FOV. This assumes that the eye is centered.
+    ///
+    /// [horizontal]: #method.fov " "
+    /// [vertical]: #method.vertical_fov " "
+    pub fn projection_fov(self, model: ProjectionModel) -> ProjectionFov {
+        let [_, height] = self.dimensions.width_and_height();
+        // The image is laid out along the surface of the screen, which is curved on curved monitors
+        let aspect = (self.panel_width() / height).get::<ratio>();
+        model.fov(self.fov(), aspect)
+    }
+    /// Calculates the [Panini distance] which makes the left and right edges of the screen show the directions in which they are seen from the eye if the game is set to the given vertical FOV, using [`panini_distance_for_vertical_fov`].
+    ///
+    /// [Panini distance]: enum.ProjectionModel.html#variant.Panini " "
+    /// [`panini_distance_for_vertical_fov`]: fn.panini_distance_for_vertical_fov.html " "
+    pub fn panini_distance_for_vertical_fov(self, vertical_fov: Angle) -> Option<f64> {
+        let [_, height] = self.dimensions.width_and_height();
+        let aspect = (self.panel_width() / height).get::<ratio>();
+        panini_distance_for_vertical_fov(self.fov(), vertical_fov, aspect)
+    }
     /// Calculates an FOV for the monitor as the starting point such that a given distance (either relative to the eye or the monitor) will be represented with accurate scale.
+    ///
+    /// The resulting FOV is horizontal; use [`convert_fov`] with the [aspect ratio] of the monitor to get it along a different axis.
+    ///
+    /// [`convert_fov`]: fn.convert_fov.html " "
+    /// [aspect ratio]: enum.MonitorDimensions.html#method.aspect " "
     pub fn monitor_fov_for_distance(self, distance: Length, relative_to_monitor: bool) -> Angle {
         let distance_from_eye = if relative_to_monitor {
             distance + self.distance
@@ -63,13 +378,13 @@ impl MonitorConfiguration {
         // Eye-to-monitor FOV divided by 2 will give us a right-angled triangle to work with
         let base_half_angle = self.fov() / 2.0;
         // The tangent of an angle is the opposite catet divided by the adjacent catet...
-        let half_width_over_distance = base_half_angle.tan();
+        let half_width_over_distance = math::tan(base_half_angle);
         // ...so multiplying that by the adjacent catet will give us the opposite catet
         let half_width = half_width_over_distance * distance_from_eye;
         // Now we get the tangent of the (half of the) monitor-relative FOV we're trying to find
-        let final_angle_tangent = half_width / distance;
+        let final_angle_tangent = (half_width / distance).get::<ratio>();
         // The arctangent will give us the angle from a known tangent...
-        let half_final_angle = final_angle_tangent.atan();
+        let half_final_angle = math::atan(final_angle_tangent);
         // ...so we can multiply it by 2 to get the final monitor-relative FOV
         half_final_angle * 2.0
     }
@@ -77,16 +392,32 @@ impl MonitorConfiguration {
 
 /// The dimensions of a monitor.
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MonitorDimensions {
     /// Dimensions expressed directly as the width and the height.
     #[allow(missing_docs)] // Field names are self-explanatory
-    WidthAndHeight { width: Length, height: Length },
+    WidthAndHeight {
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "width_m", with = "crate::serde_units::meters")
+        )]
+        width: Length,
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "height_m", with = "crate::serde_units::meters")
+        )]
+        height: Length,
+    },
     /// Dimensions expressed indirectly as the diagonal and the aspect ratio.
     DiagonalAndAspect {
         /// The length of the (imaginary) line drawn between two opposite (top left and bottom right, for example) corners of the monitor.
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "diagonal_m", with = "crate::serde_units::meters")
+        )]
         diagonal: Length,
         /// The aspect ratio, i.e. `width / height`.
-        aspect: f64,
+        aspect: AspectRatio,
     },
 }
 impl MonitorDimensions {
@@ -95,15 +426,58 @@ impl MonitorDimensions {
         match self {
             Self::WidthAndHeight { width, height } => [width, height],
             Self::DiagonalAndAspect { diagonal, aspect } => {
-                Self::diagonal_and_aspect_to_width_and_height(diagonal, aspect)
+                Self::diagonal_and_aspect_to_width_and_height(diagonal, aspect.value())
             }
         }
     }
+    /// Returns the size of the monitor along the specified axis, i.e. the width, height or diagonal.
+    pub fn extent_along(self, axis: FovAxis) -> Length {
+        match axis {
+            FovAxis::Horizontal => self.width_and_height()[0],
+            FovAxis::Vertical => self.width_and_height()[1],
+            FovAxis::Diagonal => self.diagonal(),
+        }
+    }
+    /// Calculates the viewing distance from which a flat monitor with these dimensions is seen at the given FOV along the specified axis.
+    ///
+    /// This is the inverse of [`MonitorConfiguration::fov_along`] with respect to the distance.
+    ///
+    /// [`MonitorConfiguration::fov_along`]: struct.MonitorConfiguration.html#method.fov_along " "
+    pub fn distance_for_fov(self, fov: Angle, axis: FovAxis) -> Length {
+        // The same right-angled triangle as in fov_along, except this time we know the angle and
+        // the opposite catet and are looking for the adjacent one
+        let opposite = self.extent_along(axis) / 2.0;
+        opposite / math::tan(fov / 2.0)
+    }
+    /// Calculates the dimensions of a flat monitor with the given aspect ratio which is seen at the given FOV along the specified axis from the given viewing distance.
+    ///
+    /// This is the inverse of [`MonitorConfiguration::fov_along`] with respect to the dimensions. Use [`width_and_height`] or [`diagonal`] on the result to get the width or the diagonal.
+    ///
+    /// [`MonitorConfiguration::fov_along`]: struct.MonitorConfiguration.html#method.fov_along " "
+    /// [`width_and_height`]: #method.width_and_height " "
+    /// [`diagonal`]: #method.diagonal " "
+    pub fn for_fov(fov: Angle, axis: FovAxis, distance: Length, aspect: AspectRatio) -> Self {
+        // Same as above, except we're looking for the opposite catet
+        let extent = math::tan(fov / 2.0) * distance * 2.0;
+        // Scale the extent along the axis into the diagonal with the same proportions that
+        // convert_fov uses for the tangents
+        let value = aspect.value();
+        let diagonal =
+            extent * FovAxis::Diagonal.extent_for_aspect(value) / axis.extent_for_aspect(value);
+        Self::DiagonalAndAspect { diagonal, aspect }
+    }
     /// Returns the aspect ratio of the monitor, calculating it if necessary.
     pub fn aspect(self) -> f64 {
         match self {
             Self::WidthAndHeight { width, height } => width.get::<meter>() / height.get::<meter>(),
-            Self::DiagonalAndAspect { aspect, .. } => aspect,
+            Self::DiagonalAndAspect { aspect, .. } => aspect.value(),
+        }
+    }
+    /// Returns the aspect ratio of the monitor as a fraction, approximating it with a denominator of at most 10000 if the dimensions are stored as the width and height. Returns `None` if the width or height is not a positive finite length.
+    pub fn aspect_ratio(self) -> Option<AspectRatio> {
+        match self {
+            Self::WidthAndHeight { .. } => AspectRatio::approximate(self.aspect(), 10_000),
+            Self::DiagonalAndAspect { aspect, .. } => Some(aspect),
         }
     }
     /// Returns the length of the diagonal of the monitor, calculating it if necessary.
@@ -112,7 +486,8 @@ impl MonitorDimensions {
             Self::WidthAndHeight { width, height } => {
                 // Pythagorean theorem, reinterpret width and height as catet
                 // lengths and calculate hypotenuse
-                (width.powi(P2::new()) + height.powi(P2::new())).sqrt()
+                let [width, height] = [width.get::<meter>(), height.get::<meter>()];
+                Length::new::<meter>(math::sqrt(width * width + height * height))
             }
             Self::DiagonalAndAspect { diagonal, .. } => diagonal,
         }
@@ -123,7 +498,7 @@ impl MonitorDimensions {
         // is that we have an aspect ratio rather than m and n forming the fraction m/n, meaning
         // that all uses of m are substituted by the aspect ratio and all uses of n are substituted
         // by 1.
-        let height = diagonal / (aspect.powi(2) + 1.0).sqrt();
+        let height = diagonal / math::sqrt(aspect * aspect + 1.0);
         let width = height * aspect;
         [width, height]
     }
@@ -132,12 +507,29 @@ impl MonitorDimensions {
         let [width, height] = self.width_and_height();
         Self::WidthAndHeight { width, height }
     }
-    /// Re-represents the dimensions as the `DiagonalAndAspect` variant.
+    /// Re-represents the dimensions as the `DiagonalAndAspect` variant, with the aspect ratio approximated as in [`aspect_ratio`]. If the aspect ratio can't be represented, the [nearest common one] is used instead; use [`try_as_diagonal_and_aspect`] to detect that case.
+    ///
+    /// [`aspect_ratio`]: #method.aspect_ratio " "
+    /// [nearest common one]: struct.AspectRatio.html#method.nearest_common " "
+    /// [`try_as_diagonal_and_aspect`]: #method.try_as_diagonal_and_aspect " "
     pub fn as_diagonal_and_aspect(self) -> Self {
-        Self::DiagonalAndAspect {
+        self.try_as_diagonal_and_aspect().unwrap_or_else(|| {
+            // COMMON_ASPECT_RATIOS isn't empty, so there always is a nearest one
+            let aspect = AspectRatio::nearest_common(self.aspect()).next().unwrap();
+            Self::DiagonalAndAspect {
+                diagonal: self.diagonal(),
+                aspect,
+            }
+        })
+    }
+    /// Re-represents the dimensions as the `DiagonalAndAspect` variant, with the aspect ratio approximated as in [`aspect_ratio`]. Returns `None` if the aspect ratio can't be represented.
+    ///
+    /// [`aspect_ratio`]: #method.aspect_ratio " "
+    pub fn try_as_diagonal_and_aspect(self) -> Option<Self> {
+        Some(Self::DiagonalAndAspect {
             diagonal: self.diagonal(),
-            aspect: self.aspect(),
-        }
+            aspect: self.aspect_ratio()?,
+        })
     }
 }
 impl Debug for MonitorDimensions {
@@ -161,3 +553,158 @@ impl Debug for MonitorDimensions {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uom::si::length::{centimeter, millimeter};
+
+    fn monitor(dimensions: MonitorDimensions, distance: Length) -> MonitorConfiguration {
+        MonitorConfiguration {
+            dimensions,
+            distance,
+            eye_offset: None,
+            curvature: None,
+            resolution: None,
+        }
+    }
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            math::abs(actual - expected) < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn distance_for_fov_inverts_fov() {
+        let dimensions = MonitorDimensions::DiagonalAndAspect {
+            diagonal: Length::new::<inch>(27.0),
+            aspect: AspectRatio::new(16, 9).unwrap(),
+        };
+        for &axis in &FovAxis::ALL {
+            for &fov in &[30.0, 90.0, 150.0] {
+                let fov = Angle::new::<degree>(fov);
+                let distance = dimensions.distance_for_fov(fov, axis);
+                let monitor = monitor(dimensions, distance);
+                assert_close(monitor.fov_along(axis).get::<radian>(), fov.get::<radian>());
+            }
+        }
+        // The game caps the FOV at 90°, so how far away should the monitor be?
+        let monitor = monitor(dimensions, Length::new::<centimeter>(70.0));
+        let fov = Angle::new::<degree>(90.0);
+        let distance = monitor.distance_for_fov(fov);
+        assert_close(
+            distance.get::<meter>(),
+            dimensions.width_and_height()[0].get::<meter>() / 2.0,
+        );
+        let at_distance = MonitorConfiguration {
+            distance,
+            ..monitor
+        };
+        assert_close(at_distance.fov().get::<radian>(), fov.get::<radian>());
+    }
+    #[test]
+    fn dimensions_for_fov_inverts_fov() {
+        let distance = Length::new::<centimeter>(70.0);
+        let aspect = AspectRatio::new(64, 27).unwrap();
+        for &axis in &FovAxis::ALL {
+            for &fov in &[30.0, 90.0, 150.0] {
+                let fov = Angle::new::<degree>(fov);
+                let dimensions = MonitorDimensions::for_fov(fov, axis, distance, aspect);
+                let monitor = monitor(dimensions, distance);
+                assert_close(monitor.fov_along(axis).get::<radian>(), fov.get::<radian>());
+                assert_close(monitor.dimensions.aspect(), aspect.value());
+            }
+        }
+        let current = monitor(
+            MonitorDimensions::WidthAndHeight {
+                width: Length::new::<centimeter>(60.0),
+                height: Length::new::<centimeter>(33.75),
+            },
+            distance,
+        );
+        let fov = Angle::new::<degree>(75.0);
+        let resized = MonitorConfiguration {
+            dimensions: current.dimensions_for_fov(fov).unwrap(),
+            ..current
+        };
+        assert_close(resized.fov().get::<radian>(), fov.get::<radian>());
+        assert_close(resized.dimensions.aspect(), current.dimensions.aspect());
+    }
+    #[test]
+    fn width_for_100_degrees_at_70_cm() {
+        // Sitting at 70 cm and wanting 100°, the screen needs to be 2 × 70 cm × tan 50° wide
+        let dimensions = MonitorDimensions::for_fov(
+            Angle::new::<degree>(100.0),
+            FovAxis::Horizontal,
+            Length::new::<centimeter>(70.0),
+            AspectRatio::new(16, 9).unwrap(),
+        );
+        let [width, _] = dimensions.width_and_height();
+        assert!(math::abs(width.get::<centimeter>() - 166.85) < 0.01);
+        // That's a 75.4" 16:9 screen
+        assert!(math::abs(dimensions.diagonal().get::<inch>() - 75.37) < 0.01);
+    }
+    #[test]
+    fn edge_pixels_per_degree_follows_the_curve() {
+        let flat = MonitorConfiguration {
+            resolution: Some([2560, 1440]),
+            ..monitor(
+                MonitorDimensions::WidthAndHeight {
+                    width: Length::new::<centimeter>(60.0),
+                    height: Length::new::<centimeter>(33.75),
+                },
+                Length::new::<centimeter>(60.0),
+            )
+        };
+        // The edges are half the distance to the side, which makes them 1 + 0.5² times denser
+        assert_close(
+            flat.edge_pixels_per_degree().unwrap(),
+            flat.center_pixels_per_degree().unwrap() * 1.25,
+        );
+        // From the center of the curvature, every pixel is equally far and faces the eye
+        let curved = MonitorConfiguration {
+            curvature: Some(Curvature {
+                radius: Length::new::<meter>(1.0),
+                width_measurement: CurvedWidthMeasurement::Arc,
+            }),
+            resolution: Some([3440, 1440]),
+            ..monitor(
+                MonitorDimensions::WidthAndHeight {
+                    width: Length::new::<millimeter>(1200.0),
+                    height: Length::new::<millimeter>(500.0),
+                },
+                Length::new::<meter>(1.0),
+            )
+        };
+        let center = curved.center_pixels_per_degree().unwrap();
+        assert_close(curved.edge_pixels_per_degree().unwrap(), center);
+        assert_close(curved.average_pixels_per_degree().unwrap(), center);
+        // The same screen specified by its chord gives the same numbers
+        let chord = curved
+            .curvature
+            .unwrap()
+            .chord_width(Length::new::<millimeter>(1200.0));
+        let by_chord = MonitorConfiguration {
+            dimensions: MonitorDimensions::WidthAndHeight {
+                width: chord,
+                height: Length::new::<millimeter>(500.0),
+            },
+            curvature: Some(Curvature {
+                width_measurement: CurvedWidthMeasurement::Chord,
+                ..curved.curvature.unwrap()
+            }),
+            ..curved
+        };
+        assert_close(by_chord.edge_pixels_per_degree().unwrap(), center);
+        // Behind the center of the curvature, the edges are closer to the eye than the center of
+        // the screen and have fewer pixels per degree, unlike on a flat screen
+        let farther = MonitorConfiguration {
+            distance: Length::new::<meter>(1.5),
+            ..curved
+        };
+        assert!(farther.edge_pixels_per_degree() < farther.center_pixels_per_degree());
+    }
+}
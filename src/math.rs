@@ -0,0 +1,73 @@
+// Trigonometry and roots, which core doesn't provide. With the libm feature enabled, those are
+// routed through libm, which makes the crate work without std; otherwise, the inherent methods
+// of f64 from std are used. All math in the crate goes through here, including the math on
+// quantities, since uom only provides trigonometry for them with its std feature.
+
+use uom::si::{angle::radian, f64::Angle};
+
+#[cfg(feature = "libm")]
+mod backend {
+    pub(super) use libm::{asin, atan, atan2, cos, sin, sqrt, tan};
+}
+#[cfg(not(feature = "libm"))]
+mod backend {
+    extern crate std;
+    pub(super) fn tan(x: f64) -> f64 {
+        x.tan()
+    }
+    pub(super) fn sin(x: f64) -> f64 {
+        x.sin()
+    }
+    pub(super) fn cos(x: f64) -> f64 {
+        x.cos()
+    }
+    pub(super) fn atan(x: f64) -> f64 {
+        x.atan()
+    }
+    pub(super) fn asin(x: f64) -> f64 {
+        x.asin()
+    }
+    pub(super) fn atan2(y: f64, x: f64) -> f64 {
+        y.atan2(x)
+    }
+    pub(super) fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+}
+
+/// Returns the tangent of an angle.
+pub(crate) fn tan(angle: Angle) -> f64 {
+    backend::tan(angle.get::<radian>())
+}
+/// Returns the sine of an angle.
+pub(crate) fn sin(angle: Angle) -> f64 {
+    backend::sin(angle.get::<radian>())
+}
+/// Returns the cosine of an angle.
+pub(crate) fn cos(angle: Angle) -> f64 {
+    backend::cos(angle.get::<radian>())
+}
+/// Returns the angle with the given tangent, between -90° and 90°.
+pub(crate) fn atan(tangent: f64) -> Angle {
+    Angle::new::<radian>(backend::atan(tangent))
+}
+/// Returns the angle with the given sine, between -90° and 90°.
+pub(crate) fn asin(sine: f64) -> Angle {
+    Angle::new::<radian>(backend::asin(sine))
+}
+/// Returns the angle between the positive X axis and the direction towards the given point, between -180° and 180°.
+pub(crate) fn atan2(y: f64, x: f64) -> Angle {
+    Angle::new::<radian>(backend::atan2(y, x))
+}
+/// Returns the square root of a number.
+pub(crate) fn sqrt(x: f64) -> f64 {
+    backend::sqrt(x)
+}
+/// Returns the absolute value of a number. Not provided by core on older compilers.
+pub(crate) fn abs(x: f64) -> f64 {
+    if x < 0.0 {
+        -x
+    } else {
+        x
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{math, Frustum};
+
+/// The range which depth values occupy in normalized device coordinates, i.e. after the perspective divide.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DepthRange {
+    /// Depth values range from -1 at the near plane to 1 at the far plane. This is the convention used by OpenGL.
+    NegativeOneToOne,
+    /// Depth values range from 0 at the near plane to 1 at the far plane. This is the convention used by Vulkan, Direct3D and Metal, as well as OpenGL with `glClipControl`.
+    ZeroToOne,
+}
+
+/// The order in which the elements of a matrix are laid out in memory.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MatrixLayout {
+    /// The elements of each row are contiguous, i.e. the first four elements are the first row. This is what HLSL and Direct3D math libraries use by default.
+    RowMajor,
+    /// The elements of each column are contiguous, i.e. the first four elements are the first column. This is what OpenGL, GLSL and most Vulkan math libraries use.
+    ColumnMajor,
+}
+
+/// Options for generating a perspective projection matrix.
+///
+/// The matrix assumes a right-handed view space with the camera looking down the negative Z axis, X pointing right and Y pointing up, and is meant to be multiplied with column vectors. For a left-handed view space with the camera looking down the positive Z axis, negate the third column of the matrix.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectionOptions {
+    /// The distance from the camera to the near clipping plane, in application units.
+    pub near: f64,
+    /// The distance from the camera to the far clipping plane, in application units, or `None` to place it at infinity.
+    pub far: Option<f64>,
+    /// The range of depth values in normalized device coordinates.
+    pub depth_range: DepthRange,
+    /// Whether the depth range is reversed, i.e. the near plane maps to the maximum depth value and the far plane maps to the minimum one. This greatly improves depth buffer precision with floating-point depth buffers and the `ZeroToOne` depth range.
+    pub reverse_z: bool,
+    /// The memory layout of the resulting matrix.
+    pub layout: MatrixLayout,
+}
+
+impl Frustum {
+    /// Generates a perspective projection matrix for the frustum, which is off-axis if the frustum is asymmetric.
+    pub fn projection_matrix(self, options: ProjectionOptions) -> [f64; 16] {
+        let ProjectionOptions {
+            near,
+            far,
+            depth_range,
+            reverse_z,
+            layout,
+        } = options;
+        // The extents of the near plane divided by the distance to it, which is all that's needed
+        // for the X and Y rows since they're scale-independent
+        let [left, right, bottom, top] = [
+            math::tan(self.left),
+            math::tan(self.right),
+            math::tan(self.bottom),
+            math::tan(self.top),
+        ];
+        let x_scale = 2.0 / (left + right);
+        let x_offset = (right - left) / (left + right);
+        let y_scale = 2.0 / (bottom + top);
+        let y_offset = (top - bottom) / (bottom + top);
+        // Clip-space Z is z_scale * z + z_offset, while W is -z; an infinite far plane is the
+        // limit of the finite formulas as far approaches infinity
+        let (z_scale, z_offset) = match (depth_range, far) {
+            (DepthRange::NegativeOneToOne, Some(far)) => (
+                -(far + near) / (far - near),
+                -2.0 * far * near / (far - near),
+            ),
+            (DepthRange::NegativeOneToOne, None) => (-1.0, -2.0 * near),
+            (DepthRange::ZeroToOne, Some(far)) => (-far / (far - near), -far * near / (far - near)),
+            (DepthRange::ZeroToOne, None) => (-1.0, -near),
+        };
+        let (z_scale, z_offset) = if reverse_z {
+            match depth_range {
+                // Mirror the depth around 0, which is negating clip-space Z
+                DepthRange::NegativeOneToOne => (-z_scale, -z_offset),
+                // Replace the depth d with 1 - d, which is W minus clip-space Z
+                DepthRange::ZeroToOne => (-1.0 - z_scale, -z_offset),
+            }
+        } else {
+            (z_scale, z_offset)
+        };
+        let rows = [
+            [x_scale, 0.0, x_offset, 0.0],
+            [0.0, y_scale, y_offset, 0.0],
+            [0.0, 0.0, z_scale, z_offset],
+            [0.0, 0.0, -1.0, 0.0],
+        ];
+        let mut matrix = [0.0; 16];
+        for (row_index, row) in rows.iter().enumerate() {
+            for (column_index, &element) in row.iter().enumerate() {
+                let index = match layout {
+                    MatrixLayout::RowMajor => row_index * 4 + column_index,
+                    MatrixLayout::ColumnMajor => column_index * 4 + row_index,
+                };
+                matrix[index] = element;
+            }
+        }
+        matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uom::si::{angle::degree, f64::Angle};
+
+    fn options(far: Option<f64>, depth_range: DepthRange, reverse_z: bool) -> ProjectionOptions {
+        ProjectionOptions {
+            near: 0.1,
+            far,
+            depth_range,
+            reverse_z,
+            layout: MatrixLayout::RowMajor,
+        }
+    }
+    fn frustum_90() -> Frustum {
+        Frustum::symmetric(Angle::new::<degree>(90.0), Angle::new::<degree>(90.0))
+    }
+    /// Transforms a view-space point into normalized device coordinates with a row-major matrix.
+    fn to_ndc(matrix: [f64; 16], point: [f64; 3]) -> [f64; 3] {
+        let point = [point[0], point[1], point[2], 1.0];
+        let mut clip = [0.0; 4];
+        for (row, value) in clip.iter_mut().enumerate() {
+            *value = (0..4)
+                .map(|column| matrix[row * 4 + column] * point[column])
+                .sum();
+        }
+        [clip[0] / clip[3], clip[1] / clip[3], clip[2] / clip[3]]
+    }
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            math::abs(actual - expected) < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+    fn depth_at(options: ProjectionOptions, distance: f64) -> f64 {
+        to_ndc(
+            frustum_90().projection_matrix(options),
+            [0.0, 0.0, -distance],
+        )[2]
+    }
+
+    #[test]
+    fn negative_one_to_one() {
+        let options = options(Some(100.0), DepthRange::NegativeOneToOne, false);
+        assert_close(depth_at(options, 0.1), -1.0);
+        assert_close(depth_at(options, 100.0), 1.0);
+    }
+    #[test]
+    fn zero_to_one() {
+        let options = options(Some(100.0), DepthRange::ZeroToOne, false);
+        assert_close(depth_at(options, 0.1), 0.0);
+        assert_close(depth_at(options, 100.0), 1.0);
+    }
+    #[test]
+    fn reverse_z() {
+        let zero_to_one = options(Some(100.0), DepthRange::ZeroToOne, true);
+        assert_close(depth_at(zero_to_one, 0.1), 1.0);
+        assert_close(depth_at(zero_to_one, 100.0), 0.0);
+        let negative_one_to_one = options(Some(100.0), DepthRange::NegativeOneToOne, true);
+        assert_close(depth_at(negative_one_to_one, 0.1), 1.0);
+        assert_close(depth_at(negative_one_to_one, 100.0), -1.0);
+    }
+    #[test]
+    fn infinite_far_plane() {
+        let forward = options(None, DepthRange::ZeroToOne, false);
+        assert_close(depth_at(forward, 0.1), 0.0);
+        let far_away = depth_at(forward, 1e9);
+        assert!(far_away < 1.0 && far_away > 0.999_999);
+        let reversed = options(None, DepthRange::ZeroToOne, true);
+        assert_close(depth_at(reversed, 0.1), 1.0);
+        let far_away = depth_at(reversed, 1e9);
+        assert!(far_away > 0.0 && far_away < 1e-6);
+    }
+    #[test]
+    fn off_axis_edges() {
+        // 45° to the left and 15° to the right, so the edges of the near plane land on -1 and 1
+        let frustum = Frustum {
+            left: Angle::new::<degree>(45.0),
+            right: Angle::new::<degree>(15.0),
+            top: Angle::new::<degree>(30.0),
+            bottom: Angle::new::<degree>(30.0),
+        };
+        let matrix = frustum.projection_matrix(options(Some(100.0), DepthRange::ZeroToOne, false));
+        let right = math::tan(Angle::new::<degree>(15.0));
+        assert_close(to_ndc(matrix, [-1.0, 0.0, -1.0])[0], -1.0);
+        assert_close(to_ndc(matrix, [right, 0.0, -1.0])[0], 1.0);
+        assert_close(to_ndc(matrix, [0.0, 0.0, -1.0])[1], 0.0);
+    }
+    #[test]
+    fn column_major_is_transposed() {
+        let row_major =
+            frustum_90().projection_matrix(options(Some(100.0), DepthRange::ZeroToOne, false));
+        let column_major = frustum_90().projection_matrix(ProjectionOptions {
+            layout: MatrixLayout::ColumnMajor,
+            ..options(Some(100.0), DepthRange::ZeroToOne, false)
+        });
+        assert_eq!(row_major[14], -1.0);
+        assert_eq!(column_major[11], -1.0);
+        for row in 0..4 {
+            for column in 0..4 {
+                assert_eq!(row_major[row * 4 + column], column_major[column * 4 + row]);
+            }
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use crate::math;
+use uom::si::{angle::radian, f64::Angle};
+
+/// The way in which the directions in the 3D world are mapped onto the screen by the game.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProjectionModel {
+    /// The usual perspective projection, which keeps straight lines straight but stretches objects near the edges of wide FOVs. This is the only projection which is exactly portal-like over the whole screen.
+    Rectilinear,
+    /// A projection onto a vertical cylinder around the camera, which spreads horizontal angles evenly across the screen while keeping vertical lines straight.
+    Cylindrical,
+    /// The Panini projection, also known as the Vedutismo projection, which keeps vertical lines and lines going through the center of the screen straight while compressing the edges.
+    Panini {
+        /// The distance from the center of the cylinder onto which the scene is projected to the point from which the cylinder is viewed, in radii of the cylinder. Zero is the same as rectilinear, and larger values compress the edges more.
+        distance: f64,
+    },
+    /// A fisheye projection, which maps the angle from the center of the screen to a distance from the center of the screen using the specified mapping.
+    Fisheye(FisheyeMapping),
+}
+/// The way in which a [fisheye projection] maps the angle from the center of the screen to the distance from the center of the screen.
+///
+/// [fisheye projection]: enum.ProjectionModel.html#variant.Fisheye " "
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FisheyeMapping {
+    /// The distance is proportional to the angle.
+    Equidistant,
+    /// The distance is proportional to the chord of the angle, which preserves the area of objects.
+    Equisolid,
+    /// The distance is proportional to the tangent of half of the angle, which preserves the shape of small objects.
+    Stereographic,
+}
+
+/// The FOV values which need to be given to a game using a [projection model] for the left and right edges of the screen to show the directions in which they are actually seen from the eye.
+///
+/// [projection model]: enum.ProjectionModel.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectionFov {
+    /// The angle between the directions shown at the left and right edges of the screen, along the center row. This is the same as the rectilinear portal-like FOV for all projection models.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "horizontal_rad", with = "crate::serde_units::radians")
+    )]
+    pub horizontal: Angle,
+    /// The angle between the directions shown at the top and bottom edges of the screen, along the center column.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vertical_rad", with = "crate::serde_units::radians")
+    )]
+    pub vertical: Angle,
+}
+
+impl ProjectionModel {
+    /// Returns the horizontal position on the screen at which the given angle to the right of the center is shown along the center row, in focal lengths, i.e. as a fraction of the distance between the camera and an imaginary screen which shows the center of the image at the scale of the 3D world.
+    pub fn horizontal_position(self, angle: Angle) -> f64 {
+        match self {
+            Self::Rectilinear => math::tan(angle),
+            Self::Cylindrical => angle.get::<radian>(),
+            // The point is projected onto the cylinder along the direction, and then from the
+            // cylinder to a plane along the line going through the point at the given distance
+            // behind the center of the cylinder
+            Self::Panini { distance } => {
+                (distance + 1.0) * math::sin(angle) / (distance + math::cos(angle))
+            }
+            Self::Fisheye(mapping) => mapping.radial_position(angle),
+        }
+    }
+    /// Returns the vertical position on the screen at which the given angle above the center is shown along the center column, in focal lengths, like with [`horizontal_position`].
+    ///
+    /// [`horizontal_position`]: #method.horizontal_position " "
+    pub fn vertical_position(self, angle: Angle) -> f64 {
+        match self {
+            // The cylinder is vertical, so vertical lines are projected the same way as with the
+            // rectilinear projection
+            Self::Rectilinear | Self::Cylindrical | Self::Panini { .. } => math::tan(angle),
+            Self::Fisheye(mapping) => mapping.radial_position(angle),
+        }
+    }
+    /// Returns the angle above the center which is shown at the given vertical position on the screen along the center column, in focal lengths. This is the inverse of [`vertical_position`].
+    ///
+    /// [`vertical_position`]: #method.vertical_position " "
+    pub fn vertical_angle(self, position: f64) -> Angle {
+        match self {
+            Self::Rectilinear | Self::Cylindrical | Self::Panini { .. } => math::atan(position),
+            Self::Fisheye(mapping) => mapping.radial_angle(position),
+        }
+    }
+    /// Calculates the FOV values which make the left and right edges of a screen show the directions in which they are seen from the eye, given the angle between those directions and the aspect ratio of the screen.
+    pub fn fov(self, horizontal_fov: Angle, aspect: f64) -> ProjectionFov {
+        // The scale of the image is chosen such that the edge of the screen is one half-width
+        // away from the center, and the top edge is the half-width divided by the aspect ratio
+        let half_width = self.horizontal_position(horizontal_fov / 2.0);
+        let half_height = half_width / aspect;
+        ProjectionFov {
+            horizontal: horizontal_fov,
+            vertical: self.vertical_angle(half_height) * 2.0,
+        }
+    }
+}
+impl FisheyeMapping {
+    /// Returns the distance from the center of the screen at which the given angle from the center is shown, in focal lengths.
+    fn radial_position(self, angle: Angle) -> f64 {
+        match self {
+            Self::Equidistant => angle.get::<radian>(),
+            Self::Equisolid => 2.0 * math::sin(angle / 2.0),
+            Self::Stereographic => 2.0 * math::tan(angle / 2.0),
+        }
+    }
+    /// The inverse of `radial_position`.
+    fn radial_angle(self, position: f64) -> Angle {
+        match self {
+            Self::Equidistant => Angle::new::<radian>(position),
+            Self::Equisolid => math::asin(position / 2.0) * 2.0,
+            Self::Stereographic => math::atan(position / 2.0) * 2.0,
+        }
+    }
+}
+
+/// Calculates the [Panini distance] which makes the left and right edges of a screen show the directions in which they are seen from the eye, given the angle between those directions, the vertical FOV which the game is set to along the center column and the aspect ratio of the screen.
+///
+/// This is useful for games which only allow setting the vertical FOV, or limit it to a value lower than needed for a portal-like rectilinear projection. Returns `None` if there is no such distance, i.e. if the vertical FOV is larger than that of the rectilinear projection (which would require a negative distance) or so small that no amount of compression is enough.
+///
+/// Since the Panini projection keeps vertical lines straight, the left and right edges show the right directions along their whole height, not just at the center row.
+///
+/// [Panini distance]: enum.ProjectionModel.html#variant.Panini " "
+pub fn panini_distance_for_vertical_fov(
+    horizontal_fov: Angle,
+    vertical_fov: Angle,
+    aspect: f64,
+) -> Option<f64> {
+    // With the half-width of the screen taken as 1, the focal length is the half-height divided by
+    // the tangent of half the vertical FOV, and the position of the edge in focal lengths is thus
+    // the inverse of it. Solving the Panini formula for the distance gives the rest.
+    let scale = (1.0 / aspect) / math::tan(vertical_fov / 2.0);
+    let half_fov = horizontal_fov / 2.0;
+    let (sin, cos) = (math::sin(half_fov), math::cos(half_fov));
+    let distance = (cos - scale * sin) / (scale * sin - 1.0);
+    if distance.is_finite() && distance >= 0.0 {
+        Some(distance)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AspectRatio, MonitorConfiguration, MonitorDimensions};
+    use uom::si::{
+        angle::degree,
+        f64::Length,
+        length::{centimeter, inch},
+    };
+
+    const MODELS: [ProjectionModel; 6] = [
+        ProjectionModel::Rectilinear,
+        ProjectionModel::Cylindrical,
+        ProjectionModel::Panini { distance: 1.0 },
+        ProjectionModel::Fisheye(FisheyeMapping::Equidistant),
+        ProjectionModel::Fisheye(FisheyeMapping::Equisolid),
+        ProjectionModel::Fisheye(FisheyeMapping::Stereographic),
+    ];
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            math::abs(actual - expected) < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+    fn monitor() -> MonitorConfiguration {
+        MonitorConfiguration {
+            dimensions: MonitorDimensions::DiagonalAndAspect {
+                diagonal: Length::new::<inch>(27.0),
+                aspect: AspectRatio::new(16, 9).unwrap(),
+            },
+            distance: Length::new::<centimeter>(50.0),
+            eye_offset: None,
+            curvature: None,
+            resolution: None,
+        }
+    }
+
+    #[test]
+    fn rectilinear_matches_the_portal_fov() {
+        let monitor = monitor();
+        let fov = monitor.projection_fov(ProjectionModel::Rectilinear);
+        assert_close(
+            fov.horizontal.get::<radian>(),
+            monitor.fov().get::<radian>(),
+        );
+        assert_close(
+            fov.vertical.get::<radian>(),
+            monitor.vertical_fov().get::<radian>(),
+        );
+    }
+    #[test]
+    fn edges_show_the_physical_edge_angle() {
+        let monitor = monitor();
+        let aspect = 16.0 / 9.0;
+        for &model in &MODELS {
+            let fov = monitor.projection_fov(model);
+            // The horizontal FOV is the angle at which the left and right edges are seen
+            assert_close(
+                fov.horizontal.get::<radian>(),
+                monitor.fov().get::<radian>(),
+            );
+            // and the vertical one puts the top edge at the height of the screen with the left
+            // and right edges at its width
+            let half_width = model.horizontal_position(fov.horizontal / 2.0);
+            let half_height = model.vertical_position(fov.vertical / 2.0);
+            assert_close(half_width / half_height, aspect);
+            assert_close(
+                model.vertical_angle(half_height).get::<radian>(),
+                (fov.vertical / 2.0).get::<radian>(),
+            );
+        }
+        // A cylinder spreads the horizontal angle evenly, so the edges are at the angle itself
+        let fov = ProjectionModel::Cylindrical.fov(Angle::new::<degree>(90.0), aspect);
+        let half_height = Angle::new::<degree>(45.0).get::<radian>() / aspect;
+        assert_close(
+            fov.vertical.get::<radian>(),
+            (math::atan(half_height) * 2.0).get::<radian>(),
+        );
+        // and so does an equidistant fisheye, along both axes
+        let fisheye = ProjectionModel::Fisheye(FisheyeMapping::Equidistant);
+        let fov = fisheye.fov(Angle::new::<degree>(90.0), aspect);
+        assert_close(fov.vertical.get::<degree>(), 90.0 / aspect);
+    }
+    #[test]
+    fn panini_distance_gives_the_vertical_fov() {
+        let monitor = monitor();
+        let horizontal = monitor.fov();
+        let aspect = 16.0 / 9.0;
+        let vertical = Angle::new::<degree>(35.0);
+        let distance = panini_distance_for_vertical_fov(horizontal, vertical, aspect).unwrap();
+        assert!(distance > 0.0);
+        let fov = ProjectionModel::Panini { distance }.fov(horizontal, aspect);
+        assert_close(fov.vertical.get::<radian>(), vertical.get::<radian>());
+        assert_close(
+            monitor.panini_distance_for_vertical_fov(vertical).unwrap(),
+            distance,
+        );
+        // Closer to the rectilinear vertical FOV needs less compression
+        let closer = monitor.vertical_fov() - Angle::new::<degree>(0.5);
+        let less_compressed = panini_distance_for_vertical_fov(horizontal, closer, aspect);
+        assert!(less_compressed.unwrap() < distance);
+        // and anything wider would need a negative distance
+        let wider = monitor.vertical_fov() + Angle::new::<degree>(5.0);
+        assert_eq!(
+            panini_distance_for_vertical_fov(horizontal, wider, aspect),
+            None
+        );
+        // while even an infinite distance can't squeeze the edges below the sine of their angle
+        let narrower = Angle::new::<degree>(30.0);
+        assert_eq!(
+            panini_distance_for_vertical_fov(horizontal, narrower, aspect),
+            None
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use core::fmt::{self, Display, Formatter};
+use uom::si::{
+    f64::Length,
+    length::{centimeter, foot, inch, meter, millimeter, yard},
+};
+
+/// A unit in which lengths can be [parsed] and [formatted].
+///
+/// [parsed]: fn.parse_length.html " "
+/// [formatted]: #method.format " "
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LengthUnit {
+    /// Millimeters, written as `mm`.
+    Millimeters,
+    /// Centimeters, written as `cm`.
+    Centimeters,
+    /// Meters, written as `m`.
+    Meters,
+    /// Inches, written as `in` or `"`.
+    Inches,
+    /// Feet, written as `ft` or `'`.
+    Feet,
+    /// Yards, written as `yd`.
+    Yards,
+}
+impl LengthUnit {
+    /// All units, metric ones first, each group from the smallest to the largest.
+    pub const ALL: [Self; 6] = [
+        Self::Millimeters,
+        Self::Centimeters,
+        Self::Meters,
+        Self::Inches,
+        Self::Feet,
+        Self::Yards,
+    ];
+    /// Returns the abbreviation used when formatting lengths in the unit.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Millimeters => "mm",
+            Self::Centimeters => "cm",
+            Self::Meters => "m",
+            Self::Inches => "in",
+            Self::Feet => "ft",
+            Self::Yards => "yd",
+        }
+    }
+    /// Finds the unit by its abbreviation or its full name in singular or plural, ignoring case. The prime and double prime marks, as well as the ASCII quotes commonly typed in their place, stand for feet and inches respectively.
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|unit| {
+            unit.spellings()
+                .iter()
+                .any(|spelling| spelling.eq_ignore_ascii_case(symbol))
+        })
+    }
+    /// Creates a length from a value in the unit.
+    pub fn length(self, value: f64) -> Length {
+        match self {
+            Self::Millimeters => Length::new::<millimeter>(value),
+            Self::Centimeters => Length::new::<centimeter>(value),
+            Self::Meters => Length::new::<meter>(value),
+            Self::Inches => Length::new::<inch>(value),
+            Self::Feet => Length::new::<foot>(value),
+            Self::Yards => Length::new::<yard>(value),
+        }
+    }
+    /// Returns the value of a length in the unit.
+    pub fn value_of(self, length: Length) -> f64 {
+        match self {
+            Self::Millimeters => length.get::<millimeter>(),
+            Self::Centimeters => length.get::<centimeter>(),
+            Self::Meters => length.get::<meter>(),
+            Self::Inches => length.get::<inch>(),
+            Self::Feet => length.get::<foot>(),
+            Self::Yards => length.get::<yard>(),
+        }
+    }
+    /// Returns a value which displays the length in the unit with the given number of digits after the decimal point, followed by the [symbol] of the unit, such as `68.6 cm`.
+    ///
+    /// [symbol]: #method.symbol " "
+    pub fn format(self, length: Length, precision: usize) -> FormattedLength {
+        FormattedLength {
+            length,
+            unit: self,
+            precision,
+        }
+    }
+    fn spellings(self) -> &'static [&'static str] {
+        match self {
+            Self::Millimeters => &[
+                "mm",
+                "millimeter",
+                "millimeters",
+                "millimetre",
+                "millimetres",
+            ],
+            Self::Centimeters => &[
+                "cm",
+                "centimeter",
+                "centimeters",
+                "centimetre",
+                "centimetres",
+            ],
+            Self::Meters => &["m", "meter", "meters", "metre", "metres"],
+            Self::Inches => &["in", "inch", "inches", "\"", "″", "”"],
+            Self::Feet => &["ft", "foot", "feet", "'", "′", "’"],
+            Self::Yards => &["yd", "yds", "yard", "yards"],
+        }
+    }
+}
+
+/// A length along with the unit and precision to display it with, created by [`LengthUnit::format`].
+///
+/// [`LengthUnit::format`]: enum.LengthUnit.html#method.format " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormattedLength {
+    /// The length to display.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "length_m", with = "crate::serde_units::meters")
+    )]
+    pub length: Length,
+    /// The unit to display the length in.
+    pub unit: LengthUnit,
+    /// The number of digits after the decimal point.
+    pub precision: usize,
+}
+impl Display for FormattedLength {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.*} {}",
+            self.precision,
+            self.unit.value_of(self.length),
+            self.unit.symbol(),
+        )
+    }
+}
+
+/// The reason why a string couldn't be [parsed into a length].
+///
+/// [parsed into a length]: fn.parse_length.html " "
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParseLengthError {
+    /// The string is empty or only contains whitespace.
+    Empty,
+    /// A number is missing or malformed.
+    InvalidNumber,
+    /// A unit is not one of those known to [`LengthUnit::from_symbol`].
+    ///
+    /// [`LengthUnit::from_symbol`]: enum.LengthUnit.html#method.from_symbol " "
+    UnknownUnit,
+    /// A number has no unit, and either no default unit was given or the number follows another term which isn't a number of feet.
+    MissingUnit,
+}
+impl Display for ParseLengthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "the length is empty",
+            Self::InvalidNumber => "the length contains an invalid number",
+            Self::UnknownUnit => "the length contains an unknown unit",
+            Self::MissingUnit => "the length is missing a unit",
+        })
+    }
+}
+
+/// Parses a length written as a number followed by a unit, such as `27in`, `68.6 cm` or `0.7m`.
+///
+/// Several such terms are added together, which allows for lengths like `5' 3"` or `1m 20cm`. A minus sign before the first term negates the whole length. A number without a unit is taken to be in the given default unit if it's the only or the first term, or in inches if it follows a number of feet as in `5' 3`; anywhere else, it's rejected as missing its unit. See [`LengthUnit::from_symbol`] for the accepted ways to write the units.
+///
+/// [`LengthUnit::from_symbol`]: enum.LengthUnit.html#method.from_symbol " "
+pub fn parse_length(s: &str, default_unit: Option<LengthUnit>) -> Result<Length, ParseLengthError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseLengthError::Empty);
+    }
+    let (sign, mut rest) = match s.strip_prefix('-') {
+        Some(rest) => (-1.0, rest.trim_start()),
+        None => (1.0, s),
+    };
+    if rest.is_empty() {
+        return Err(ParseLengthError::InvalidNumber);
+    }
+    let mut total = Length::default();
+    let mut previous_unit = None;
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let value = rest[..number_end]
+            .parse::<f64>()
+            .map_err(|_| ParseLengthError::InvalidNumber)?;
+        rest = rest[number_end..].trim_start();
+        // Quotes are units on their own and can be directly followed by the next number, while
+        // words go on until the next space or number
+        let unit_end = match rest.chars().next() {
+            Some(c) if !c.is_alphabetic() && !c.is_ascii_digit() => c.len_utf8(),
+            _ => rest
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(rest.len()),
+        };
+        let unit = if unit_end == 0 {
+            match previous_unit {
+                Some(LengthUnit::Feet) => LengthUnit::Inches,
+                None => default_unit.ok_or(ParseLengthError::MissingUnit)?,
+                // Adding up plain numbers, as in `27 3`, is more likely a typo than intended
+                Some(_) => return Err(ParseLengthError::MissingUnit),
+            }
+        } else {
+            LengthUnit::from_symbol(&rest[..unit_end]).ok_or(ParseLengthError::UnknownUnit)?
+        };
+        rest = rest[unit_end..].trim_start();
+        total += unit.length(value);
+        previous_unit = Some(unit);
+    }
+    Ok(total * sign)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::string::ToString;
+
+    fn assert_length(src: &str, default_unit: Option<LengthUnit>, unit: LengthUnit, value: f64) {
+        let length = parse_length(src, default_unit).unwrap();
+        let expected = unit.length(value);
+        assert!(
+            (length - expected).abs() < Length::new::<millimeter>(1e-6),
+            "{:?} read as {:?} instead of {:?}",
+            src,
+            length,
+            expected,
+        );
+    }
+
+    #[test]
+    fn suffixed_lengths() {
+        assert_length("27in", None, LengthUnit::Inches, 27.0);
+        assert_length("68.6 cm", None, LengthUnit::Centimeters, 68.6);
+        assert_length("0.7m", None, LengthUnit::Meters, 0.7);
+        assert_length("5' 3\"", None, LengthUnit::Inches, 63.0);
+        assert_length("600mm", None, LengthUnit::Millimeters, 600.0);
+        assert_length("2 yd", None, LengthUnit::Feet, 6.0);
+        assert_length("1.5 yards", None, LengthUnit::Inches, 54.0);
+        assert_length("1m 20cm", None, LengthUnit::Centimeters, 120.0);
+        assert_length("-3 ft", None, LengthUnit::Inches, -36.0);
+    }
+    #[test]
+    fn plain_numbers() {
+        assert_length("27", Some(LengthUnit::Inches), LengthUnit::Inches, 27.0);
+        assert_length(
+            " 68.6 ",
+            Some(LengthUnit::Centimeters),
+            LengthUnit::Centimeters,
+            68.6,
+        );
+        // Suffixes override the default unit
+        assert_length(
+            "27in",
+            Some(LengthUnit::Centimeters),
+            LengthUnit::Inches,
+            27.0,
+        );
+        // A plain number after feet is in inches regardless of the default unit
+        assert_length("5' 3", Some(LengthUnit::Meters), LengthUnit::Inches, 63.0);
+        assert_length("5ft 3", None, LengthUnit::Inches, 63.0);
+    }
+    #[test]
+    fn errors() {
+        assert_eq!(parse_length("", None), Err(ParseLengthError::Empty));
+        assert_eq!(parse_length("   ", None), Err(ParseLengthError::Empty));
+        assert_eq!(
+            parse_length("-", None),
+            Err(ParseLengthError::InvalidNumber)
+        );
+        assert_eq!(
+            parse_length("wide", Some(LengthUnit::Inches)),
+            Err(ParseLengthError::InvalidNumber)
+        );
+        assert_eq!(
+            parse_length("1.2.3 cm", None),
+            Err(ParseLengthError::InvalidNumber)
+        );
+        assert_eq!(
+            parse_length("27 parsecs", Some(LengthUnit::Inches)),
+            Err(ParseLengthError::UnknownUnit)
+        );
+        assert_eq!(parse_length("27", None), Err(ParseLengthError::MissingUnit));
+    }
+    #[test]
+    fn plain_number_after_non_feet_is_rejected() {
+        assert_eq!(
+            parse_length("27 3", Some(LengthUnit::Inches)),
+            Err(ParseLengthError::MissingUnit)
+        );
+        assert_eq!(
+            parse_length("1m 20", Some(LengthUnit::Centimeters)),
+            Err(ParseLengthError::MissingUnit)
+        );
+        assert_eq!(
+            parse_length("5' 3 2", None),
+            Err(ParseLengthError::MissingUnit)
+        );
+    }
+    #[test]
+    fn unit_symbols() {
+        for &unit in &LengthUnit::ALL {
+            assert_eq!(LengthUnit::from_symbol(unit.symbol()), Some(unit));
+        }
+        assert_eq!(LengthUnit::from_symbol("Inches"), Some(LengthUnit::Inches));
+        assert_eq!(LengthUnit::from_symbol("METRES"), Some(LengthUnit::Meters));
+        assert_eq!(LengthUnit::from_symbol("′"), Some(LengthUnit::Feet));
+        assert_eq!(LengthUnit::from_symbol("\""), Some(LengthUnit::Inches));
+        assert_eq!(LengthUnit::from_symbol("parsec"), None);
+        assert_eq!(LengthUnit::from_symbol(""), None);
+    }
+    #[test]
+    fn formatting() {
+        let length = Length::new::<centimeter>(68.58);
+        assert_eq!(
+            LengthUnit::Centimeters.format(length, 1).to_string(),
+            "68.6 cm"
+        );
+        assert_eq!(LengthUnit::Inches.format(length, 0).to_string(), "27 in");
+        assert_eq!(LengthUnit::Meters.format(length, 3).to_string(), "0.686 m");
+        assert_eq!(
+            LengthUnit::Millimeters.format(length, 2).to_string(),
+            "685.80 mm"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{convert_fov, math, FovAxis, MonitorConfiguration};
+use uom::si::{
+    angle::revolution,
+    f64::{Angle, Length},
+    length::inch,
+    ratio::ratio,
+};
+
+/// A method of converting a mouse sensitivity between two fields of view, deciding which mouse movement should turn the camera the same way before and after the change.
+///
+/// No method keeps all movements the same, since the image is distorted differently at different FOVs, so the choice depends on which movements matter most to the player.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SensitivityMatch {
+    /// The same mouse movement moves the camera to a point which was at the same position on the screen, at the given fraction of the distance from the center of the screen to its left or right edge. This is the "monitor distance" method, usually given in percent.
+    ///
+    /// 0% keeps the speed of small movements around the crosshair the same, which is what aiming relies on, and is the default of most sensitivity calculators. 100% matches flicks to the edges of the screen and is the same as matching the horizontal FOVs linearly.
+    MonitorDistance {
+        /// The fraction of the distance from the center of the screen to its edge at which the positions are matched.
+        fraction: f64,
+    },
+    /// The ratio of the sensitivities is the ratio of the vertical FOVs, which keeps the mouse movement for turning by the vertical FOV the same.
+    Vertical,
+    /// The sensitivity stays the same, which keeps the mouse movement for a full turn the same regardless of the FOV.
+    Rotation360,
+}
+impl SensitivityMatch {
+    /// Matching at 0% monitor distance, i.e. the speed of small movements around the crosshair.
+    pub const MONITOR_DISTANCE_0: Self = Self::MonitorDistance { fraction: 0.0 };
+    /// Matching at 100% monitor distance, i.e. flicks to the edges of the screen.
+    pub const MONITOR_DISTANCE_100: Self = Self::MonitorDistance { fraction: 1.0 };
+    /// Calculates the factor by which the sensitivity needs to be multiplied when changing the horizontal FOV of a screen with the given aspect ratio (`width / height`) from one value to another.
+    pub fn sensitivity_ratio(self, from_fov: Angle, to_fov: Angle, aspect: f64) -> f64 {
+        match self {
+            Self::MonitorDistance { fraction } => {
+                // The point at the fraction of the half-width is the same fraction of the tangent
+                // of the half-FOV away from the view direction, and the camera has to turn by its
+                // arctangent to face it. At 0%, the arctangents are indistinguishable from the
+                // tangents, and their ratio approaches the ratio of the tangents.
+                let [from_tangent, to_tangent] =
+                    [math::tan(from_fov / 2.0), math::tan(to_fov / 2.0)];
+                if fraction == 0.0 {
+                    to_tangent / from_tangent
+                } else {
+                    (math::atan(to_tangent * fraction) / math::atan(from_tangent * fraction))
+                        .get::<ratio>()
+                }
+            }
+            Self::Vertical => {
+                let vertical =
+                    |fov| convert_fov(fov, FovAxis::Horizontal, FovAxis::Vertical, aspect);
+                (vertical(to_fov) / vertical(from_fov)).get::<ratio>()
+            }
+            Self::Rotation360 => 1.0,
+        }
+    }
+    /// Converts a sensitivity for one horizontal FOV of a screen with the given aspect ratio (`width / height`) into the sensitivity for another one.
+    pub fn convert(self, sensitivity: f64, from_fov: Angle, to_fov: Angle, aspect: f64) -> f64 {
+        sensitivity * self.sensitivity_ratio(from_fov, to_fov, aspect)
+    }
+}
+
+/// Calculates how far the mouse needs to be moved to turn the camera around once, with the given in-game sensitivity, yaw and mouse DPI. Taken in centimeters, this is the commonly used cm/360 measure.
+///
+/// The yaw is how much the camera turns per count reported by the mouse at a sensitivity of 1, which is a constant of the game or engine, such as 0.022° for Source and Quake engine games. The DPI (strictly speaking, CPI) is the number of counts which the mouse reports per inch of movement.
+pub fn distance_per_360(sensitivity: f64, yaw: Angle, dpi: f64) -> Length {
+    let counts = (Angle::new::<revolution>(1.0) / (yaw * sensitivity)).get::<ratio>();
+    Length::new::<inch>(counts / dpi)
+}
+
+impl MonitorConfiguration {
+    /// Converts a sensitivity used with the given horizontal FOV into the sensitivity for the [portal-like FOV], using the given matching method.
+    ///
+    /// [portal-like FOV]: #method.fov " "
+    pub fn portal_sensitivity(
+        self,
+        sensitivity: f64,
+        previous_fov: Angle,
+        method: SensitivityMatch,
+    ) -> f64 {
+        method.convert(
+            sensitivity,
+            previous_fov,
+            self.fov(),
+            self.dimensions.aspect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MonitorDimensions;
+    use uom::si::{
+        angle::degree,
+        length::{centimeter, millimeter},
+    };
+
+    fn degrees(value: f64) -> Angle {
+        Angle::new::<degree>(value)
+    }
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            math::abs(actual - expected) < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn monitor_distance_0_is_the_tangent_ratio() {
+        let [from, to] = [degrees(90.0), degrees(103.0)];
+        let tangent_ratio = math::tan(to / 2.0) / math::tan(from / 2.0);
+        assert_close(
+            SensitivityMatch::MONITOR_DISTANCE_0.sensitivity_ratio(from, to, 16.0 / 9.0),
+            tangent_ratio,
+        );
+        // Matching close to the crosshair approaches the same ratio
+        let close = SensitivityMatch::MonitorDistance { fraction: 1e-6 };
+        assert!(math::abs(close.sensitivity_ratio(from, to, 16.0 / 9.0) - tangent_ratio) < 1e-9);
+    }
+    #[test]
+    fn monitor_distance_100_is_linear() {
+        let [from, to] = [degrees(90.0), degrees(103.0)];
+        assert_close(
+            SensitivityMatch::MONITOR_DISTANCE_100.convert(2.0, from, to, 16.0 / 9.0),
+            2.0 * 103.0 / 90.0,
+        );
+    }
+    #[test]
+    fn vertical_is_the_vertical_fov_ratio() {
+        let [from, to] = [degrees(90.0), degrees(103.0)];
+        let aspect = 16.0 / 9.0;
+        let vertical = |fov| convert_fov(fov, FovAxis::Horizontal, FovAxis::Vertical, aspect);
+        assert_close(
+            SensitivityMatch::Vertical.sensitivity_ratio(from, to, aspect),
+            (vertical(to) / vertical(from)).get::<ratio>(),
+        );
+    }
+    #[test]
+    fn rotation_360_keeps_the_distance() {
+        let [from, to] = [degrees(90.0), degrees(60.0)];
+        let sensitivity = SensitivityMatch::Rotation360.convert(2.5, from, to, 16.0 / 9.0);
+        assert_eq!(sensitivity, 2.5);
+        assert_eq!(
+            distance_per_360(sensitivity, degrees(0.022), 800.0),
+            distance_per_360(2.5, degrees(0.022), 800.0)
+        );
+    }
+    #[test]
+    fn centimeters_per_360() {
+        // 360° / 0.022° per count / 800 counts per inch
+        let distance = distance_per_360(1.0, degrees(0.022), 800.0);
+        assert!(math::abs(distance.get::<centimeter>() - 51.95) < 0.01);
+        assert_close(distance.get::<millimeter>(), 360.0 / 0.022 / 800.0 * 25.4);
+        // Doubling the sensitivity or the DPI halves the distance
+        assert_close(
+            distance_per_360(2.0, degrees(0.022), 800.0).get::<millimeter>() * 2.0,
+            distance.get::<millimeter>(),
+        );
+        assert_close(
+            distance_per_360(1.0, degrees(0.022), 1600.0).get::<millimeter>() * 2.0,
+            distance.get::<millimeter>(),
+        );
+    }
+    #[test]
+    fn portal_sensitivity_targets_the_portal_fov() {
+        let monitor = MonitorConfiguration {
+            dimensions: MonitorDimensions::WidthAndHeight {
+                width: Length::new::<centimeter>(60.0),
+                height: Length::new::<centimeter>(33.75),
+            },
+            distance: Length::new::<centimeter>(70.0),
+            eye_offset: None,
+            curvature: None,
+            resolution: None,
+        };
+        let method = SensitivityMatch::MONITOR_DISTANCE_0;
+        assert_close(
+            monitor.portal_sensitivity(1.5, degrees(103.0), method),
+            method.convert(1.5, degrees(103.0), monitor.fov(), 16.0 / 9.0),
+        );
+        // The portal-like FOV of this setup is narrower, so the sensitivity goes down
+        assert!(monitor.portal_sensitivity(1.5, degrees(103.0), method) < 1.5);
+    }
+}
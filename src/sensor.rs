@@ -0,0 +1,174 @@
+use crate::{math, FovAxis, MonitorConfiguration};
+use uom::si::{
+    f64::{Angle, Length},
+    length::millimeter,
+    ratio::ratio,
+};
+
+/// The size of the sensor of a physical camera, which, along with the focal length, determines the field of view. Cameras in Blender, Unreal Engine's CineCamera and Unity's Physical Camera are set up this way.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SensorSize {
+    /// A 36×24 mm full-frame sensor, the size of a frame of 35 mm film, which is what "35 mm equivalent" focal lengths refer to.
+    FullFrame,
+    /// A 24.89×18.66 mm Super 35 sensor, the most common size in digital cinema cameras.
+    Super35,
+    /// A 23.6×15.6 mm APS-C sensor, as used by most crop-sensor cameras. Canon's APS-C sensors are slightly smaller, at 22.3×14.9 mm.
+    ApsC,
+    /// A sensor of any other size.
+    Custom {
+        /// The width of the sensor.
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "width_m", with = "crate::serde_units::meters")
+        )]
+        width: Length,
+        /// The height of the sensor.
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "height_m", with = "crate::serde_units::meters")
+        )]
+        height: Length,
+    },
+}
+impl SensorSize {
+    /// All sensor sizes except for custom ones, from the largest to the smallest.
+    pub const PRESETS: [Self; 3] = [Self::FullFrame, Self::Super35, Self::ApsC];
+    /// Returns the width and height of the sensor.
+    pub fn width_and_height(self) -> [Length; 2] {
+        let millimeters = |width, height| {
+            [
+                Length::new::<millimeter>(width),
+                Length::new::<millimeter>(height),
+            ]
+        };
+        match self {
+            Self::FullFrame => millimeters(36.0, 24.0),
+            Self::Super35 => millimeters(24.89, 18.66),
+            Self::ApsC => millimeters(23.6, 15.6),
+            Self::Custom { width, height } => [width, height],
+        }
+    }
+    /// Returns the size of the sensor along the specified axis, i.e. the width, height or diagonal.
+    pub fn extent_along(self, axis: FovAxis) -> Length {
+        let [width, height] = self.width_and_height();
+        height * axis.extent_for_aspect((width / height).get::<ratio>())
+    }
+    /// Returns the crop factor of the sensor, i.e. how many times smaller its diagonal is than that of a full-frame sensor. Multiplying a focal length by the crop factor gives the 35 mm equivalent focal length.
+    pub fn crop_factor(self) -> f64 {
+        (Self::FullFrame.extent_along(FovAxis::Diagonal) / self.extent_along(FovAxis::Diagonal))
+            .get::<ratio>()
+    }
+    /// Returns the focal length which makes a camera with this sensor capture the given field of view along the given axis of the sensor.
+    ///
+    /// Engines fit the sensor to the rendered image along one axis, usually the horizontal one, so that's the axis along which the field of view should be given.
+    pub fn focal_length_for_fov(self, fov: Angle, axis: FovAxis) -> Length {
+        // The same right-angled triangle as for the monitor, with the lens in place of the eye and
+        // the sensor in place of the screen
+        self.extent_along(axis) / 2.0 / math::tan(fov / 2.0)
+    }
+    /// Returns the field of view which a camera with this sensor captures along the given axis of the sensor with the given focal length. This is the inverse of [`focal_length_for_fov`].
+    ///
+    /// [`focal_length_for_fov`]: #method.focal_length_for_fov " "
+    pub fn fov_for_focal_length(self, focal_length: Length, axis: FovAxis) -> Angle {
+        math::atan((self.extent_along(axis) / 2.0 / focal_length).get::<ratio>()) * 2.0
+    }
+}
+
+/// Returns the 35 mm equivalent focal length for the given diagonal field of view, i.e. the focal length with which a full-frame camera captures the same field of view along its diagonal.
+///
+/// The diagonal is used since it's the only measure which doesn't depend on the aspect ratio, which is how 35 mm equivalent focal lengths are defined for cameras with sensors of other shapes.
+pub fn equivalent_focal_length(diagonal_fov: Angle) -> Length {
+    SensorSize::FullFrame.focal_length_for_fov(diagonal_fov, FovAxis::Diagonal)
+}
+
+impl MonitorConfiguration {
+    /// Returns the focal length which makes a camera with the given sensor capture the portal-like [horizontal field of view] across the width of the sensor.
+    ///
+    /// [horizontal field of view]: #method.fov " "
+    pub fn focal_length(self, sensor: SensorSize) -> Length {
+        sensor.focal_length_for_fov(self.fov(), FovAxis::Horizontal)
+    }
+    /// Returns the 35 mm [equivalent focal length] for the portal-like [diagonal field of view].
+    ///
+    /// [equivalent focal length]: fn.equivalent_focal_length.html " "
+    /// [diagonal field of view]: #method.diagonal_fov " "
+    pub fn equivalent_focal_length(self) -> Length {
+        equivalent_focal_length(self.diagonal_fov())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uom::si::angle::degree;
+
+    fn mm(value: f64) -> Length {
+        Length::new::<millimeter>(value)
+    }
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            math::abs(actual - expected) < tolerance,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn full_frame_50mm() {
+        let fov = SensorSize::FullFrame.fov_for_focal_length(mm(50.0), FovAxis::Horizontal);
+        assert_close(fov.get::<degree>(), 39.6, 0.05);
+        let vertical = SensorSize::FullFrame.fov_for_focal_length(mm(50.0), FovAxis::Vertical);
+        assert_close(vertical.get::<degree>(), 27.0, 0.05);
+        let diagonal = SensorSize::FullFrame.fov_for_focal_length(mm(50.0), FovAxis::Diagonal);
+        assert_close(diagonal.get::<degree>(), 46.8, 0.05);
+    }
+    #[test]
+    fn focal_length_round_trip() {
+        let custom = SensorSize::Custom {
+            width: mm(6.17),
+            height: mm(4.55),
+        };
+        let sensors = [
+            SensorSize::FullFrame,
+            SensorSize::Super35,
+            SensorSize::ApsC,
+            custom,
+        ];
+        let axes = [FovAxis::Horizontal, FovAxis::Vertical, FovAxis::Diagonal];
+        for &sensor in &sensors {
+            for &axis in &axes {
+                for &fov in &[10.0, 60.0, 120.0] {
+                    let focal_length = sensor.focal_length_for_fov(Angle::new::<degree>(fov), axis);
+                    let back = sensor.fov_for_focal_length(focal_length, axis);
+                    assert_close(back.get::<degree>(), fov, 1e-9);
+                }
+            }
+        }
+    }
+    #[test]
+    fn crop_factors() {
+        assert_eq!(SensorSize::FullFrame.crop_factor(), 1.0);
+        assert_close(SensorSize::ApsC.crop_factor(), 1.5, 0.05);
+        assert_close(SensorSize::Super35.crop_factor(), 1.39, 0.01);
+    }
+    #[test]
+    fn equivalent_focal_lengths() {
+        let diagonal = |sensor: SensorSize, focal_length| {
+            sensor.fov_for_focal_length(mm(focal_length), FovAxis::Diagonal)
+        };
+        assert_close(
+            equivalent_focal_length(diagonal(SensorSize::FullFrame, 50.0)).get::<millimeter>(),
+            50.0,
+            1e-9,
+        );
+        // A lens on a crop sensor is equivalent to one longer by the crop factor on full frame
+        let crop_factor = SensorSize::ApsC.crop_factor();
+        assert_close(
+            equivalent_focal_length(diagonal(SensorSize::ApsC, 33.0)).get::<millimeter>(),
+            33.0 * crop_factor,
+            1e-9,
+        );
+    }
+}
@@ -0,0 +1,97 @@
+// Adapters for `#[serde(with = "...")]` which store quantities as plain numbers in a fixed unit, so
+// that the unit can be spelled out in the name of the field. The units are the ones which uom
+// stores the quantities in internally, which makes the round trip lossless.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use uom::si::{angle::radian, length::meter};
+
+macro_rules! unit_adapter {
+    ($name:ident, $storage:ident::$quantity:ident, $unit:ident) => {
+        pub(crate) mod $name {
+            use super::*;
+            pub(crate) fn serialize<S: Serializer>(
+                value: &uom::si::$storage::$quantity,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                value.get::<$unit>().serialize(serializer)
+            }
+            pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<uom::si::$storage::$quantity, D::Error> {
+                $storage::deserialize(deserializer).map(uom::si::$storage::$quantity::new::<$unit>)
+            }
+        }
+    };
+}
+
+unit_adapter!(meters, f64::Length, meter);
+unit_adapter!(radians, f64::Angle, radian);
+unit_adapter!(meters_f32, f32::Length, meter);
+unit_adapter!(radians_f32, f32::Angle, radian);
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use crate::{AspectRatio, MonitorDimensions, ValidationWarning};
+    use std::string::String;
+    use uom::si::{
+        f64::{Angle, Length},
+        length::centimeter,
+    };
+
+    fn round_trip(dimensions: MonitorDimensions) -> (String, MonitorDimensions) {
+        let json = serde_json::to_string(&dimensions).unwrap();
+        let back = serde_json::from_str(&json).unwrap();
+        (json, back)
+    }
+
+    #[test]
+    fn width_and_height_round_trip() {
+        let dimensions = MonitorDimensions::WidthAndHeight {
+            width: Length::new::<centimeter>(59.8),
+            height: Length::new::<centimeter>(33.6),
+        };
+        let (json, back) = round_trip(dimensions);
+        assert!(json.contains("\"width_m\"") && json.contains("\"height_m\""));
+        match back {
+            MonitorDimensions::WidthAndHeight { width, height } => {
+                assert_eq!(width, Length::new::<centimeter>(59.8));
+                assert_eq!(height, Length::new::<centimeter>(33.6));
+            }
+            MonitorDimensions::DiagonalAndAspect { .. } => panic!("variant changed: {}", json),
+        }
+    }
+
+    #[test]
+    fn diagonal_and_aspect_round_trip() {
+        let aspect = AspectRatio::new(64, 27).unwrap();
+        let dimensions = MonitorDimensions::DiagonalAndAspect {
+            diagonal: Length::new::<centimeter>(86.4),
+            aspect,
+        };
+        let (json, back) = round_trip(dimensions);
+        assert!(json.contains("\"diagonal_m\"") && json.contains("[64,27]"));
+        match back {
+            MonitorDimensions::DiagonalAndAspect {
+                diagonal,
+                aspect: back_aspect,
+            } => {
+                assert_eq!(diagonal, Length::new::<centimeter>(86.4));
+                assert_eq!(back_aspect, aspect);
+            }
+            MonitorDimensions::WidthAndHeight { .. } => panic!("variant changed: {}", json),
+        }
+    }
+
+    #[test]
+    fn warning_names_its_unit() {
+        let warning =
+            ValidationWarning::ExtremelyNarrowFov(Angle::new::<uom::si::angle::radian>(0.05));
+        let json = serde_json::to_string(&warning).unwrap();
+        assert_eq!(json, r#"{"ExtremelyNarrowFov_rad":0.05}"#);
+        assert_eq!(
+            serde_json::from_str::<ValidationWarning>(&json).unwrap(),
+            warning
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{math, EyeOffset, Frustum, MonitorConfiguration};
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length},
+    length::meter,
+    ratio::ratio,
+};
+
+/// The portal-like view for each eye on a stereoscopic 3D display.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StereoView {
+    /// The frustum of the camera for the left eye.
+    pub left: Frustum,
+    /// The frustum of the camera for the right eye.
+    pub right: Frustum,
+    /// The distance between the two cameras, which is the same as the interpupillary distance for the view to be at the scale of the real world. Multiply this by the number of application units per unit of length to get the separation in application units.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "separation_m", with = "crate::serde_units::meters")
+    )]
+    pub separation: Length,
+    /// The distance from the cameras to the plane on which objects have no parallax, i.e. are shown at the same position to both eyes. This is the plane of the screen.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "zero_parallax_distance_m",
+            with = "crate::serde_units::meters"
+        )
+    )]
+    pub zero_parallax_distance: Length,
+}
+
+/// The largest angular disparity which is generally considered comfortable to look at for extended periods of time.
+pub fn default_max_disparity() -> Angle {
+    Angle::new::<degree>(1.0)
+}
+
+impl MonitorConfiguration {
+    /// Calculates the portal-like view for each eye, given the distance between the pupils of the eyes.
+    ///
+    /// The eyes are placed to the left and right of the [eye offset], which thus specifies the point between the eyes. Both frusta are asymmetric even if the eye offset is zero, since neither eye is in front of the center of the screen; the cameras must use parallel view directions with an off-axis projection rather than being turned towards each other.
+    ///
+    /// [eye offset]: #structfield.eye_offset " "
+    pub fn stereo_view(self, interpupillary_distance: Length) -> StereoView {
+        let center = self.eye_offset.unwrap_or(EyeOffset {
+            horizontal: Length::default(),
+            vertical: Length::default(),
+        });
+        let eye = |side: f64| MonitorConfiguration {
+            eye_offset: Some(EyeOffset {
+                horizontal: center.horizontal + interpupillary_distance / 2.0 * side,
+                vertical: center.vertical,
+            }),
+            ..self
+        };
+        StereoView {
+            left: eye(-1.0).frustum(),
+            right: eye(1.0).frustum(),
+            separation: interpupillary_distance,
+            zero_parallax_distance: self.distance,
+        }
+    }
+    /// Calculates the horizontal distance on the screen between the images of a point for the right and left eyes, given the distance between the pupils and the distance from the eyes to the point.
+    ///
+    /// Positive values mean that the point is behind the screen, with the image for the right eye to the right of the one for the left eye, and negative values mean that it's in front of the screen. The parallax approaches the interpupillary distance as the point goes infinitely far away.
+    pub fn screen_parallax(self, interpupillary_distance: Length, depth: Length) -> Length {
+        // The lines from the eyes to the point cross the screen at points which, together with the
+        // point itself, form a triangle similar to the one between the eyes and the point
+        interpupillary_distance * ((depth - self.distance) / depth)
+    }
+    /// Calculates the angular disparity of a point, i.e. the difference between the angle at which the eyes need to converge to look at the screen and the angle at which they need to converge to look at the point, given the distance between the pupils and the distance from the eyes to the point.
+    ///
+    /// Positive values mean that the point is behind the screen. The eyes focus on the screen regardless of the depth of the point, so large disparities in either direction cause eye strain.
+    pub fn angular_disparity(self, interpupillary_distance: Length, depth: Length) -> Angle {
+        vergence(interpupillary_distance, self.distance) - vergence(interpupillary_distance, depth)
+    }
+    /// Returns whether a point at the given distance from the eyes can be looked at comfortably, i.e. whether the magnitude of its [angular disparity] doesn't exceed the given maximum, such as the [default one].
+    ///
+    /// [angular disparity]: #method.angular_disparity " "
+    /// [default one]: fn.default_max_disparity.html " "
+    pub fn is_disparity_comfortable(
+        self,
+        interpupillary_distance: Length,
+        depth: Length,
+        max_disparity: Angle,
+    ) -> bool {
+        self.angular_disparity(interpupillary_distance, depth).abs() <= max_disparity
+    }
+    /// Calculates the nearest and farthest distances from the eyes at which points can be looked at comfortably, i.e. have an [angular disparity] not exceeding the given maximum, as `[near, far]`.
+    ///
+    /// The farthest distance is infinite if even infinitely far points are comfortable.
+    ///
+    /// [angular disparity]: #method.angular_disparity " "
+    pub fn comfortable_depth_range(
+        self,
+        interpupillary_distance: Length,
+        max_disparity: Angle,
+    ) -> [Length; 2] {
+        let screen_vergence = vergence(interpupillary_distance, self.distance);
+        // The inverse of vergence, which splits the triangle between the eyes and the point into
+        // two right-angled ones, same as fov_along does with the screen
+        let depth_for = |vergence: Angle| interpupillary_distance / 2.0 / math::tan(vergence / 2.0);
+        let near = depth_for(screen_vergence + max_disparity);
+        let far_vergence = screen_vergence - max_disparity;
+        let far = if far_vergence > Angle::default() {
+            depth_for(far_vergence)
+        } else {
+            Length::new::<meter>(f64::INFINITY)
+        };
+        [near, far]
+    }
+}
+
+/// Returns the angle between the lines from the eyes to a point straight ahead at the given distance.
+fn vergence(interpupillary_distance: Length, depth: Length) -> Angle {
+    math::atan((interpupillary_distance / 2.0 / depth).get::<ratio>()) * 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AspectRatio, MonitorDimensions};
+    use uom::si::{
+        angle::radian,
+        length::{centimeter, inch, millimeter},
+    };
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            math::abs(actual - expected) < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+    fn monitor() -> MonitorConfiguration {
+        MonitorConfiguration {
+            dimensions: MonitorDimensions::DiagonalAndAspect {
+                diagonal: Length::new::<inch>(27.0),
+                aspect: AspectRatio::new(16, 9).unwrap(),
+            },
+            distance: Length::new::<centimeter>(60.0),
+            eye_offset: None,
+            curvature: None,
+            resolution: None,
+        }
+    }
+    fn ipd() -> Length {
+        Length::new::<millimeter>(63.0)
+    }
+
+    #[test]
+    fn no_parallax_at_screen_depth() {
+        let monitor = monitor();
+        let view = monitor.stereo_view(ipd());
+        assert_eq!(view.zero_parallax_distance, monitor.distance);
+        assert_eq!(view.separation, ipd());
+        assert_close(
+            monitor
+                .screen_parallax(ipd(), monitor.distance)
+                .get::<meter>(),
+            0.0,
+        );
+        assert_close(
+            monitor
+                .angular_disparity(ipd(), monitor.distance)
+                .get::<radian>(),
+            0.0,
+        );
+        // Behind the screen the parallax is positive and approaches the IPD, in front of it it's
+        // negative
+        let far = monitor.screen_parallax(ipd(), Length::new::<meter>(1e9));
+        assert_close(far.get::<meter>(), ipd().get::<meter>());
+        assert!(
+            monitor.screen_parallax(ipd(), Length::new::<centimeter>(120.0)) > Length::default()
+        );
+        assert!(
+            monitor.screen_parallax(ipd(), Length::new::<centimeter>(30.0)) < Length::default()
+        );
+        // Halfway to the screen, the images are as far apart as the eyes, crossed
+        let half = monitor.screen_parallax(ipd(), monitor.distance / 2.0);
+        assert_close(half.get::<meter>(), -ipd().get::<meter>());
+    }
+    #[test]
+    fn frusta_are_mirrored() {
+        let view = monitor().stereo_view(ipd());
+        let [left, right] = [view.left, view.right];
+        assert_close(left.left.get::<radian>(), right.right.get::<radian>());
+        assert_close(left.right.get::<radian>(), right.left.get::<radian>());
+        assert_close(left.top.get::<radian>(), right.top.get::<radian>());
+        assert_close(left.bottom.get::<radian>(), right.bottom.get::<radian>());
+        // The left eye sees more of the screen to its right
+        assert!(left.right > left.left);
+        // Off-center, each frustum is the one of its eye on its own
+        let mut monitor = monitor();
+        monitor.eye_offset = Some(EyeOffset {
+            horizontal: Length::new::<centimeter>(5.0),
+            vertical: Length::new::<centimeter>(-3.0),
+        });
+        let view = monitor.stereo_view(ipd());
+        let eye = |horizontal: f64| {
+            MonitorConfiguration {
+                eye_offset: Some(EyeOffset {
+                    horizontal: Length::new::<millimeter>(horizontal),
+                    vertical: Length::new::<centimeter>(-3.0),
+                }),
+                ..monitor
+            }
+            .frustum()
+        };
+        let [expected_left, expected_right] = [eye(50.0 - 31.5), eye(50.0 + 31.5)];
+        assert_close(
+            view.left.left.get::<radian>(),
+            expected_left.left.get::<radian>(),
+        );
+        assert_close(
+            view.right.right.get::<radian>(),
+            expected_right.right.get::<radian>(),
+        );
+        assert_close(
+            view.right.top.get::<radian>(),
+            expected_right.top.get::<radian>(),
+        );
+    }
+    #[test]
+    fn comfort_ends_at_the_disparity_limit() {
+        let monitor = monitor();
+        let max_disparity = default_max_disparity();
+        let [near, far] = monitor.comfortable_depth_range(ipd(), max_disparity);
+        assert!(near < monitor.distance && far > monitor.distance);
+        assert_close(
+            monitor.angular_disparity(ipd(), near).get::<radian>(),
+            -max_disparity.get::<radian>(),
+        );
+        assert_close(
+            monitor.angular_disparity(ipd(), far).get::<radian>(),
+            max_disparity.get::<radian>(),
+        );
+        let step = Length::new::<millimeter>(1.0);
+        let comfortable = |depth| monitor.is_disparity_comfortable(ipd(), depth, max_disparity);
+        assert!(comfortable(monitor.distance));
+        assert!(comfortable(near + step) && comfortable(far - step));
+        assert!(!comfortable(near - step) && !comfortable(far + step));
+        // Far enough from the screen, even points at infinity are comfortable
+        let mut monitor = monitor;
+        monitor.distance = Length::new::<meter>(5.0);
+        let [_, far] = monitor.comfortable_depth_range(ipd(), max_disparity);
+        assert!(far.get::<meter>().is_infinite());
+        assert!(monitor.is_disparity_comfortable(ipd(), Length::new::<meter>(1e9), max_disparity));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::{math, EyeOffset, MonitorConfiguration, MonitorDimensions};
+use uom::si::{
+    f64::{Angle, Length},
+    length::meter,
+};
+
+/// A single screen in a [surround rig].
+///
+/// [surround rig]: struct.SurroundRig.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SurroundScreen {
+    /// The dimensions of the screen, not including the bezels.
+    pub dimensions: MonitorDimensions,
+    /// The angle by which the screen is turned towards the viewer relative to the center screen. Ignored for the center screen itself.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "yaw_rad", with = "crate::serde_units::radians")
+    )]
+    pub yaw: Angle,
+    /// The width of the bezel on each of the left and right sides of the screen.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "bezel_m", with = "crate::serde_units::meters")
+    )]
+    pub bezel: Length,
+}
+
+/// A rig of multiple screens placed side by side, each one touching the next one with its bezel, with the outer ones usually turned towards the viewer.
+///
+/// The rig is assumed to be horizontal, with the eye at the height of the centers of all screens.
+#[derive(Copy, Clone, Debug)]
+pub struct SurroundRig<'a> {
+    /// The screens of the rig, ordered from left to right.
+    pub screens: &'a [SurroundScreen],
+    /// The index of the center screen in `screens`, which is located directly in front of the eye and faces it.
+    pub center: usize,
+    /// The distance from the eye to the surface of the center screen.
+    pub distance: Length,
+}
+impl<'a> SurroundRig<'a> {
+    /// Calculates the view through one of the screens.
+    ///
+    /// # Panics
+    /// If either `index` or the index of the center screen is out of bounds.
+    pub fn screen_view(self, index: usize) -> SurroundScreenView {
+        let (left_edge, right_edge, yaw) = self.screen_geometry(index);
+        // The center of the screen is halfway between its edges...
+        let center = [
+            (left_edge[0] + right_edge[0]) / 2.0,
+            (left_edge[1] + right_edge[1]) / 2.0,
+        ];
+        // ...and the screen's own right and forward directions are rotated by its yaw
+        let (sin, cos) = (math::sin(yaw), math::cos(yaw));
+        let right = [cos, -sin];
+        let forward = [sin, cos];
+        // Projecting the vector from the center to the eye onto those directions gives us the
+        // position of the eye relative to the screen, which is all a MonitorConfiguration needs
+        let distance = center[0] * forward[0] + center[1] * forward[1];
+        let horizontal_offset = -(center[0] * right[0] + center[1] * right[1]);
+        SurroundScreenView {
+            configuration: MonitorConfiguration {
+                dimensions: self.screens[index].dimensions,
+                distance,
+                eye_offset: Some(EyeOffset {
+                    horizontal: horizontal_offset,
+                    vertical: Length::default(),
+                }),
+                curvature: None,
+                resolution: None,
+            },
+            camera_yaw: yaw,
+            left_edge: azimuth(left_edge),
+            right_edge: azimuth(right_edge),
+        }
+    }
+    /// Returns an iterator over the views through all screens, from left to right.
+    pub fn screen_views(self) -> impl Iterator<Item = SurroundScreenView> + 'a {
+        (0..self.screens.len()).map(move |i| self.screen_view(i))
+    }
+    /// Calculates the total horizontal FOV of the rig, from the left edge of the leftmost screen to the right edge of the rightmost screen, including the bezels in between.
+    ///
+    /// Returns zero if there are no screens.
+    pub fn total_fov(self) -> Angle {
+        let count = self.screens.len();
+        if count == 0 {
+            return Angle::default();
+        }
+        self.screen_view(count - 1).right_edge - self.screen_view(0).left_edge
+    }
+    /// Calculates the angle to which a side screen needs to be turned for its center to face the eye directly, given the yaws of the screens between it and the center screen.
+    ///
+    /// At this angle, the [view] through the screen has no eye offset and thus a symmetric frustum. Returns zero for the center screen.
+    ///
+    /// # Panics
+    /// If either `index` or the index of the center screen is out of bounds.
+    ///
+    /// [view]: #method.screen_view " "
+    pub fn optimal_yaw(self, index: usize) -> Angle {
+        if index == self.center {
+            return Angle::default();
+        }
+        let [hinge_x, hinge_z] = self.inner_hinge(index);
+        let screen = self.screens[index];
+        let [width, _] = screen.dimensions.width_and_height();
+        // The screen faces the eye if the line from the eye to its center is perpendicular to the
+        // screen, i.e. the projection of the line from the hinge to the eye onto the screen is
+        // exactly as long as the line from the hinge to the center of the screen. Solving that
+        // for the angle gives the angle of the line from the eye to the hinge plus an extra angle
+        // which grows with the distance between the hinge and the center of the screen.
+        let hinge_to_center = screen.bezel + width / 2.0;
+        let [hinge_x, hinge_z] = [hinge_x.get::<meter>(), hinge_z.get::<meter>()];
+        let hinge_distance = math::sqrt(hinge_x * hinge_x + hinge_z * hinge_z);
+        let hinge_angle = math::atan2(math::abs(hinge_x), hinge_z);
+        hinge_angle + math::asin(hinge_to_center.get::<meter>() / hinge_distance)
+    }
+    /// Returns the position of the point where the bezel of a side screen touches the bezel of its neighbor closer to the center screen, as `[x, z]` with the eye at the origin, X pointing right and Z pointing forward.
+    fn inner_hinge(self, index: usize) -> [Length; 2] {
+        let center = self.screens[self.center];
+        let [center_width, _] = center.dimensions.width_and_height();
+        let side = side_of(index, self.center);
+        let mut hinge = [(center_width / 2.0 + center.bezel) * side, self.distance];
+        // Walk along the screens between the center one and this one. The order doesn't matter,
+        // since all we do is add up their spans as vectors.
+        let between = if index > self.center {
+            self.center + 1..index
+        } else {
+            index + 1..self.center
+        };
+        for screen in &self.screens[between] {
+            let [width, _] = screen.dimensions.width_and_height();
+            let [dx, dz] = outward_direction(screen.yaw, side);
+            let span = screen.bezel * 2.0 + width;
+            hinge = [hinge[0] + span * dx, hinge[1] + span * dz];
+        }
+        hinge
+    }
+    /// Returns the positions of the left and right edges of the visible area of a screen in the same form as `inner_hinge`, along with the yaw of the screen, positive to the right.
+    fn screen_geometry(self, index: usize) -> ([Length; 2], [Length; 2], Angle) {
+        let screen = self.screens[index];
+        let [width, _] = screen.dimensions.width_and_height();
+        if index == self.center {
+            let left_edge = [-width / 2.0, self.distance];
+            let right_edge = [width / 2.0, self.distance];
+            return (left_edge, right_edge, Angle::default());
+        }
+        let side = side_of(index, self.center);
+        let hinge = self.inner_hinge(index);
+        let [dx, dz] = outward_direction(screen.yaw, side);
+        let at = |along: Length| [hinge[0] + along * dx, hinge[1] + along * dz];
+        let inner_edge = at(screen.bezel);
+        let outer_edge = at(screen.bezel + width);
+        if side > 0.0 {
+            (inner_edge, outer_edge, screen.yaw)
+        } else {
+            (outer_edge, inner_edge, -screen.yaw)
+        }
+    }
+}
+
+/// The view through a single screen of a [surround rig].
+///
+/// [surround rig]: struct.SurroundRig.html " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SurroundScreenView {
+    /// The configuration of the screen relative to the eye, with the distance measured perpendicular to the screen and the eye offset measured along it. The [frustum] of this configuration is the one the camera for this screen needs.
+    ///
+    /// [frustum]: struct.MonitorConfiguration.html#method.frustum " "
+    pub configuration: MonitorConfiguration,
+    /// The yaw of the camera for this screen relative to the camera for the center screen, with positive values meaning a turn to the right.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "camera_yaw_rad", with = "crate::serde_units::radians")
+    )]
+    pub camera_yaw: Angle,
+    /// The angle between the direction the eye is looking in and the left edge of the screen, with positive values to the right.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "left_edge_rad", with = "crate::serde_units::radians")
+    )]
+    pub left_edge: Angle,
+    /// The angle between the direction the eye is looking in and the right edge of the screen, with positive values to the right.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "right_edge_rad", with = "crate::serde_units::radians")
+    )]
+    pub right_edge: Angle,
+}
+impl SurroundScreenView {
+    /// Returns the horizontal FOV which the screen occupies as seen from the eye.
+    pub fn fov(self) -> Angle {
+        self.right_edge - self.left_edge
+    }
+}
+
+/// Returns `1.0` for screens to the right of the center screen and `-1.0` for those to the left.
+fn side_of(index: usize, center: usize) -> f64 {
+    if index > center {
+        1.0
+    } else {
+        -1.0
+    }
+}
+/// Returns the direction going along a side screen away from the center screen, as `[x, z]`.
+fn outward_direction(yaw: Angle, side: f64) -> [f64; 2] {
+    [math::cos(yaw) * side, -math::sin(yaw)]
+}
+/// Returns the angle between the forward direction and the direction towards a point, positive to the right.
+fn azimuth([x, z]: [Length; 2]) -> Angle {
+    math::atan2(x.get::<meter>(), z.get::<meter>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uom::si::{angle::radian, length::centimeter};
+
+    fn screen(width: f64, yaw: Angle, bezel: f64) -> SurroundScreen {
+        SurroundScreen {
+            dimensions: MonitorDimensions::WidthAndHeight {
+                width: Length::new::<centimeter>(width),
+                height: Length::new::<centimeter>(width * 9.0 / 16.0),
+            },
+            yaw,
+            bezel: Length::new::<centimeter>(bezel),
+        }
+    }
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            math::abs(actual - expected) < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn single_screen_matches_monitor() {
+        let screens = [screen(60.0, Angle::default(), 1.0)];
+        let rig = SurroundRig {
+            screens: &screens,
+            center: 0,
+            distance: Length::new::<centimeter>(70.0),
+        };
+        let monitor = MonitorConfiguration {
+            dimensions: screens[0].dimensions,
+            distance: rig.distance,
+            eye_offset: None,
+            curvature: None,
+            resolution: None,
+        };
+        assert_close(
+            rig.total_fov().get::<radian>(),
+            monitor.fov().get::<radian>(),
+        );
+        assert_close(
+            rig.screen_view(0).fov().get::<radian>(),
+            monitor.fov().get::<radian>(),
+        );
+    }
+    #[test]
+    fn optimal_yaw_faces_the_eye() {
+        // Uneven on purpose: two screens to the left, one to the right, all of different sizes
+        let mut screens = [
+            screen(50.0, Angle::default(), 1.5),
+            screen(55.0, Angle::default(), 0.5),
+            screen(60.0, Angle::default(), 1.0),
+            screen(70.0, Angle::default(), 2.0),
+        ];
+        let center = 2;
+        let distance = Length::new::<centimeter>(65.0);
+        for &index in &[1, 3, 0] {
+            let yaw = SurroundRig {
+                screens: &screens,
+                center,
+                distance,
+            }
+            .optimal_yaw(index);
+            screens[index].yaw = yaw;
+        }
+        let rig = SurroundRig {
+            screens: &screens,
+            center,
+            distance,
+        };
+        assert_eq!(rig.optimal_yaw(center), Angle::default());
+        for index in 0..screens.len() {
+            let view = rig.screen_view(index);
+            let offset = view.configuration.eye_offset.unwrap().horizontal;
+            assert_close(offset.get::<meter>(), 0.0);
+            // With the eye on the normal through the center, the edges are equally far to each
+            // side of the camera direction
+            assert_close(
+                (view.right_edge - view.camera_yaw).get::<radian>(),
+                (view.camera_yaw - view.left_edge).get::<radian>(),
+            );
+        }
+    }
+    #[test]
+    fn bezels_widen_the_total_fov() {
+        let distance = 70.0;
+        let width = 60.0;
+        let total_fov = |center_bezel: f64, side_bezel: f64| {
+            let screens = [
+                screen(width, Angle::default(), side_bezel),
+                screen(width, Angle::default(), center_bezel),
+                screen(width, Angle::default(), side_bezel),
+            ];
+            SurroundRig {
+                screens: &screens,
+                center: 1,
+                distance: Length::new::<centimeter>(distance),
+            }
+            .total_fov()
+            .get::<radian>()
+        };
+        // Unturned screens lie in one plane, with the bezels between the center screen and each
+        // side screen adding to the half-width of the whole rig
+        let expected = |bezels: f64| math::atan((width * 1.5 + bezels) / distance) * 2.0;
+        assert_close(total_fov(0.0, 0.0), expected(0.0).get::<radian>());
+        assert_close(total_fov(1.0, 2.0), expected(3.0).get::<radian>());
+        assert!(total_fov(1.0, 2.0) > total_fov(0.0, 0.0));
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{MonitorConfiguration, MonitorDimensions};
+use core::fmt::{self, Display, Formatter};
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length},
+};
+
+/// How far each measured input of a [`MonitorConfiguration`] may be off from its true value, in either direction.
+///
+/// Dimensions read from EDID are rounded to whole centimeters and thus have a tolerance of half a centimeter, while a viewing distance measured with a tape held by eye is likely to be off by a few centimeters.
+///
+/// [`MonitorConfiguration`]: struct.MonitorConfiguration.html " "
+#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tolerances {
+    /// The tolerance of each of the lengths in the [dimensions], i.e. of the width and the height, or of the diagonal.
+    ///
+    /// [dimensions]: struct.MonitorConfiguration.html#structfield.dimensions " "
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "dimensions_m", with = "crate::serde_units::meters")
+    )]
+    pub dimensions: Length,
+    /// The tolerance of the [viewing distance].
+    ///
+    /// [viewing distance]: struct.MonitorConfiguration.html#structfield.distance " "
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "distance_m", with = "crate::serde_units::meters")
+    )]
+    pub distance: Length,
+    /// The tolerance of the [radius of the curvature], ignored for flat monitors.
+    ///
+    /// [radius of the curvature]: struct.Curvature.html#structfield.radius " "
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "curvature_radius_m", with = "crate::serde_units::meters")
+    )]
+    pub curvature_radius: Length,
+}
+
+/// The range of values which a field of view can take given the tolerances of the inputs it was calculated from.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FovInterval {
+    /// The smallest possible value.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "min_rad", with = "crate::serde_units::radians")
+    )]
+    pub min: Angle,
+    /// The largest possible value.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "max_rad", with = "crate::serde_units::radians")
+    )]
+    pub max: Angle,
+}
+impl FovInterval {
+    /// Returns the middle of the interval. Since the FOV doesn't depend on the inputs linearly, this may be slightly different from the FOV calculated from the measured values.
+    pub fn center(self) -> Angle {
+        (self.min + self.max) / 2.0
+    }
+    /// Returns how far the ends of the interval are from its [center].
+    ///
+    /// [center]: #method.center " "
+    pub fn tolerance(self) -> Angle {
+        (self.max - self.min) / 2.0
+    }
+    /// Returns whether the given FOV lies within the interval.
+    pub fn contains(self, fov: Angle) -> bool {
+        self.min <= fov && fov <= self.max
+    }
+}
+impl Display for FovInterval {
+    /// Displays the interval in degrees as its [center] plus or minus the [tolerance], such as `103.2° ± 1.4°`.
+    ///
+    /// Unless a precision is specified, the number of digits after the decimal point is chosen to show two significant digits of the tolerance, but no more than three, so that only the digits which mean something are displayed.
+    ///
+    /// [center]: #method.center " "
+    /// [tolerance]: #method.tolerance " "
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let tolerance = self.tolerance().get::<degree>();
+        let precision = f.precision().unwrap_or_else(|| {
+            let (mut precision, mut scaled) = (0, tolerance);
+            while scaled < 10.0 && precision < 3 {
+                scaled *= 10.0;
+                precision += 1;
+            }
+            precision
+        });
+        write!(
+            f,
+            "{:.*}° ± {:.*}°",
+            precision,
+            self.center().get::<degree>(),
+            precision,
+            tolerance,
+        )
+    }
+}
+
+impl MonitorConfiguration {
+    /// Calculates the range of values which the [portal-like FOV] can take if each input may be off by the given tolerance.
+    ///
+    /// [portal-like FOV]: #method.fov " "
+    pub fn fov_interval(self, tolerances: Tolerances) -> FovInterval {
+        self.interval(tolerances, Self::fov)
+    }
+    /// Calculates the range of values which the FOV from [`monitor_fov_for_distance`] can take if each input of the monitor configuration may be off by the given tolerance. The distance with accurate scale is taken as exact, since it's chosen rather than measured.
+    ///
+    /// [`monitor_fov_for_distance`]: #method.monitor_fov_for_distance " "
+    pub fn monitor_fov_for_distance_interval(
+        self,
+        distance: Length,
+        relative_to_monitor: bool,
+        tolerances: Tolerances,
+    ) -> FovInterval {
+        self.interval(tolerances, |conf| {
+            conf.monitor_fov_for_distance(distance, relative_to_monitor)
+        })
+    }
+    /// Evaluates the FOV with every input at both ends of its tolerance, in all combinations. The FOV changes monotonically with each of the inputs, so the extremes are always found among those.
+    fn interval(self, tolerances: Tolerances, fov: impl Fn(Self) -> Angle) -> FovInterval {
+        let nominal = fov(self);
+        let mut interval = FovInterval {
+            min: nominal,
+            max: nominal,
+        };
+        for corner in 0..16_u8 {
+            let sign = |input: u8| {
+                if corner & (1 << input) == 0 {
+                    -1.0
+                } else {
+                    1.0
+                }
+            };
+            let mut conf = self;
+            conf.dimensions = match self.dimensions {
+                MonitorDimensions::WidthAndHeight { width, height } => {
+                    MonitorDimensions::WidthAndHeight {
+                        width: width + tolerances.dimensions * sign(0),
+                        height: height + tolerances.dimensions * sign(1),
+                    }
+                }
+                MonitorDimensions::DiagonalAndAspect { diagonal, aspect } => {
+                    MonitorDimensions::DiagonalAndAspect {
+                        diagonal: diagonal + tolerances.dimensions * sign(0),
+                        aspect,
+                    }
+                }
+            };
+            conf.distance += tolerances.distance * sign(2);
+            if let Some(curvature) = &mut conf.curvature {
+                curvature.radius += tolerances.curvature_radius * sign(3);
+            }
+            let fov = fov(conf);
+            interval.min = interval.min.min(fov);
+            interval.max = interval.max.max(fov);
+        }
+        interval
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use crate::{Curvature, CurvedWidthMeasurement};
+    use std::{format, string::ToString};
+    use uom::si::{angle::radian, length::centimeter};
+
+    fn monitor(width: f64, distance: f64) -> MonitorConfiguration {
+        MonitorConfiguration {
+            dimensions: MonitorDimensions::WidthAndHeight {
+                width: Length::new::<centimeter>(width),
+                height: Length::new::<centimeter>(width * 9.0 / 16.0),
+            },
+            distance: Length::new::<centimeter>(distance),
+            eye_offset: None,
+            curvature: None,
+            resolution: None,
+        }
+    }
+    fn tolerances(dimensions: f64, distance: f64, curvature_radius: f64) -> Tolerances {
+        Tolerances {
+            dimensions: Length::new::<centimeter>(dimensions),
+            distance: Length::new::<centimeter>(distance),
+            curvature_radius: Length::new::<centimeter>(curvature_radius),
+        }
+    }
+    fn degrees(min: f64, max: f64) -> FovInterval {
+        FovInterval {
+            min: Angle::new::<degree>(min),
+            max: Angle::new::<degree>(max),
+        }
+    }
+
+    #[test]
+    fn interval_contains_the_nominal_fov() {
+        let flat = monitor(60.0, 70.0);
+        let curved = MonitorConfiguration {
+            curvature: Some(Curvature {
+                radius: Length::new::<centimeter>(150.0),
+                width_measurement: CurvedWidthMeasurement::Arc,
+            }),
+            ..monitor(80.0, 70.0)
+        };
+        for &monitor in &[flat, curved] {
+            let interval = monitor.fov_interval(tolerances(0.5, 3.0, 10.0));
+            assert!(interval.contains(monitor.fov()));
+            assert!(interval.min < interval.max);
+            let distance = Length::new::<centimeter>(200.0);
+            let interval = monitor.monitor_fov_for_distance_interval(
+                distance,
+                true,
+                tolerances(0.5, 3.0, 0.0),
+            );
+            assert!(interval.contains(monitor.monitor_fov_for_distance(distance, true)));
+        }
+        // For a flat monitor, the extremes are the widest screen seen from closest and the
+        // narrowest one seen from farthest
+        let interval = flat.fov_interval(tolerances(0.5, 3.0, 10.0));
+        let [min, max] = [monitor(59.5, 73.0).fov(), monitor(60.5, 67.0).fov()];
+        assert!((interval.min - min).abs().get::<radian>() < 1e-12);
+        assert!((interval.max - max).abs().get::<radian>() < 1e-12);
+    }
+    #[test]
+    fn zero_tolerances_give_an_exact_interval() {
+        let monitor = monitor(60.0, 70.0);
+        let interval = monitor.fov_interval(Tolerances::default());
+        assert_eq!(interval.min, monitor.fov());
+        assert_eq!(interval.max, monitor.fov());
+        assert_eq!(interval.tolerance(), Angle::default());
+    }
+    #[test]
+    fn display() {
+        assert_eq!(degrees(101.8, 104.6).to_string(), "103.2° ± 1.4°");
+        assert_eq!(degrees(90.0, 110.0).to_string(), "100° ± 10°");
+        assert_eq!(degrees(99.996, 100.004).to_string(), "100.000° ± 0.004°");
+        assert_eq!(format!("{:.2}", degrees(101.8, 104.6)), "103.20° ± 1.40°");
+    }
+}
@@ -0,0 +1,427 @@
+use crate::{AspectRatio, CurvedWidthMeasurement, MonitorConfiguration, MonitorDimensions};
+use core::fmt::{self, Display, Formatter};
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length},
+    length::{centimeter, meter},
+};
+
+/// The range of aspect ratios (`width / height`) which [`MonitorDimensions::try_diagonal_and_aspect`] and friends accept, as `[min, max]`.
+///
+/// This covers portrait monitors and everything from square screens to three 32:9 super ultrawides in a row treated as one monitor, which is about 10.7:1 before counting the bezels.
+///
+/// [`MonitorDimensions::try_diagonal_and_aspect`]: enum.MonitorDimensions.html#method.try_diagonal_and_aspect " "
+pub const PLAUSIBLE_ASPECT_RANGE: [f64; 2] = [1.0 / 12.0, 12.0];
+/// The distance in centimeters at or under which the eye is considered to be too close to the screen to focus on it.
+const NEAR_POINT_CM: f64 = 10.0;
+/// The horizontal FOV in degrees above which the screen is considered to wrap around the viewer implausibly.
+const MAX_PLAUSIBLE_FOV_DEG: f64 = 150.0;
+/// The horizontal FOV in degrees under which the screen is considered to be implausibly small or far away.
+const MIN_PLAUSIBLE_FOV_DEG: f64 = 5.0;
+
+/// One of the measurements which make up a [monitor configuration].
+///
+/// [monitor configuration]: struct.MonitorConfiguration.html " "
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Measurement {
+    /// The width of the monitor.
+    Width,
+    /// The height of the monitor.
+    Height,
+    /// The length of the diagonal of the monitor.
+    Diagonal,
+    /// The aspect ratio of the monitor.
+    Aspect,
+    /// The distance from the eye to the monitor.
+    Distance,
+    /// The position of the eye relative to the center of the monitor.
+    EyeOffset,
+    /// The radius of the curvature of the monitor.
+    CurvatureRadius,
+    /// The native resolution of the monitor.
+    Resolution,
+}
+impl Display for Measurement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Width => "width",
+            Self::Height => "height",
+            Self::Diagonal => "diagonal",
+            Self::Aspect => "aspect ratio",
+            Self::Distance => "distance",
+            Self::EyeOffset => "eye offset",
+            Self::CurvatureRadius => "curvature radius",
+            Self::Resolution => "resolution",
+        })
+    }
+}
+
+/// The reason why a [monitor configuration] or [monitor dimensions] were rejected by a checked constructor.
+///
+/// Any of those would make the calculations return NaN or otherwise meaningless results.
+///
+/// [monitor configuration]: struct.MonitorConfiguration.html#method.validate " "
+/// [monitor dimensions]: enum.MonitorDimensions.html#method.validate " "
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValidationError {
+    /// A length which can only be positive is zero or negative.
+    NonPositiveLength(Measurement),
+    /// A measurement is infinite or NaN.
+    NonFinite(Measurement),
+    /// The aspect ratio, which is contained in the variant, is outside of [`PLAUSIBLE_ASPECT_RANGE`].
+    ///
+    /// [`PLAUSIBLE_ASPECT_RANGE`]: constant.PLAUSIBLE_ASPECT_RANGE.html " "
+    ImplausibleAspect(f64),
+    /// The width of a curved monitor was [measured along the chord], but is longer than the diameter of the curvature, which no chord of a circle can be.
+    ///
+    /// [measured along the chord]: enum.CurvedWidthMeasurement.html#variant.Chord " "
+    ChordLongerThanDiameter,
+    /// The native resolution has zero pixels along one of the axes.
+    ZeroResolution,
+}
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonPositiveLength(measurement) => {
+                write!(f, "the {} must be greater than zero", measurement)
+            }
+            Self::NonFinite(measurement) => {
+                write!(f, "the {} must be a finite number", measurement)
+            }
+            Self::ImplausibleAspect(aspect) => write!(
+                f,
+                "the aspect ratio {} is outside of the plausible range from {} to {}",
+                aspect, PLAUSIBLE_ASPECT_RANGE[0], PLAUSIBLE_ASPECT_RANGE[1],
+            ),
+            Self::ChordLongerThanDiameter => f.write_str(
+                "the width measured along the chord must not exceed twice the curvature radius",
+            ),
+            Self::ZeroResolution => f.write_str("the resolution must be at least one pixel"),
+        }
+    }
+}
+
+/// A sign that a [monitor configuration] which passed validation is still physically suspicious, most likely because of a typo or a wrong unit.
+///
+/// The calculations work fine for such configurations, but the results are unlikely to be what the user wants. Use [`MonitorConfiguration::warnings`] to get those.
+///
+/// [monitor configuration]: struct.MonitorConfiguration.html " "
+/// [`MonitorConfiguration::warnings`]: struct.MonitorConfiguration.html#method.warnings " "
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValidationWarning {
+    /// The eye is 10 centimeters away from the screen or closer, the distance which even young eyes can barely focus at. The variant contains the distance.
+    #[cfg_attr(feature = "serde", serde(rename = "CloserThanNearPoint_m"))]
+    CloserThanNearPoint(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_units::meters"))] Length,
+    ),
+    /// The horizontal FOV, which is contained in the variant, is wider than 150°, which requires the screen to nearly wrap around the viewer, such as a 5 meter screen 10 centimeters away.
+    #[cfg_attr(feature = "serde", serde(rename = "ExtremelyWideFov_rad"))]
+    ExtremelyWideFov(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_units::radians"))] Angle,
+    ),
+    /// The horizontal FOV, which is contained in the variant, is narrower than 5°, which happens when the distance is given in meters instead of centimeters or similar.
+    #[cfg_attr(feature = "serde", serde(rename = "ExtremelyNarrowFov_rad"))]
+    ExtremelyNarrowFov(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_units::radians"))] Angle,
+    ),
+}
+impl Display for ValidationWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CloserThanNearPoint(distance) => write!(
+                f,
+                "the screen is {:.1} cm away, closer than the eye can focus",
+                distance.get::<centimeter>(),
+            ),
+            Self::ExtremelyWideFov(fov) => write!(
+                f,
+                "the screen is seen at {:.1}°, which is suspiciously wide",
+                fov.get::<degree>(),
+            ),
+            Self::ExtremelyNarrowFov(fov) => write!(
+                f,
+                "the screen is seen at {:.1}°, which is suspiciously narrow",
+                fov.get::<degree>(),
+            ),
+        }
+    }
+}
+
+impl MonitorDimensions {
+    /// Creates the `WidthAndHeight` variant, checking that both lengths are positive and finite and that the resulting aspect ratio is plausible.
+    pub fn try_width_and_height(width: Length, height: Length) -> Result<Self, ValidationError> {
+        Self::WidthAndHeight { width, height }.validate()
+    }
+    /// Creates the `DiagonalAndAspect` variant, checking that the diagonal is positive and finite and that the aspect ratio is within [`PLAUSIBLE_ASPECT_RANGE`].
+    ///
+    /// [`PLAUSIBLE_ASPECT_RANGE`]: constant.PLAUSIBLE_ASPECT_RANGE.html " "
+    pub fn try_diagonal_and_aspect(
+        diagonal: Length,
+        aspect: AspectRatio,
+    ) -> Result<Self, ValidationError> {
+        Self::DiagonalAndAspect { diagonal, aspect }.validate()
+    }
+    /// Checks the dimensions the same way as the checked constructors do, returning them back if they're valid.
+    pub fn validate(self) -> Result<Self, ValidationError> {
+        match self {
+            Self::WidthAndHeight { width, height } => {
+                check_length(width, Measurement::Width)?;
+                check_length(height, Measurement::Height)?;
+            }
+            Self::DiagonalAndAspect { diagonal, .. } => {
+                check_length(diagonal, Measurement::Diagonal)?;
+            }
+        }
+        let aspect = self.aspect();
+        if !aspect.is_finite() {
+            return Err(ValidationError::NonFinite(Measurement::Aspect));
+        }
+        let [min_aspect, max_aspect] = PLAUSIBLE_ASPECT_RANGE;
+        if aspect < min_aspect || aspect > max_aspect {
+            return Err(ValidationError::ImplausibleAspect(aspect));
+        }
+        Ok(self)
+    }
+}
+
+impl MonitorConfiguration {
+    /// Creates a configuration for a flat monitor of unknown resolution with the eye in front of its center, checking the dimensions and distance.
+    ///
+    /// The other fields can be filled in afterwards, in which case [`validate`] can be used to check the whole configuration again.
+    ///
+    /// [`validate`]: #method.validate " "
+    pub fn try_new(
+        dimensions: MonitorDimensions,
+        distance: Length,
+    ) -> Result<Self, ValidationError> {
+        Self {
+            dimensions,
+            distance,
+            eye_offset: None,
+            curvature: None,
+            resolution: None,
+        }
+        .validate()
+    }
+    /// Checks that all measurements in the configuration make sense, returning it back if they do.
+    ///
+    /// The dimensions are checked the same way as by [`MonitorDimensions::validate`], the distance and curvature radius have to be positive and finite, a width measured along the chord has to fit within the curvature, the eye offset has to be finite and the resolution can't be zero. Use [`warnings`] to catch values which are valid but suspicious.
+    ///
+    /// [`MonitorDimensions::validate`]: enum.MonitorDimensions.html#method.validate " "
+    /// [`warnings`]: #method.warnings " "
+    pub fn validate(self) -> Result<Self, ValidationError> {
+        self.dimensions.validate()?;
+        check_length(self.distance, Measurement::Distance)?;
+        if let Some(offset) = self.eye_offset {
+            if !offset.horizontal.is_finite() || !offset.vertical.is_finite() {
+                return Err(ValidationError::NonFinite(Measurement::EyeOffset));
+            }
+        }
+        if let Some(curvature) = self.curvature {
+            check_length(curvature.radius, Measurement::CurvatureRadius)?;
+            if curvature.width_measurement == CurvedWidthMeasurement::Chord
+                && self.dimensions.width_and_height()[0] > curvature.radius * 2.0
+            {
+                return Err(ValidationError::ChordLongerThanDiameter);
+            }
+        }
+        if let Some([width, height]) = self.resolution {
+            if width == 0 || height == 0 {
+                return Err(ValidationError::ZeroResolution);
+            }
+        }
+        Ok(self)
+    }
+    /// Returns an iterator over the signs of the configuration being physically suspicious, which is empty for any reasonable setup.
+    ///
+    /// The configuration should be [valid] for the warnings to make sense.
+    ///
+    /// [valid]: #method.validate " "
+    pub fn warnings(self) -> impl Iterator<Item = ValidationWarning> {
+        let too_close = if self.distance <= Length::new::<centimeter>(NEAR_POINT_CM) {
+            Some(ValidationWarning::CloserThanNearPoint(self.distance))
+        } else {
+            None
+        };
+        let fov = self.fov();
+        let implausible_fov = if fov > Angle::new::<degree>(MAX_PLAUSIBLE_FOV_DEG) {
+            Some(ValidationWarning::ExtremelyWideFov(fov))
+        } else if fov < Angle::new::<degree>(MIN_PLAUSIBLE_FOV_DEG) {
+            Some(ValidationWarning::ExtremelyNarrowFov(fov))
+        } else {
+            None
+        };
+        too_close.into_iter().chain(implausible_fov)
+    }
+}
+
+fn check_length(length: Length, measurement: Measurement) -> Result<(), ValidationError> {
+    let value = length.get::<meter>();
+    if !value.is_finite() {
+        Err(ValidationError::NonFinite(measurement))
+    } else if value <= 0.0 {
+        Err(ValidationError::NonPositiveLength(measurement))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use crate::{Curvature, EyeOffset};
+    use std::{string::ToString, vec::Vec};
+
+    fn cm(value: f64) -> Length {
+        Length::new::<centimeter>(value)
+    }
+    fn monitor(width: f64, distance: f64) -> MonitorConfiguration {
+        MonitorConfiguration {
+            dimensions: MonitorDimensions::WidthAndHeight {
+                width: cm(width),
+                height: cm(width * 9.0 / 16.0),
+            },
+            distance: cm(distance),
+            eye_offset: None,
+            curvature: None,
+            resolution: None,
+        }
+    }
+
+    #[test]
+    fn non_positive_lengths() {
+        assert_eq!(
+            MonitorDimensions::try_width_and_height(cm(0.0), cm(30.0)).unwrap_err(),
+            ValidationError::NonPositiveLength(Measurement::Width)
+        );
+        assert_eq!(
+            MonitorDimensions::try_width_and_height(cm(50.0), cm(-30.0)).unwrap_err(),
+            ValidationError::NonPositiveLength(Measurement::Height)
+        );
+        assert_eq!(
+            MonitorDimensions::try_diagonal_and_aspect(cm(-60.0), AspectRatio::new(16, 9).unwrap())
+                .unwrap_err(),
+            ValidationError::NonPositiveLength(Measurement::Diagonal)
+        );
+        let dimensions = monitor(60.0, 70.0).dimensions;
+        assert_eq!(
+            MonitorConfiguration::try_new(dimensions, cm(-70.0)).unwrap_err(),
+            ValidationError::NonPositiveLength(Measurement::Distance)
+        );
+        let curved = MonitorConfiguration {
+            curvature: Some(Curvature {
+                radius: cm(0.0),
+                width_measurement: CurvedWidthMeasurement::Arc,
+            }),
+            ..monitor(60.0, 70.0)
+        };
+        assert_eq!(
+            curved.validate().unwrap_err(),
+            ValidationError::NonPositiveLength(Measurement::CurvatureRadius)
+        );
+    }
+    #[test]
+    fn non_finite_measurements() {
+        assert_eq!(
+            MonitorDimensions::try_width_and_height(cm(f64::NAN), cm(30.0)).unwrap_err(),
+            ValidationError::NonFinite(Measurement::Width)
+        );
+        assert_eq!(
+            MonitorDimensions::try_diagonal_and_aspect(
+                cm(f64::INFINITY),
+                AspectRatio::new(16, 9).unwrap()
+            )
+            .unwrap_err(),
+            ValidationError::NonFinite(Measurement::Diagonal)
+        );
+        let dimensions = monitor(60.0, 70.0).dimensions;
+        assert_eq!(
+            MonitorConfiguration::try_new(dimensions, cm(f64::NAN)).unwrap_err(),
+            ValidationError::NonFinite(Measurement::Distance)
+        );
+        let offset = MonitorConfiguration {
+            eye_offset: Some(EyeOffset {
+                horizontal: cm(0.0),
+                vertical: cm(f64::NEG_INFINITY),
+            }),
+            ..monitor(60.0, 70.0)
+        };
+        assert_eq!(
+            offset.validate().unwrap_err(),
+            ValidationError::NonFinite(Measurement::EyeOffset)
+        );
+    }
+    #[test]
+    fn implausible_aspect() {
+        assert_eq!(
+            MonitorDimensions::try_width_and_height(cm(130.0), cm(10.0)).unwrap_err(),
+            ValidationError::ImplausibleAspect(13.0)
+        );
+        assert_eq!(
+            MonitorDimensions::try_diagonal_and_aspect(cm(100.0), AspectRatio::new(1, 13).unwrap())
+                .unwrap_err(),
+            ValidationError::ImplausibleAspect(1.0 / 13.0)
+        );
+        // Three 32:9 screens side by side are still plausible
+        assert!(MonitorDimensions::try_width_and_height(cm(360.0), cm(33.75)).is_ok());
+    }
+    #[test]
+    fn chord_longer_than_diameter() {
+        let chord = |width| MonitorConfiguration {
+            curvature: Some(Curvature {
+                radius: cm(100.0),
+                width_measurement: CurvedWidthMeasurement::Chord,
+            }),
+            ..monitor(width, 70.0)
+        };
+        assert!(chord(200.0).validate().is_ok());
+        assert_eq!(
+            chord(201.0).validate().unwrap_err(),
+            ValidationError::ChordLongerThanDiameter
+        );
+    }
+    #[test]
+    fn zero_resolution() {
+        let monitor = |resolution| MonitorConfiguration {
+            resolution: Some(resolution),
+            ..monitor(60.0, 70.0)
+        };
+        assert!(monitor([1920, 1080]).validate().is_ok());
+        assert_eq!(
+            monitor([1920, 0]).validate().unwrap_err(),
+            ValidationError::ZeroResolution
+        );
+        assert_eq!(
+            ValidationError::ZeroResolution.to_string(),
+            "the resolution must be at least one pixel"
+        );
+    }
+    #[test]
+    fn warnings() {
+        assert_eq!(monitor(60.0, 70.0).warnings().count(), 0);
+        // A 5 meter monitor at 10 centimeters, most likely with the units mixed up
+        let warnings = monitor(500.0, 10.0).warnings().collect::<Vec<_>>();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[0],
+            ValidationWarning::CloserThanNearPoint(cm(10.0))
+        );
+        assert!(matches!(
+            warnings[1],
+            ValidationWarning::ExtremelyWideFov(_)
+        ));
+        // A distance given in meters where centimeters were expected
+        let warnings = monitor(60.0, 7000.0).warnings().collect::<Vec<_>>();
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(
+            warnings[0],
+            ValidationWarning::ExtremelyNarrowFov(_)
+        ));
+        // Just past the near point, a wide screen is still within the plausible FOV
+        assert_eq!(monitor(60.0, 10.5).warnings().count(), 0);
+    }
+}
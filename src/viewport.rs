@@ -0,0 +1,382 @@
+use crate::{
+    math, AspectRatio, Curvature, CurvedWidthMeasurement, EyeOffset, Frustum, MonitorConfiguration,
+    MonitorDimensions,
+};
+use uom::si::{
+    angle::radian,
+    f64::{Angle, Length},
+    ratio::ratio,
+};
+
+/// The rectangle of the screen which the game draws into, when it doesn't fill the whole panel: a 16:9 game pillarboxed on an ultrawide screen, a letterboxed cutscene, or a game running in a window.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Viewport {
+    /// A rectangle in pixels of the native resolution, with the position measured from the top left corner of the screen. Requires the [resolution] to be known.
+    ///
+    /// [resolution]: struct.MonitorConfiguration.html#structfield.resolution " "
+    #[allow(missing_docs)] // Field names are self-explanatory
+    Pixels {
+        left: u32,
+        top: u32,
+        width: u32,
+        height: u32,
+    },
+    /// A rectangle as fractions of the width and height of the screen, with the position measured from the top left corner of the screen, meaning that `left: 0.0, top: 0.0, width: 1.0, height: 1.0` is the whole screen.
+    #[allow(missing_docs)] // Field names are self-explanatory
+    Fraction {
+        left: f64,
+        top: f64,
+        width: f64,
+        height: f64,
+    },
+}
+impl Viewport {
+    /// Returns the largest centered viewport with the given aspect ratio which fits onto a screen with another aspect ratio (`width / height`), i.e. the pillarboxed or letterboxed area of the screen.
+    pub fn fit(aspect: AspectRatio, screen_aspect: f64) -> Self {
+        let aspect = aspect.value();
+        // Whichever side is relatively shorter on the screen is filled completely
+        let (width, height) = if aspect > screen_aspect {
+            (1.0, screen_aspect / aspect)
+        } else {
+            (aspect / screen_aspect, 1.0)
+        };
+        Self::Fraction {
+            left: (1.0 - width) / 2.0,
+            top: (1.0 - height) / 2.0,
+            width,
+            height,
+        }
+    }
+    /// Returns the viewports of local split-screen with the given number of columns and rows, in reading order, i.e. left to right within each row and rows from top to bottom.
+    pub fn split_screen(columns: u32, rows: u32) -> impl Iterator<Item = Self> {
+        let [width, height] = [1.0 / columns as f64, 1.0 / rows as f64];
+        (0..rows).flat_map(move |row| {
+            (0..columns).map(move |column| Self::Fraction {
+                left: column as f64 * width,
+                top: row as f64 * height,
+                width,
+                height,
+            })
+        })
+    }
+    /// Returns the viewport as fractions of the width and height of a screen with the given resolution, in the order `[left, top, width, height]`, or `None` if the viewport is in pixels and the resolution is unknown.
+    pub fn as_fractions(self, resolution: Option<[u32; 2]>) -> Option<[f64; 4]> {
+        match self {
+            Self::Pixels {
+                left,
+                top,
+                width,
+                height,
+            } => {
+                let [screen_width, screen_height] = resolution?;
+                let [screen_width, screen_height] = [screen_width as f64, screen_height as f64];
+                Some([
+                    left as f64 / screen_width,
+                    top as f64 / screen_height,
+                    width as f64 / screen_width,
+                    height as f64 / screen_height,
+                ])
+            }
+            Self::Fraction {
+                left,
+                top,
+                width,
+                height,
+            } => Some([left, top, width, height]),
+        }
+    }
+}
+
+/// The portal-like view through a part of the screen, as returned by [`MonitorConfiguration::viewport_view`].
+///
+/// [`MonitorConfiguration::viewport_view`]: struct.MonitorConfiguration.html#method.viewport_view " "
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewportView {
+    /// The horizontal FOV of the view, i.e. the angle between the left and right edges of the viewport as seen from the eye.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "fov_rad", with = "crate::serde_units::radians")
+    )]
+    pub fov: Angle,
+    /// The vertical FOV of the view, i.e. the angle between the top and bottom edges of the viewport as seen from the eye.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vertical_fov_rad", with = "crate::serde_units::radians")
+    )]
+    pub vertical_fov: Angle,
+    /// The frustum of the view, which is asymmetric unless the viewport is right in front of the eye.
+    pub frustum: Frustum,
+    /// The [lens shift] of the frustum as `[x, y]`.
+    ///
+    /// [lens shift]: struct.Frustum.html#method.lens_shift " "
+    pub lens_shift: [f64; 2],
+}
+
+impl MonitorConfiguration {
+    /// Returns a configuration for the part of the screen covered by the given viewport, as if it was a monitor of its own, so that all calculations use the physical size of the viewport rather than that of the whole screen.
+    ///
+    /// The [eye offset] becomes relative to the center of the viewport and the [resolution] becomes that of the viewport. Returns `None` if the viewport is in pixels and the resolution is unknown, or if the viewport is empty or reaches beyond the edges of the screen.
+    ///
+    /// On a curved monitor, a viewport which isn't horizontally centered is a symmetric piece of the same curve turned around the center of the curvature, so the configuration describes it from a view direction turned by the same angle, i.e. perpendicular to the screen at the middle of the viewport. The viewing distance and the eye offset are measured from there, and the [frustum] is relative to that direction, as is the view of each player in left and right split-screen.
+    ///
+    /// [eye offset]: #structfield.eye_offset " "
+    /// [resolution]: #structfield.resolution " "
+    /// [frustum]: #method.frustum " "
+    pub fn viewport(self, viewport: Viewport) -> Option<Self> {
+        let [left, top, width_fraction, height_fraction] =
+            viewport.as_fractions(self.resolution)?;
+        let within = |start: f64, size: f64| start >= 0.0 && size > 0.0 && start + size <= 1.0;
+        if !within(left, width_fraction) || !within(top, height_fraction) {
+            return None;
+        }
+        let [width, height] = self.dimensions.width_and_height();
+        // How far the center of the viewport is from the center of the screen, along the surface
+        // of the screen, to the right and up
+        let horizontal_offset = (left + width_fraction / 2.0 - 0.5) * self.panel_width();
+        let vertical_offset = (0.5 - top - height_fraction / 2.0) * height;
+        let (width, curvature, horizontal_offset, distance) = match self.curvature {
+            None => (
+                width * width_fraction,
+                None,
+                horizontal_offset,
+                self.distance,
+            ),
+            Some(c) => {
+                // The pixels are spread evenly along the curve, so it's the arc which gets divided
+                let curvature = Curvature {
+                    radius: c.radius,
+                    width_measurement: CurvedWidthMeasurement::Arc,
+                };
+                // Turning everything around the center of the curvature by the central angle
+                // between the middles of the screen and the viewport centers the viewport, so the
+                // eye is turned the other way, measured from the center of the curvature: to the
+                // right and towards the screen
+                let angle = Angle::new::<radian>((horizontal_offset / c.radius).get::<ratio>());
+                let eye_horizontal = self.eye_offset.map_or(Length::default(), |o| o.horizontal);
+                let eye_depth = c.radius - self.distance;
+                let (sin, cos) = (math::sin(angle), math::cos(angle));
+                let turned_horizontal = eye_horizontal * cos - eye_depth * sin;
+                let turned_depth = eye_horizontal * sin + eye_depth * cos;
+                (
+                    c.arc_width(width) * width_fraction,
+                    Some(curvature),
+                    eye_horizontal - turned_horizontal,
+                    c.radius - turned_depth,
+                )
+            }
+        };
+        let centered =
+            horizontal_offset == Length::default() && vertical_offset == Length::default();
+        let eye_offset = match self.eye_offset {
+            None if centered => None,
+            offset => {
+                let EyeOffset {
+                    horizontal,
+                    vertical,
+                } = offset.unwrap_or(EyeOffset {
+                    horizontal: Length::default(),
+                    vertical: Length::default(),
+                });
+                Some(EyeOffset {
+                    horizontal: horizontal - horizontal_offset,
+                    vertical: vertical - vertical_offset,
+                })
+            }
+        };
+        let resolution = match viewport {
+            Viewport::Pixels { width, height, .. } => Some([width, height]),
+            Viewport::Fraction { .. } => self.resolution.map(|[screen_width, screen_height]| {
+                [
+                    (screen_width as f64 * width_fraction + 0.5) as u32,
+                    (screen_height as f64 * height_fraction + 0.5) as u32,
+                ]
+            }),
+        };
+        Some(Self {
+            dimensions: MonitorDimensions::WidthAndHeight {
+                width,
+                height: height * height_fraction,
+            },
+            distance,
+            eye_offset,
+            curvature,
+            resolution,
+        })
+    }
+    /// Calculates the portal-like view through the given viewport, for games which render several views into different parts of the screen: local split-screen, rear-view mirrors in racing games or picture-in-picture cameras. Returns `None` in the same cases as [`viewport`].
+    ///
+    /// Each view has its own off-axis [frustum] which makes the viewport a portal into the 3D world as seen from the eye, so the FOVs are the total angles of the frustum rather than the FOV of the viewport as if it was centered in front of the eye.
+    ///
+    /// [`viewport`]: #method.viewport " "
+    /// [frustum]: #method.frustum " "
+    pub fn viewport_view(self, viewport: Viewport) -> Option<ViewportView> {
+        let frustum = self.viewport(viewport)?.frustum();
+        Some(ViewportView {
+            fov: frustum.horizontal_fov(),
+            vertical_fov: frustum.vertical_fov(),
+            frustum,
+            lens_shift: frustum.lens_shift(),
+        })
+    }
+    /// Calculates the [portal-like view] through each of the given viewports, in the same order.
+    ///
+    /// [portal-like view]: #method.viewport_view " "
+    pub fn viewport_views<'a>(
+        self,
+        viewports: &'a [Viewport],
+    ) -> impl Iterator<Item = Option<ViewportView>> + 'a {
+        viewports
+            .iter()
+            .map(move |&viewport| self.viewport_view(viewport))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::vec::Vec;
+    use uom::si::length::centimeter;
+
+    fn monitor(distance: f64, curvature: Option<Curvature>) -> MonitorConfiguration {
+        MonitorConfiguration {
+            dimensions: MonitorDimensions::WidthAndHeight {
+                width: Length::new::<centimeter>(80.0),
+                height: Length::new::<centimeter>(33.5),
+            },
+            distance: Length::new::<centimeter>(distance),
+            eye_offset: None,
+            curvature,
+            resolution: Some([3440, 1440]),
+        }
+    }
+    fn curvature() -> Option<Curvature> {
+        Some(Curvature {
+            radius: Length::new::<centimeter>(100.0),
+            width_measurement: CurvedWidthMeasurement::Arc,
+        })
+    }
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            math::abs(actual - expected) < 1e-9,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn full_viewport_is_the_whole_screen() {
+        let full = Viewport::Fraction {
+            left: 0.0,
+            top: 0.0,
+            width: 1.0,
+            height: 1.0,
+        };
+        for &monitor in &[monitor(70.0, None), monitor(70.0, curvature())] {
+            let view = monitor.viewport_view(full).unwrap();
+            assert_close(view.fov.get::<radian>(), monitor.fov().get::<radian>());
+            assert_close(
+                view.vertical_fov.get::<radian>(),
+                monitor.vertical_fov().get::<radian>(),
+            );
+            assert_eq!(view.lens_shift, [0.0, 0.0]);
+            assert_eq!(
+                monitor.viewport(full).unwrap().resolution,
+                Some([3440, 1440])
+            );
+        }
+    }
+    #[test]
+    fn split_screen_halves_are_mirrored() {
+        let halves = Viewport::split_screen(2, 1).collect::<Vec<_>>();
+        assert_eq!(halves.len(), 2);
+        for &monitor in &[monitor(70.0, None), monitor(70.0, curvature())] {
+            let views = monitor
+                .viewport_views(&halves)
+                .map(Option::unwrap)
+                .collect::<Vec<_>>();
+            let (left, right) = (views[0], views[1]);
+            assert_close(left.fov.get::<radian>(), right.fov.get::<radian>());
+            assert_close(
+                left.frustum.left.get::<radian>(),
+                right.frustum.right.get::<radian>(),
+            );
+            assert_close(
+                left.frustum.right.get::<radian>(),
+                right.frustum.left.get::<radian>(),
+            );
+            assert!(left.lens_shift[0] < 0.0);
+            assert_close(left.lens_shift[0], -right.lens_shift[0]);
+            assert_close(left.lens_shift[1], 0.0);
+        }
+    }
+    #[test]
+    fn off_center_curved_viewport_is_turned_by_its_arc_angle() {
+        let viewport = Viewport::Pixels {
+            left: 2000,
+            top: 0,
+            width: 1000,
+            height: 1440,
+        };
+        // The arc from the middle of the screen to the middle of the viewport, over the radius
+        let arc_angle = (2500.0 / 3440.0 - 0.5) * 80.0 / 100.0;
+        // From the center of the curvature, the middle of the viewport is straight ahead of the
+        // turned view direction
+        let from_center = monitor(100.0, curvature());
+        let turned = from_center.viewport(viewport).unwrap();
+        assert_close(turned.distance.get::<centimeter>(), 100.0);
+        assert!(turned.eye_offset.is_none());
+        let direction = from_center.direction_at_pixel([2500.0, 720.0]).unwrap();
+        assert_close(direction.yaw.get::<radian>(), arc_angle);
+        // From elsewhere, the direction towards the middle of the viewport is the turn plus the
+        // direction within the turned view
+        for &distance in &[60.0, 140.0] {
+            let monitor = monitor(distance, curvature());
+            let turned = monitor.viewport(viewport).unwrap();
+            let whole = monitor.direction_at_pixel([2500.0, 720.0]).unwrap();
+            let within = turned.direction_at_pixel([500.0, 720.0]).unwrap();
+            assert_close(
+                whole.yaw.get::<radian>(),
+                arc_angle + within.yaw.get::<radian>(),
+            );
+            // The edges of the viewport agree as well
+            let whole_left = monitor.direction_at_pixel([2000.0, 720.0]).unwrap();
+            let within_left = turned.direction_at_pixel([0.0, 720.0]).unwrap();
+            assert_close(
+                whole_left.yaw.get::<radian>(),
+                arc_angle + within_left.yaw.get::<radian>(),
+            );
+        }
+    }
+    #[test]
+    fn pillarbox_uses_the_reduced_width() {
+        // The panel is an ultrawide one, about 21:9
+        let monitor = monitor(70.0, None);
+        let sixteen_nine = AspectRatio::new(16, 9).unwrap();
+        let pillarbox = Viewport::fit(sixteen_nine, monitor.dimensions.aspect());
+        let [left, top, width, height] = pillarbox.as_fractions(None).unwrap();
+        assert_close(width, 16.0 / 9.0 / (80.0 / 33.5));
+        assert_close(left, (1.0 - width) / 2.0);
+        assert_eq!([top, height], [0.0, 1.0]);
+
+        let pillarboxed = monitor.viewport(pillarbox).unwrap();
+        let [game_width, game_height] = pillarboxed.dimensions.width_and_height();
+        assert_close(game_width.get::<centimeter>(), 33.5 * 16.0 / 9.0);
+        assert_close(game_height.get::<centimeter>(), 33.5);
+        assert!(pillarboxed.eye_offset.is_none());
+        // The game is seen like a 16:9 monitor of the same height would be, which is narrower
+        // than the whole panel
+        let expected = math::atan(33.5 * 16.0 / 9.0 / 2.0 / 70.0) * 2.0;
+        assert_close(pillarboxed.fov().get::<radian>(), expected.get::<radian>());
+        assert!(pillarboxed.fov() < monitor.fov());
+        assert_close(
+            pillarboxed.vertical_fov().get::<radian>(),
+            monitor.vertical_fov().get::<radian>(),
+        );
+        let view = monitor.viewport_view(pillarbox).unwrap();
+        assert_close(view.fov.get::<radian>(), expected.get::<radian>());
+    }
+}